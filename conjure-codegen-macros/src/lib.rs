@@ -0,0 +1,144 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Procedural macros for inline Conjure code generation.
+//!
+//! These live outside of `conjure-macros` since `conjure-codegen` depends on `conjure-object`, which itself depends on
+//! `conjure-macros`.
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use structmeta::StructMeta;
+use syn::{parse_macro_input, Error, LitBool, LitStr};
+
+#[derive(StructMeta)]
+struct Args {
+    #[struct_meta(unnamed)]
+    path: LitStr,
+    strip_prefix: Option<LitStr>,
+    exhaustive: Option<LitBool>,
+    serialize_empty_collections: Option<LitBool>,
+    use_legacy_error_serialization: Option<LitBool>,
+    public_fields: Option<LitBool>,
+    patches: Option<LitBool>,
+    diff: Option<LitBool>,
+    redacted_debug: Option<LitBool>,
+    field_masks: Option<LitBool>,
+}
+
+/// Generates Rust code from a JSON-encoded Conjure IR file at compile time.
+///
+/// The macro expands to the same set of modules that `conjure_codegen::Config::generate_files` would write to disk, so
+/// it should typically be invoked as the sole contents of a module:
+///
+/// ```ignore
+/// mod conjure {
+///     conjure_codegen_macros::include_conjure!("api.conjure.json", strip_prefix = "com.foo");
+/// }
+/// ```
+///
+/// The path to the IR file is resolved relative to the root of the invoking crate (i.e. the directory containing its
+/// `Cargo.toml`), and the crate will be rebuilt when the file changes.
+///
+/// The macro accepts several optional parameters corresponding to `conjure_codegen::Config` settings:
+///
+/// * `strip_prefix` - A package prefix to strip from the generated module paths.
+/// * `exhaustive` - Defaults to `false`.
+/// * `serialize_empty_collections` - Defaults to `false`.
+/// * `use_legacy_error_serialization` - Defaults to `true`.
+/// * `public_fields` - Defaults to `false`.
+/// * `patches` - Defaults to `false`.
+/// * `diff` - Defaults to `false`.
+/// * `redacted_debug` - Defaults to `false`.
+/// * `field_masks` - Defaults to `false`.
+///
+/// The generated code depends on the `conjure-object`, `conjure-error`, and `conjure-http` crates, which must be
+/// dependencies of the invoking crate.
+#[proc_macro]
+pub fn include_conjure(input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(input as Args);
+
+    match generate(&args) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+fn generate(args: &Args) -> Result<proc_macro2::TokenStream, Error> {
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")
+        .ok_or_else(|| Error::new(Span::call_site(), "CARGO_MANIFEST_DIR is not set"))?;
+    let path = PathBuf::from(manifest_dir).join(args.path.value());
+
+    let ir = fs::read_to_string(&path).map_err(|e| {
+        Error::new(
+            args.path.span(),
+            format!("error reading file {}: {}", path.display(), e),
+        )
+    })?;
+    let defs = conjure_serde::json::client_from_str(&ir).map_err(|e| {
+        Error::new(
+            args.path.span(),
+            format!("error parsing Conjure IR file {}: {}", path.display(), e),
+        )
+    })?;
+
+    let mut config = conjure_codegen::Config::new();
+    config.strip_prefix(args.strip_prefix.as_ref().map(|s| s.value()));
+    if let Some(exhaustive) = &args.exhaustive {
+        config.exhaustive(exhaustive.value);
+    }
+    if let Some(serialize_empty_collections) = &args.serialize_empty_collections {
+        config.serialize_empty_collections(serialize_empty_collections.value);
+    }
+    if let Some(use_legacy_error_serialization) = &args.use_legacy_error_serialization {
+        config.use_legacy_error_serialization(use_legacy_error_serialization.value);
+    }
+    if let Some(public_fields) = &args.public_fields {
+        config.public_fields(public_fields.value);
+    }
+    if let Some(patches) = &args.patches {
+        config.patches(patches.value);
+    }
+    if let Some(diff) = &args.diff {
+        config.diff(diff.value);
+    }
+    if let Some(redacted_debug) = &args.redacted_debug {
+        config.redacted_debug(redacted_debug.value);
+    }
+    if let Some(field_masks) = &args.field_masks {
+        config.field_masks(field_masks.value);
+    }
+
+    let modules = config
+        .generate_tokens(&defs)
+        .map_err(|e| Error::new(args.path.span(), format!("{:#}", e)))?;
+
+    // Tie the invoking crate's fingerprint to the IR file so it's rebuilt when the file changes.
+    let path = path.to_str().ok_or_else(|| {
+        Error::new(
+            args.path.span(),
+            format!("non-UTF8 path {}", path.display()),
+        )
+    })?;
+
+    Ok(quote! {
+        const _: &[u8] = include_bytes!(#path);
+
+        #modules
+    })
+}
@@ -14,7 +14,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::arbitrary;
 use crate::context::{BaseModule, Context};
+use crate::debug;
+use crate::diff;
+use crate::field_mask;
 use crate::types::objects::AliasDefinition;
 
 pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
@@ -26,17 +30,19 @@ pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
     let mut type_attrs = vec![quote!(#[serde(crate = "conjure_object::serde", transparent)])];
     let mut field_attrs = vec![];
     let mut derives = vec![
-        "Debug",
         "Clone",
         "conjure_object::serde::Deserialize",
         "conjure_object::serde::Serialize",
     ];
+    if !debug::redacts_alias(ctx, def) {
+        derives.insert(0, "Debug");
+    }
 
     if ctx.is_copy(def.alias()) {
         derives.push("Copy");
     }
 
-    if ctx.is_double(def.alias()) {
+    if ctx.is_double(def.alias()) || ctx.is_unordered(def.alias()) {
         derives.push("conjure_object::private::DeriveWith");
         type_attrs.push(quote!(#[derive_with(PartialEq, Eq, PartialOrd, Ord, Hash)]));
         field_attrs.push(quote!(#[derive_with(with = conjure_object::private::DoubleWrapper)]));
@@ -110,6 +116,12 @@ pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
         None => quote!(),
     };
 
+    let arbitrary = arbitrary::generate_alias(ctx, def);
+    let diff = diff::generate_alias(ctx, def);
+    let field_mask = field_mask::generate_alias(ctx, def);
+    let debug = debug::generate_alias(ctx, def);
+    let double_ops_leaf = ctx.double_ops_leaf(def.type_name());
+
     let dealiased_type = ctx.rust_type(
         BaseModule::Objects,
         def.type_name(),
@@ -156,5 +168,15 @@ pub fn generate(ctx: &Context, def: &AliasDefinition) -> TokenStream {
                 &self.0
             }
         }
+
+        #arbitrary
+
+        #diff
+
+        #field_mask
+
+        #debug
+
+        #double_ops_leaf
     }
 }
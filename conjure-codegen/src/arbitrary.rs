@@ -0,0 +1,182 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::context::{BaseModule, Context};
+use crate::types::objects::{AliasDefinition, EnumDefinition, ObjectDefinition, UnionDefinition};
+
+// proptest implements Strategy for tuples of up to 12 elements.
+const MAX_TUPLE_LEN: usize = 12;
+
+pub fn generate_object(
+    ctx: &Context,
+    base_module: BaseModule,
+    def: &ObjectDefinition,
+) -> TokenStream {
+    if !ctx.arbitrary() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let fields = def
+        .fields()
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()))
+        .collect::<Vec<_>>();
+
+    let body = if fields.is_empty() {
+        quote!(Just(#name {}).boxed())
+    } else {
+        let strategies = def.fields().iter().map(|f| {
+            // Break cycles by leaving recursive fields empty. Required recursive fields can only appear through a
+            // union, which excludes its recursive variants itself.
+            if !ctx.is_required(f.type_()) && ctx.is_recursive(def.type_name(), f.type_()) {
+                let type_ = ctx.boxed_rust_type(base_module, def.type_name(), f.type_());
+                quote!(Just(<#type_ as std::default::Default>::default()))
+            } else {
+                ctx.arbitrary_strategy(base_module, def.type_name(), f.type_())
+            }
+        });
+        let (pattern, strategy) = nest(fields.iter().map(|f| quote!(#f)).zip(strategies).collect());
+
+        quote! {
+            #strategy
+                .prop_map(|#pattern| #name { #(#fields,)* })
+                .boxed()
+        }
+    };
+
+    arbitrary_impl(&name, body)
+}
+
+pub fn generate_union(ctx: &Context, def: &UnionDefinition, unknown: &TokenStream) -> TokenStream {
+    if !ctx.arbitrary() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    let mut variants = def
+        .union_()
+        .iter()
+        .filter(|f| !ctx.is_recursive(def.type_name(), f.type_()))
+        .map(|f| {
+            let variant = ctx.type_name(f.field_name());
+            let strategy = ctx.arbitrary_strategy(BaseModule::Objects, def.type_name(), f.type_());
+            quote!(#strategy.prop_map(#name::#variant).boxed())
+        })
+        .collect::<Vec<_>>();
+
+    if !ctx.exhaustive(def.type_name()) {
+        let known = def.union_().iter().map(|f| &f.field_name().0);
+        variants.push(quote! {
+            (
+                "[a-z][a-zA-Z0-9]{0,15}"
+                    .prop_filter("known variant", |v| ![#(#known,)* "type"].contains(&v.as_str())),
+                any::<conjure_object::Any>(),
+            )
+                .prop_map(|(type_, value)| #name::#unknown(#unknown { type_: type_.into_boxed_str(), value }))
+                .boxed()
+        });
+    }
+
+    // Values of an exhaustive union with no non-recursive variants can't be finitely generated.
+    if variants.is_empty() {
+        return quote!();
+    }
+
+    arbitrary_impl(
+        &name,
+        quote!(prop::strategy::Union::new([#(#variants,)*]).boxed()),
+    )
+}
+
+pub fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    if !ctx.arbitrary() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    let mut variants = def
+        .values()
+        .iter()
+        .map(|v| {
+            let variant = ctx.type_name(v.value());
+            quote!(Just(#name::#variant).boxed())
+        })
+        .collect::<Vec<_>>();
+
+    if !ctx.exhaustive(def.type_name()) {
+        variants.push(quote! {
+            "[A-Z][A-Z0-9_]{0,15}".prop_map(|v| v.parse::<#name>().unwrap()).boxed()
+        });
+    }
+
+    if variants.is_empty() {
+        return quote!();
+    }
+
+    arbitrary_impl(
+        &name,
+        quote!(prop::strategy::Union::new([#(#variants,)*]).boxed()),
+    )
+}
+
+pub fn generate_alias(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    if !ctx.arbitrary() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let strategy =
+        ctx.unboxed_arbitrary_strategy(BaseModule::Objects, def.type_name(), def.alias());
+
+    arbitrary_impl(&name, quote!(#strategy.prop_map(#name).boxed()))
+}
+
+fn arbitrary_impl(name: &Ident, body: TokenStream) -> TokenStream {
+    quote! {
+        impl conjure_object::private::proptest::arbitrary::Arbitrary for #name {
+            type Parameters = ();
+            type Strategy = conjure_object::private::proptest::strategy::BoxedStrategy<Self>;
+
+            #[allow(deprecated)]
+            fn arbitrary_with(_: ()) -> Self::Strategy {
+                use conjure_object::private::proptest::prelude::*;
+
+                #body
+            }
+        }
+    }
+}
+
+/// Combines (pattern, strategy) pairs into a single tuple strategy, nesting as necessary to stay within the maximum
+/// tuple size supported by proptest.
+fn nest(mut items: Vec<(TokenStream, TokenStream)>) -> (TokenStream, TokenStream) {
+    while items.len() > MAX_TUPLE_LEN {
+        items = items
+            .chunks(MAX_TUPLE_LEN)
+            .map(|chunk| tuple(chunk.to_vec()))
+            .collect();
+    }
+
+    tuple(items)
+}
+
+fn tuple(items: Vec<(TokenStream, TokenStream)>) -> (TokenStream, TokenStream) {
+    let (patterns, strategies): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+    (quote!((#(#patterns,)*)), quote!((#(#strategies,)*)))
+}
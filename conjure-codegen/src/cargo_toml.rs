@@ -6,7 +6,33 @@ use std::collections::BTreeMap;
 pub struct Manifest<'a> {
     pub package: Package<'a>,
     #[serde(skip_serializing_if = "BTreeMap::is_empty")]
-    pub dependencies: BTreeMap<&'a str, &'a str>,
+    pub dependencies: BTreeMap<&'a str, Dependency<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum Dependency<'a> {
+    Version(&'a str),
+    Detailed {
+        version: &'a str,
+        features: Vec<&'a str>,
+    },
+    Raw(&'a toml::Value),
+    Path {
+        version: &'a str,
+        path: String,
+    },
+}
+
+#[derive(Serialize)]
+pub struct WorkspaceManifest<'a> {
+    pub workspace: Workspace<'a>,
+}
+
+#[derive(Serialize)]
+pub struct Workspace<'a> {
+    pub members: Vec<&'a str>,
+    pub resolver: &'a str,
 }
 
 #[derive(Serialize)]
@@ -13,17 +13,19 @@
 // limitations under the License.
 #![allow(clippy::match_like_matches_macro)]
 
+use anyhow::{bail, Error};
 use heck::{ToSnakeCase, ToUpperCamelCase};
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::quote;
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use crate::errors::error_object_definition;
 use crate::types::objects::{
-    ArgumentDefinition, ConjureDefinition, Documentation, LogSafety, PrimitiveType, Type,
-    TypeDefinition, TypeName,
+    AliasDefinition, ArgumentDefinition, ConjureDefinition, Documentation, ExternalReference,
+    LogSafety, PrimitiveType, Type, TypeDefinition, TypeName,
 };
+use crate::{definition_name, MapType, Overrides, SetType, StringType};
 
 #[derive(Copy, Clone)]
 pub enum BaseModule {
@@ -56,33 +58,102 @@ struct TypeContext {
     log_safety: RefCell<CachedLogSafety>,
 }
 
+fn collect_external_references(def: &Type, references: &mut HashMap<TypeName, Type>) {
+    match def {
+        Type::Primitive(_) | Type::Reference(_) => {}
+        Type::Optional(def) => collect_external_references(def.item_type(), references),
+        Type::List(def) => collect_external_references(def.item_type(), references),
+        Type::Set(def) => collect_external_references(def.item_type(), references),
+        Type::Map(def) => {
+            collect_external_references(def.key_type(), references);
+            collect_external_references(def.value_type(), references);
+        }
+        Type::External(def) => {
+            references
+                .entry(def.external_reference().clone())
+                .or_insert_with(|| def.fallback().clone());
+        }
+    }
+}
+
+/// Returns `true` if `package` is `parent` or one of its subpackages.
+fn in_package(package: &str, parent: &str) -> bool {
+    package == parent
+        || package
+            .strip_prefix(parent)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
 pub struct Context {
     types: HashMap<TypeName, TypeContext>,
     exhaustive: bool,
     serialize_empty_collections: bool,
     use_legacy_error_serialization: bool,
     public_fields: bool,
+    arbitrary: bool,
+    patches: bool,
+    diff: bool,
+    redacted_debug: bool,
+    field_masks: bool,
     strip_prefix: Vec<String>,
+    crate_per_package: Option<String>,
+    map_type: MapType,
+    set_type: SetType,
+    string_type: StringType,
+    crate_dependencies: RefCell<BTreeMap<String, BTreeSet<String>>>,
+    external_crates: RefCell<BTreeMap<Option<String>, BTreeSet<String>>>,
+    package_overrides: BTreeMap<String, Overrides>,
+    type_overrides: BTreeMap<String, Overrides>,
+    external_types: HashMap<TypeName, TokenStream>,
+    external_references: HashMap<TypeName, Type>,
     version: Option<String>,
 }
 
 impl Context {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         defs: &ConjureDefinition,
         exhaustive: bool,
         serialize_empty_collections: bool,
         use_legacy_error_serialization: bool,
         public_fields: bool,
+        arbitrary: bool,
+        patches: bool,
+        diff: bool,
+        redacted_debug: bool,
+        field_masks: bool,
         strip_prefix: Option<&str>,
+        crate_per_package: Option<&str>,
+        map_type: MapType,
+        set_type: SetType,
+        string_type: StringType,
+        package_overrides: &BTreeMap<String, Overrides>,
+        type_overrides: &BTreeMap<String, Overrides>,
+        external_types: &BTreeMap<String, String>,
         version: Option<&str>,
-    ) -> Context {
+    ) -> Result<Context, Error> {
         let mut context = Context {
             types: HashMap::new(),
             exhaustive,
             serialize_empty_collections,
             use_legacy_error_serialization,
             public_fields,
+            arbitrary,
+            patches,
+            diff,
+            redacted_debug,
+            field_masks,
             strip_prefix: vec![],
+            crate_per_package: crate_per_package.map(str::to_owned),
+            map_type,
+            set_type,
+            string_type,
+            crate_dependencies: RefCell::new(BTreeMap::new()),
+            external_crates: RefCell::new(BTreeMap::new()),
+            package_overrides: package_overrides.clone(),
+            type_overrides: type_overrides.clone(),
+            external_types: HashMap::new(),
+            external_references: HashMap::new(),
             version: version.map(str::to_owned),
         };
 
@@ -91,15 +162,8 @@ impl Context {
         }
 
         for def in defs.types() {
-            let name = match &def {
-                TypeDefinition::Alias(def) => def.type_name().clone(),
-                TypeDefinition::Enum(def) => def.type_name().clone(),
-                TypeDefinition::Object(def) => def.type_name().clone(),
-                TypeDefinition::Union(def) => def.type_name().clone(),
-            };
-
             context.types.insert(
-                name,
+                definition_name(def).clone(),
                 TypeContext {
                     def: def.clone(),
                     has_double: Cell::new(None),
@@ -109,11 +173,15 @@ impl Context {
             );
         }
 
+        let redact_unsafe_error_args = context.redact_unsafe_error_args();
         for def in defs.errors() {
             context.types.insert(
                 def.error_name().clone(),
                 TypeContext {
-                    def: TypeDefinition::Object(error_object_definition(def)),
+                    def: TypeDefinition::Object(error_object_definition(
+                        def,
+                        redact_unsafe_error_args,
+                    )),
                     has_double: Cell::new(None),
                     is_copy: Cell::new(None),
                     log_safety: RefCell::new(CachedLogSafety::Uncomputed),
@@ -121,23 +189,163 @@ impl Context {
             );
         }
 
-        context
+        for type_name in type_overrides.keys() {
+            if !context
+                .types
+                .keys()
+                .any(|name| format!("{}.{}", name.package(), name.name()) == *type_name)
+            {
+                bail!("type overrides specified for unknown type `{type_name}`");
+            }
+        }
+        for package in package_overrides.keys() {
+            if !context
+                .types
+                .keys()
+                .any(|name| in_package(name.package(), package))
+            {
+                bail!("package overrides specified for unknown package `{package}`");
+            }
+        }
+
+        let mut external_references = HashMap::new();
+        for ctx in context.types.values() {
+            match &ctx.def {
+                TypeDefinition::Alias(def) => {
+                    collect_external_references(def.alias(), &mut external_references)
+                }
+                TypeDefinition::Enum(_) => {}
+                TypeDefinition::Object(def) => def
+                    .fields()
+                    .iter()
+                    .for_each(|f| collect_external_references(f.type_(), &mut external_references)),
+                TypeDefinition::Union(def) => def
+                    .union_()
+                    .iter()
+                    .for_each(|f| collect_external_references(f.type_(), &mut external_references)),
+            }
+        }
+        for endpoint in defs.services().iter().flat_map(|s| s.endpoints()) {
+            for arg in endpoint.args() {
+                collect_external_references(arg.type_(), &mut external_references);
+            }
+            if let Some(returns) = endpoint.returns() {
+                collect_external_references(returns, &mut external_references);
+            }
+        }
+
+        for (name, path) in external_types {
+            let name = match name.rsplit_once('.') {
+                Some((package, name)) => TypeName::new(name, package),
+                None => TypeName::new(name.as_str(), ""),
+            };
+            context
+                .external_types
+                .insert(name.clone(), path.parse().unwrap());
+
+            if context.types.contains_key(&name) {
+                continue;
+            }
+
+            // Mapped external references are treated like aliases of their fallback type.
+            let Some(fallback) = external_references.remove(&name) else {
+                continue;
+            };
+            context.types.insert(
+                name.clone(),
+                TypeContext {
+                    def: TypeDefinition::Alias(AliasDefinition::new(name.clone(), fallback)),
+                    has_double: Cell::new(None),
+                    is_copy: Cell::new(None),
+                    log_safety: RefCell::new(CachedLogSafety::Computed(None)),
+                },
+            );
+            context
+                .external_references
+                .insert(name.clone(), Type::Reference(name));
+        }
+
+        Ok(context)
     }
 
-    pub fn exhaustive(&self) -> bool {
-        self.exhaustive
+    pub fn exhaustive(&self, name: &TypeName) -> bool {
+        self.overridden(name, |o| o.exhaustive)
+            .unwrap_or(self.exhaustive)
     }
 
-    pub fn serialize_empty_collections(&self) -> bool {
-        self.serialize_empty_collections
+    pub fn serialize_empty_collections(&self, name: &TypeName) -> bool {
+        self.overridden(name, |o| o.serialize_empty_collections)
+            .unwrap_or(self.serialize_empty_collections)
     }
 
     pub fn use_legacy_error_serialization(&self) -> bool {
         self.use_legacy_error_serialization
     }
 
-    pub fn public_fields(&self) -> bool {
-        self.public_fields
+    pub fn public_fields(&self, name: &TypeName) -> bool {
+        self.overridden(name, |o| o.public_fields)
+            .unwrap_or(self.public_fields)
+    }
+
+    pub fn arbitrary(&self) -> bool {
+        self.arbitrary
+    }
+
+    pub fn patches(&self) -> bool {
+        self.patches
+    }
+
+    pub fn diff(&self) -> bool {
+        self.diff
+    }
+
+    pub fn redacted_debug(&self) -> bool {
+        self.redacted_debug
+    }
+
+    /// Returns `true` if unsafe error args without an explicit safety should be treated as unsafe.
+    pub fn redact_unsafe_error_args(&self) -> bool {
+        self.redacted_debug || self.diff
+    }
+
+    pub fn field_masks(&self) -> bool {
+        self.field_masks
+    }
+
+    /// Returns `true` if the type has been mapped to an existing Rust type rather than being generated.
+    pub fn is_external(&self, name: &TypeName) -> bool {
+        self.external_types.contains_key(name)
+    }
+
+    // Mapped external references are treated as references to the mapped type rather than their fallback.
+    fn resolve_external<'a>(&'a self, def: &'a ExternalReference) -> &'a Type {
+        self.external_references
+            .get(def.external_reference())
+            .unwrap_or_else(|| def.fallback())
+    }
+
+    // Returns the setting from the type's own overrides if set, and otherwise from the overrides of the most specific
+    // package containing the type which sets it.
+    fn overridden<F>(&self, name: &TypeName, f: F) -> Option<bool>
+    where
+        F: Fn(&Overrides) -> Option<bool>,
+    {
+        let type_overrides = self
+            .type_overrides
+            .get(&format!("{}.{}", name.package(), name.name()))
+            .and_then(&f);
+        if type_overrides.is_some() {
+            return type_overrides;
+        }
+
+        let mut matches = self
+            .package_overrides
+            .iter()
+            .filter(|(package, _)| in_package(name.package(), package))
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|(package, _)| std::cmp::Reverse(package.len()));
+
+        matches.into_iter().find_map(|(_, overrides)| f(overrides))
     }
 
     fn needs_box(&self, def: &Type) -> bool {
@@ -146,7 +354,7 @@ impl Context {
             Type::Optional(def) => self.needs_box(def.item_type()),
             Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_needs_box(def),
-            Type::External(def) => self.needs_box(def.fallback()),
+            Type::External(def) => self.needs_box(self.resolve_external(def)),
         }
     }
 
@@ -171,7 +379,7 @@ impl Context {
             Type::Set(def) => self.has_double(def.item_type()),
             Type::Map(def) => self.has_double(def.key_type()) || self.has_double(def.value_type()),
             Type::Reference(def) => self.ref_has_double(def),
-            Type::External(def) => self.has_double(def.fallback()),
+            Type::External(def) => self.has_double(self.resolve_external(def)),
         }
     }
 
@@ -212,7 +420,7 @@ impl Context {
             Type::Optional(def) => self.is_copy(def.item_type()),
             Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_is_copy(def),
-            Type::External(def) => self.is_copy(def.fallback()),
+            Type::External(def) => self.is_copy(self.resolve_external(def)),
         }
     }
 
@@ -237,7 +445,7 @@ impl Context {
             Type::Primitive(_) => true,
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_is_required(def),
-            Type::External(def) => self.is_required(def.fallback()),
+            Type::External(def) => self.is_required(self.resolve_external(def)),
         }
     }
 
@@ -267,7 +475,7 @@ impl Context {
             },
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => true,
             Type::Reference(def) => self.ref_is_default(def),
-            Type::External(def) => self.is_default(def.fallback()),
+            Type::External(def) => self.is_default(self.resolve_external(def)),
         }
     }
 
@@ -295,7 +503,7 @@ impl Context {
             },
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.ref_is_display(def),
-            Type::External(def) => self.is_display(def.fallback()),
+            Type::External(def) => self.is_display(self.resolve_external(def)),
         }
     }
 
@@ -325,7 +533,9 @@ impl Context {
                 Some(self.rust_type_inner(base_module, this_type, def.item_type(), true))
             }
             Type::Reference(def) => self.ref_is_from_iter(base_module, this_type, def),
-            Type::External(def) => self.is_from_iter(base_module, this_type, def.fallback()),
+            Type::External(def) => {
+                self.is_from_iter(base_module, this_type, self.resolve_external(def))
+            }
         }
     }
 
@@ -354,14 +564,14 @@ impl Context {
                 }
                 TypeDefinition::Alias(def) => self.dealiased_type(def.alias()),
             },
-            Type::External(def) => self.dealiased_type(def.fallback()),
+            Type::External(def) => self.dealiased_type(self.resolve_external(def)),
         }
     }
 
     pub fn is_aliased(&self, def: &Type) -> bool {
         match def {
             Type::Reference(name) => matches!(&self.types[name].def, TypeDefinition::Alias(_)),
-            Type::External(ext) => self.is_aliased(ext.fallback()),
+            Type::External(ext) => self.is_aliased(self.resolve_external(ext)),
             _ => false,
         }
     }
@@ -384,7 +594,7 @@ impl Context {
     ) -> TokenStream {
         match def {
             Type::Primitive(def) => match *def {
-                PrimitiveType::String => self.string_ident(this_type),
+                PrimitiveType::String => self.string_type(this_type),
                 PrimitiveType::Datetime => quote!(conjure_object::DateTime<conjure_object::Utc>),
                 PrimitiveType::Integer => quote!(i32),
                 PrimitiveType::Double => {
@@ -412,18 +622,29 @@ impl Context {
                 let item = self.rust_type_inner(base_module, this_type, def.item_type(), key);
                 quote!(#vec<#item>)
             }
+            // Set elements and map keys need to be Ord and Hash, so collections in those positions are always BTrees.
             Type::Set(def) => {
+                let set = if key {
+                    quote!(std::collections::BTreeSet)
+                } else {
+                    self.set_ident()
+                };
                 let item = self.rust_type_inner(base_module, this_type, def.item_type(), true);
-                quote!(std::collections::BTreeSet<#item>)
+                quote!(#set<#item>)
             }
             Type::Map(def) => {
+                let map = if key {
+                    quote!(std::collections::BTreeMap)
+                } else {
+                    self.map_ident()
+                };
                 let key = self.rust_type_inner(base_module, this_type, def.key_type(), true);
                 let value = self.rust_type(base_module, this_type, def.value_type());
-                quote!(std::collections::BTreeMap<#key, #value>)
+                quote!(#map<#key, #value>)
             }
             Type::Reference(def) => self.type_path(base_module, this_type, def),
             Type::External(def) => {
-                self.rust_type_inner(base_module, this_type, def.fallback(), key)
+                self.rust_type_inner(base_module, this_type, self.resolve_external(def), key)
             }
         }
     }
@@ -441,7 +662,9 @@ impl Context {
                 quote!(#option<#item>)
             }
             Type::Reference(def) => self.ref_boxed_rust_type(base_module, this_type, def),
-            Type::External(def) => self.boxed_rust_type(base_module, this_type, def.fallback()),
+            Type::External(def) => {
+                self.boxed_rust_type(base_module, this_type, self.resolve_external(def))
+            }
             def => self.rust_type(base_module, this_type, def),
         }
     }
@@ -503,16 +726,20 @@ impl Context {
                 quote!(&[#item])
             }
             Type::Set(def) => {
+                let set = self.set_ident();
                 let item = self.rust_type_inner(base_module, this_type, def.item_type(), true);
-                quote!(&std::collections::BTreeSet<#item>)
+                quote!(&#set<#item>)
             }
             Type::Map(def) => {
+                let map = self.map_ident();
                 let key = self.rust_type_inner(base_module, this_type, def.key_type(), true);
                 let value = self.rust_type(base_module, this_type, def.value_type());
-                quote!(&std::collections::BTreeMap<#key, #value>)
+                quote!(&#map<#key, #value>)
             }
             Type::Reference(def) => self.borrowed_rust_type_ref(base_module, this_type, def),
-            Type::External(def) => self.borrowed_rust_type(base_module, this_type, def.fallback()),
+            Type::External(def) => {
+                self.borrowed_rust_type(base_module, this_type, self.resolve_external(def))
+            }
         }
     }
 
@@ -563,7 +790,7 @@ impl Context {
             Type::List(_) => quote!(&*#value),
             Type::Set(_) | Type::Map(_) => quote!(&#value),
             Type::Reference(def) => self.borrow_rust_type_ref(value, def),
-            Type::External(def) => self.borrow_rust_type(value, def.fallback()),
+            Type::External(def) => self.borrow_rust_type(value, self.resolve_external(def)),
         }
     }
 
@@ -637,7 +864,9 @@ impl Context {
                     BuilderConfig::Normal
                 }
             }
-            Type::External(def) => self.builder_config(base_module, this_type, def.fallback()),
+            Type::External(def) => {
+                self.builder_config(base_module, this_type, self.resolve_external(def))
+            }
         }
     }
 
@@ -651,7 +880,7 @@ impl Context {
         match def {
             Type::Primitive(primitive) => match primitive {
                 PrimitiveType::String => BuilderItemConfig::Into {
-                    type_: self.string_ident(this_type),
+                    type_: self.string_type(this_type),
                 },
                 PrimitiveType::Binary => BuilderItemConfig::Into {
                     type_: quote!(conjure_object::Bytes),
@@ -702,7 +931,151 @@ impl Context {
                 type_: self.type_path(base_module, this_type, def),
             },
             Type::External(def) => {
-                self.builder_item_config(base_module, this_type, def.fallback(), key)
+                self.builder_item_config(base_module, this_type, self.resolve_external(def), key)
+            }
+        }
+    }
+
+    /// Returns a `proptest` strategy generating values of the type returned by `boxed_rust_type`.
+    ///
+    /// The `proptest` prelude is expected to be in scope.
+    pub fn arbitrary_strategy(
+        &self,
+        base_module: BaseModule,
+        this_type: &TypeName,
+        def: &Type,
+    ) -> TokenStream {
+        match def {
+            Type::Optional(def) => {
+                let item = self.arbitrary_strategy(base_module, this_type, def.item_type());
+                quote!(prop::option::of(#item))
+            }
+            Type::Reference(_) => {
+                let type_ = self.boxed_rust_type(base_module, this_type, def);
+                quote!(any::<#type_>())
+            }
+            Type::External(def) => {
+                self.arbitrary_strategy(base_module, this_type, self.resolve_external(def))
+            }
+            def => self.arbitrary_strategy_inner(base_module, this_type, def, false),
+        }
+    }
+
+    pub fn unboxed_arbitrary_strategy(
+        &self,
+        base_module: BaseModule,
+        this_type: &TypeName,
+        def: &Type,
+    ) -> TokenStream {
+        self.arbitrary_strategy_inner(base_module, this_type, def, false)
+    }
+
+    fn arbitrary_strategy_inner(
+        &self,
+        base_module: BaseModule,
+        this_type: &TypeName,
+        def: &Type,
+        key: bool,
+    ) -> TokenStream {
+        match def {
+            Type::Primitive(primitive) => match primitive {
+                PrimitiveType::Datetime => quote!(conjure_object::arbitrary::date_time()),
+                PrimitiveType::Binary => quote!(conjure_object::arbitrary::bytes()),
+                PrimitiveType::Uuid => quote!(conjure_object::arbitrary::uuid()),
+                PrimitiveType::String if self.string_type == StringType::ArcStr => {
+                    quote!(any::<String>().prop_map(std::sync::Arc::<str>::from))
+                }
+                _ => {
+                    let type_ = self.rust_type_inner(base_module, this_type, def, key);
+                    quote!(any::<#type_>())
+                }
+            },
+            Type::Optional(def) => {
+                let item =
+                    self.arbitrary_strategy_inner(base_module, this_type, def.item_type(), key);
+                quote!(prop::option::of(#item))
+            }
+            Type::List(list) => {
+                let item =
+                    self.arbitrary_strategy_inner(base_module, this_type, list.item_type(), key);
+                quote!(prop::collection::vec(#item, 0..4))
+            }
+            // Generating a Vec and collecting it avoids rejections when the item type has only a few distinct values.
+            Type::Set(set) => {
+                let item =
+                    self.arbitrary_strategy_inner(base_module, this_type, set.item_type(), true);
+                let type_ = self.rust_type_inner(base_module, this_type, def, key);
+                quote!(prop::collection::vec(#item, 0..4).prop_map(|v| v.into_iter().collect::<#type_>()))
+            }
+            Type::Map(map) => {
+                let key_strategy =
+                    self.arbitrary_strategy_inner(base_module, this_type, map.key_type(), true);
+                let value_strategy =
+                    self.arbitrary_strategy_inner(base_module, this_type, map.value_type(), false);
+                let type_ = self.rust_type_inner(base_module, this_type, def, key);
+                quote! {
+                    prop::collection::vec((#key_strategy, #value_strategy), 0..4)
+                        .prop_map(|v| v.into_iter().collect::<#type_>())
+                }
+            }
+            Type::Reference(name) => {
+                let type_ = self.type_path(base_module, this_type, name);
+                quote!(any::<#type_>())
+            }
+            Type::External(def) => self.arbitrary_strategy_inner(
+                base_module,
+                this_type,
+                self.resolve_external(def),
+                key,
+            ),
+        }
+    }
+
+    /// Returns `true` if a value of the type can contain a value of `this_type`.
+    pub fn is_recursive(&self, this_type: &TypeName, def: &Type) -> bool {
+        self.references_type(this_type, def, &mut HashSet::new())
+    }
+
+    fn references_type<'a>(
+        &'a self,
+        target: &TypeName,
+        def: &'a Type,
+        visited: &mut HashSet<&'a TypeName>,
+    ) -> bool {
+        match def {
+            Type::Primitive(_) => false,
+            Type::Optional(def) => self.references_type(target, def.item_type(), visited),
+            Type::List(def) => self.references_type(target, def.item_type(), visited),
+            Type::Set(def) => self.references_type(target, def.item_type(), visited),
+            Type::Map(def) => {
+                self.references_type(target, def.key_type(), visited)
+                    || self.references_type(target, def.value_type(), visited)
+            }
+            Type::Reference(name) => {
+                if name == target {
+                    return true;
+                }
+                if !visited.insert(name) {
+                    return false;
+                }
+
+                match &self.types[name].def {
+                    TypeDefinition::Alias(def) => {
+                        self.references_type(target, def.alias(), visited)
+                    }
+                    TypeDefinition::Enum(_) => false,
+                    TypeDefinition::Object(def) => def
+                        .fields()
+                        .iter()
+                        .any(|f| self.references_type(target, f.type_(), visited)),
+                    TypeDefinition::Union(def) => def
+                        .union_()
+                        .iter()
+                        .any(|f| self.references_type(target, f.type_(), visited)),
+                }
+            }
+            Type::External(def) => {
+                self.references_type(target, self.resolve_external(def), visited)
             }
         }
     }
@@ -718,10 +1091,10 @@ impl Context {
                 let vec = self.vec_ident(this_type);
                 Some(format!("{vec}::is_empty"))
             }
-            Type::Set(_) => Some("std::collections::BTreeSet::is_empty".to_string()),
-            Type::Map(_) => Some("std::collections::BTreeMap::is_empty".to_string()),
+            Type::Set(_) => Some(format!("{}::is_empty", self.set_path())),
+            Type::Map(_) => Some(format!("{}::is_empty", self.map_path())),
             Type::Reference(def) => self.is_empty_method_ref(this_type, def),
-            Type::External(def) => self.is_empty_method(this_type, def.fallback()),
+            Type::External(def) => self.is_empty_method(this_type, self.resolve_external(def)),
         }
     }
 
@@ -743,7 +1116,7 @@ impl Context {
             | Type::Set(_)
             | Type::Map(_) => false,
             Type::Reference(def) => self.is_binary_ref(def),
-            Type::External(def) => self.is_binary(def.fallback()),
+            Type::External(def) => self.is_binary(self.resolve_external(def)),
         }
     }
 
@@ -773,7 +1146,7 @@ impl Context {
             },
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
             Type::Reference(def) => self.is_plain_ref(def),
-            Type::External(def) => self.is_plain(def.fallback()),
+            Type::External(def) => self.is_plain(self.resolve_external(def)),
         }
     }
 
@@ -792,7 +1165,7 @@ impl Context {
             Type::Primitive(_) => false,
             Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => true,
             Type::Reference(def) => self.is_iterable_ref(def),
-            Type::External(def) => self.is_iterable(def.fallback()),
+            Type::External(def) => self.is_iterable(self.resolve_external(def)),
         }
     }
 
@@ -810,7 +1183,7 @@ impl Context {
             Type::Primitive(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => None,
             Type::Optional(def) => Some(def.item_type()),
             Type::Reference(def) => self.is_optional_ref(def),
-            Type::External(def) => self.is_optional(def.fallback()),
+            Type::External(def) => self.is_optional(self.resolve_external(def)),
         }
     }
 
@@ -831,7 +1204,35 @@ impl Context {
             Type::List(def) => self.is_double(def.item_type()),
             Type::Map(def) => self.is_double(def.value_type()),
             Type::Primitive(_) | Type::Set(_) | Type::Reference(_) => false,
-            Type::External(def) => self.is_double(def.fallback()),
+            Type::External(def) => self.is_double(self.resolve_external(def)),
+        }
+    }
+
+    /// Returns `true` if the type directly contains a map or set which doesn't implement `Ord` and `Hash`.
+    ///
+    /// Like doubles, these have to be compared and hashed through `DoubleWrapper`.
+    pub fn is_unordered(&self, def: &Type) -> bool {
+        match def {
+            Type::Primitive(_) | Type::Reference(_) => false,
+            Type::Optional(def) => self.is_unordered(def.item_type()),
+            Type::List(def) => self.is_unordered(def.item_type()),
+            Type::Set(_) => self.set_type != SetType::BTreeSet,
+            Type::Map(def) => {
+                self.map_type != MapType::BTreeMap || self.is_unordered(def.value_type())
+            }
+            Type::External(def) => self.is_unordered(self.resolve_external(def)),
+        }
+    }
+
+    /// Returns an implementation of `DoubleOpsLeaf` for the type if it can be stored in unordered collections.
+    pub fn double_ops_leaf(&self, name: &TypeName) -> TokenStream {
+        if self.map_type == MapType::BTreeMap && self.set_type == SetType::BTreeSet {
+            return quote!();
+        }
+
+        let name = self.type_name(name.name());
+        quote! {
+            impl conjure_object::private::DoubleOpsLeaf for #name {}
         }
     }
 
@@ -920,6 +1321,37 @@ impl Context {
         self.prelude_ident(name, "String", "std::string::String")
     }
 
+    pub fn string_type(&self, name: &TypeName) -> TokenStream {
+        match self.string_type {
+            StringType::String => self.string_ident(name),
+            StringType::ArcStr => quote!(std::sync::Arc<str>),
+        }
+    }
+
+    fn set_path(&self) -> &'static str {
+        match self.set_type {
+            SetType::BTreeSet => "std::collections::BTreeSet",
+            SetType::HashSet => "std::collections::HashSet",
+            SetType::IndexSet => "conjure_object::indexmap::IndexSet",
+        }
+    }
+
+    fn set_ident(&self) -> TokenStream {
+        self.set_path().parse().unwrap()
+    }
+
+    fn map_path(&self) -> &'static str {
+        match self.map_type {
+            MapType::BTreeMap => "std::collections::BTreeMap",
+            MapType::HashMap => "std::collections::HashMap",
+            MapType::IndexMap => "conjure_object::indexmap::IndexMap",
+        }
+    }
+
+    fn map_ident(&self) -> TokenStream {
+        self.map_path().parse().unwrap()
+    }
+
     pub fn vec_ident(&self, name: &TypeName) -> TokenStream {
         self.prelude_ident(name, "Vec", "std::vec::Vec")
     }
@@ -1006,16 +1438,55 @@ impl Context {
     }
 
     pub fn module_path(&self, base: BaseModule, name: &TypeName) -> Vec<String> {
+        let mut stripped = self.stripped_module_path(name);
+
+        // the top-level package is the crate itself
+        if self.crate_per_package.is_some() && !stripped.is_empty() {
+            stripped.remove(0);
+        }
+
+        stripped.insert(0, base.module());
+        stripped
+    }
+
+    fn stripped_module_path(&self, name: &TypeName) -> Vec<String> {
         let raw = self.raw_module_path(name.package());
 
-        let mut stripped = if raw.starts_with(&self.strip_prefix) {
+        if raw.starts_with(&self.strip_prefix) {
             raw[self.strip_prefix.len()..].to_vec()
         } else {
             raw
-        };
+        }
+    }
 
-        stripped.insert(0, base.module());
-        stripped
+    /// Returns the name of the crate containing a type when generating a crate per package.
+    pub fn crate_name(&self, name: &TypeName) -> Option<String> {
+        let base = self.crate_per_package.as_ref()?;
+
+        match self.stripped_module_path(name).first() {
+            Some(package) => Some(format!(
+                "{}-{}",
+                base,
+                package.trim_end_matches('_').replace('_', "-")
+            )),
+            None => Some(base.clone()),
+        }
+    }
+
+    /// Returns the crates referenced by each crate's generated code when generating a crate per package.
+    pub fn crate_dependencies(&self) -> BTreeMap<String, BTreeSet<String>> {
+        self.crate_dependencies.borrow().clone()
+    }
+
+    /// Returns the root modules of the external types referenced by a crate's generated code.
+    ///
+    /// The crate is identified by its name when generating a crate per package, and by `None` otherwise.
+    pub fn external_crates(&self, crate_name: Option<&str>) -> BTreeSet<String> {
+        self.external_crates
+            .borrow()
+            .get(&crate_name.map(str::to_owned))
+            .cloned()
+            .unwrap_or_default()
     }
 
     fn raw_module_path(&self, package: &str) -> Vec<String> {
@@ -1028,9 +1499,44 @@ impl Context {
         this_type: &TypeName,
         other_type: &TypeName,
     ) -> TokenStream {
+        if let Some(path) = self.external_types.get(other_type) {
+            let root = path.clone().into_iter().find_map(|t| match t {
+                TokenTree::Ident(ident) => Some(ident.to_string()),
+                _ => None,
+            });
+            if let Some(root) = root {
+                self.external_crates
+                    .borrow_mut()
+                    .entry(self.crate_name(this_type))
+                    .or_default()
+                    .insert(root);
+            }
+            return path.clone();
+        }
+
         let this_module_path = self.module_path(this_module, this_type);
         let other_module_path = self.module_path(BaseModule::Objects, other_type);
 
+        if let (Some(this_crate), Some(other_crate)) =
+            (self.crate_name(this_type), self.crate_name(other_type))
+        {
+            if this_crate != other_crate {
+                let crate_ident = Ident::new(&other_crate.replace('-', "_"), Span::call_site());
+                let components = other_module_path
+                    .iter()
+                    .map(|c| c.parse::<TokenStream>().unwrap());
+                let other_type_name = self.type_name(other_type.name());
+
+                self.crate_dependencies
+                    .borrow_mut()
+                    .entry(this_crate)
+                    .or_default()
+                    .insert(other_crate);
+
+                return quote!(::#crate_ident::#(#components::)* #other_type_name);
+            }
+        }
+
         let shared_prefix = this_module_path
             .iter()
             .zip(&other_module_path)
@@ -1167,6 +1673,99 @@ impl Context {
         matches!(self.type_log_safety_ref(name), Some(LogSafety::Safe))
     }
 
+    /// Returns `true` when this Conjure type resolves to `LogSafety::Unsafe` or `LogSafety::DoNotLog`.
+    pub fn is_unsafe_type(&self, name: &TypeName) -> bool {
+        matches!(
+            self.type_log_safety_ref(name),
+            Some(LogSafety::Unsafe | LogSafety::DoNotLog)
+        )
+    }
+
+    /// Returns `true` when a value with the given declared safety and type must not be logged.
+    ///
+    /// Unlike `is_unsafe_type`, referenced types are not inspected since they handle the safety of their own contents.
+    pub fn is_unsafe_value(&self, safety: Option<&LogSafety>, ty: &Type) -> bool {
+        matches!(
+            safety.cloned().or_else(|| self.shallow_log_safety(ty)),
+            Some(LogSafety::Unsafe | LogSafety::DoNotLog)
+        )
+    }
+
+    /// Returns `true` when a value with the given declared safety and type contains anything which must not be logged.
+    ///
+    /// Unlike `is_unsafe_value`, the contents of referenced types are inspected as well.
+    pub fn contains_unsafe_value(&self, safety: Option<&LogSafety>, ty: &Type) -> bool {
+        self.contains_unsafe_value_inner(safety, ty, &mut HashSet::new())
+    }
+
+    fn contains_unsafe_value_inner<'a>(
+        &'a self,
+        safety: Option<&LogSafety>,
+        ty: &'a Type,
+        visited: &mut HashSet<&'a TypeName>,
+    ) -> bool {
+        match safety {
+            Some(safety) => matches!(safety, LogSafety::Unsafe | LogSafety::DoNotLog),
+            None => self.contains_unsafe_type(ty, visited),
+        }
+    }
+
+    fn contains_unsafe_type<'a>(
+        &'a self,
+        ty: &'a Type,
+        visited: &mut HashSet<&'a TypeName>,
+    ) -> bool {
+        match ty {
+            Type::Primitive(primitive) => matches!(
+                self.primitive_log_safety(primitive),
+                Some(LogSafety::Unsafe | LogSafety::DoNotLog)
+            ),
+            Type::Optional(optional) => self.contains_unsafe_type(optional.item_type(), visited),
+            Type::List(list) => self.contains_unsafe_type(list.item_type(), visited),
+            Type::Set(set) => self.contains_unsafe_type(set.item_type(), visited),
+            Type::Map(map) => {
+                self.contains_unsafe_type(map.key_type(), visited)
+                    || self.contains_unsafe_type(map.value_type(), visited)
+            }
+            Type::Reference(name) => {
+                // recursive type definitions are handled at their first occurrence.
+                if !visited.insert(name) {
+                    return false;
+                }
+
+                match &self.types[name].def {
+                    TypeDefinition::Alias(alias) => {
+                        self.contains_unsafe_value_inner(alias.safety(), alias.alias(), visited)
+                    }
+                    TypeDefinition::Enum(_) => false,
+                    TypeDefinition::Object(object) => object
+                        .fields()
+                        .iter()
+                        .any(|f| self.contains_unsafe_value_inner(f.safety(), f.type_(), visited)),
+                    TypeDefinition::Union(union_) => union_
+                        .union_()
+                        .iter()
+                        .any(|f| self.contains_unsafe_value_inner(f.safety(), f.type_(), visited)),
+                }
+            }
+            Type::External(def) => self.contains_unsafe_type(self.resolve_external(def), visited),
+        }
+    }
+
+    fn shallow_log_safety(&self, ty: &Type) -> Option<LogSafety> {
+        match ty {
+            Type::Primitive(primitive) => self.primitive_log_safety(primitive),
+            Type::Optional(optional) => self.shallow_log_safety(optional.item_type()),
+            Type::List(list) => self.shallow_log_safety(list.item_type()),
+            Type::Set(set) => self.shallow_log_safety(set.item_type()),
+            Type::Map(map) => self.combine_safety(
+                self.shallow_log_safety(map.key_type()),
+                self.shallow_log_safety(map.value_type()),
+            ),
+            Type::Reference(_) | Type::External(_) => None,
+        }
+    }
+
     /// Returns `true` when a field of a safe-typed container needs an
     /// explicit `#[assert_is_safe]` because the field's Rust type doesn't
     /// statically resolve to `LogSafety::Safe`.
@@ -0,0 +1,144 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::context::Context;
+use crate::types::objects::{AliasDefinition, ObjectDefinition, UnionDefinition};
+
+/// Returns `true` if the object needs a redacting `Debug` implementation rather than a derived one.
+pub fn redacts_object(ctx: &Context, def: &ObjectDefinition) -> bool {
+    ctx.redacted_debug()
+        && def
+            .fields()
+            .iter()
+            .any(|f| ctx.is_unsafe_value(f.safety(), f.type_()))
+}
+
+/// Returns `true` if the union needs a redacting `Debug` implementation rather than a derived one.
+pub fn redacts_union(ctx: &Context, def: &UnionDefinition) -> bool {
+    ctx.redacted_debug()
+        && def
+            .union_()
+            .iter()
+            .any(|f| ctx.is_unsafe_value(f.safety(), f.type_()))
+}
+
+/// Returns `true` if the alias needs a redacting `Debug` implementation rather than a derived one.
+pub fn redacts_alias(ctx: &Context, def: &AliasDefinition) -> bool {
+    ctx.redacted_debug() && ctx.is_unsafe_value(def.safety(), def.alias())
+}
+
+pub fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if !redacts_object(ctx, def) {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let name_str = name.to_string();
+
+    let fields = def.fields().iter().map(|f| {
+        let field = ctx.field_name(f.field_name());
+        let field_str = field.to_string();
+        let value = if ctx.is_unsafe_value(f.safety(), f.type_()) {
+            quote!(&conjure_object::private::Redacted)
+        } else {
+            quote!(&self.#field)
+        };
+        quote!(.field(#field_str, #value))
+    });
+
+    debug_impl(
+        &name,
+        quote! {
+            fmt.debug_struct(#name_str)
+                #(#fields)*
+                .finish()
+        },
+    )
+}
+
+pub fn generate_union(ctx: &Context, def: &UnionDefinition, unknown: &TokenStream) -> TokenStream {
+    if !redacts_union(ctx, def) {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    let mut arms = def
+        .union_()
+        .iter()
+        .map(|f| {
+            let variant = ctx.type_name(f.field_name());
+            let variant_str = variant.to_string();
+            if ctx.is_unsafe_value(f.safety(), f.type_()) {
+                quote! {
+                    #name::#variant(_) => fmt
+                        .debug_tuple(#variant_str)
+                        .field(&conjure_object::private::Redacted)
+                        .finish(),
+                }
+            } else {
+                quote! {
+                    #name::#variant(v) => fmt.debug_tuple(#variant_str).field(v).finish(),
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if !ctx.exhaustive(def.type_name()) {
+        let unknown_str = unknown.to_string();
+        arms.push(quote! {
+            #name::#unknown(v) => fmt.debug_tuple(#unknown_str).field(v).finish(),
+        });
+    }
+
+    debug_impl(
+        &name,
+        quote! {
+            match self {
+                #(#arms)*
+            }
+        },
+    )
+}
+
+pub fn generate_alias(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    if !redacts_alias(ctx, def) {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let name_str = name.to_string();
+
+    debug_impl(
+        &name,
+        quote! {
+            fmt.debug_tuple(#name_str)
+                .field(&conjure_object::private::Redacted)
+                .finish()
+        },
+    )
+}
+
+fn debug_impl(name: &Ident, body: TokenStream) -> TokenStream {
+    quote! {
+        impl std::fmt::Debug for #name {
+            #[allow(deprecated)]
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                #body
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::context::Context;
+use crate::types::objects::{AliasDefinition, EnumDefinition, ObjectDefinition, UnionDefinition};
+
+pub fn generate_object(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    if !ctx.diff() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    let fields = def.fields().iter().map(|f| {
+        let field = ctx.field_name(f.field_name());
+        let wire_name = &f.field_name().0;
+        let redact = ctx.contains_unsafe_value(f.safety(), f.type_());
+        quote!(differ.field(#wire_name, #redact, &self.#field, &other.#field);)
+    });
+
+    let (other, differ) = if def.fields().is_empty() {
+        (quote!(_), quote!(_))
+    } else {
+        (quote!(other), quote!(differ))
+    };
+
+    diff_impl(
+        &name,
+        other,
+        differ,
+        quote! {
+            #(#fields)*
+        },
+    )
+}
+
+pub fn generate_union(ctx: &Context, def: &UnionDefinition, unknown: &TokenStream) -> TokenStream {
+    if !ctx.diff() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    if ctx.exhaustive(def.type_name()) && def.union_().is_empty() {
+        return diff_impl(&name, quote!(_), quote!(_), quote!(match *self {}));
+    }
+
+    let mut arms = def
+        .union_()
+        .iter()
+        .map(|f| {
+            let variant = ctx.type_name(f.field_name());
+            let wire_name = &f.field_name().0;
+            let redact = ctx.contains_unsafe_value(f.safety(), f.type_());
+            quote! {
+                (#name::#variant(a), #name::#variant(b)) => differ.field(#wire_name, #redact, a, b),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Unknown variants are redacted like a change of variant, since their contents are unknown.
+    let redact = ctx.is_unsafe_type(def.type_name());
+
+    if !ctx.exhaustive(def.type_name()) {
+        arms.push(quote! {
+            (#name::#unknown(a), #name::#unknown(b)) if a.type_ == b.type_ => {
+                differ.field(&a.type_, #redact, &a.value, &b.value)
+            }
+        });
+    }
+
+    // A change of variant is reported as a change of the whole value.
+    if !ctx.exhaustive(def.type_name()) || def.union_().len() > 1 {
+        arms.push(quote! {
+            _ => differ.with_redaction(#redact, |differ| differ.changed(self, other)),
+        });
+    }
+
+    diff_impl(
+        &name,
+        quote!(other),
+        quote!(differ),
+        quote! {
+            match (self, other) {
+                #(#arms)*
+            }
+        },
+    )
+}
+
+pub fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    if !ctx.diff() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    diff_impl(
+        &name,
+        quote!(other),
+        quote!(differ),
+        quote! {
+            if self != other {
+                differ.changed(self, other);
+            }
+        },
+    )
+}
+
+pub fn generate_alias(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    if !ctx.diff() {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+
+    let body = if ctx.contains_unsafe_value(def.safety(), def.alias()) {
+        quote! {
+            differ.with_redaction(true, |differ| {
+                conjure_object::diff::Diff::diff(&self.0, &other.0, differ)
+            })
+        }
+    } else {
+        quote!(conjure_object::diff::Diff::diff(&self.0, &other.0, differ))
+    };
+
+    diff_impl(&name, quote!(other), quote!(differ), body)
+}
+
+fn diff_impl(
+    name: &Ident,
+    other: TokenStream,
+    differ: TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    quote! {
+        impl conjure_object::diff::Diff for #name {
+            #[allow(deprecated)]
+            fn diff(&self, #other: &Self, #differ: &mut conjure_object::diff::Differ) {
+                #body
+            }
+        }
+    }
+}
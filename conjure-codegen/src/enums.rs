@@ -14,12 +14,19 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::arbitrary;
 use crate::context::Context;
-use crate::types::objects::EnumDefinition;
+use crate::diff;
+use crate::field_mask;
+use crate::types::objects::{Documentation, EnumDefinition};
 
 pub fn generate(ctx: &Context, def: &EnumDefinition) -> TokenStream {
     let enum_ = generate_enum(ctx, def);
     let unknown = generate_unknown(ctx, def);
+    let arbitrary = arbitrary::generate_enum(ctx, def);
+    let diff = diff::generate_enum(ctx, def);
+    let field_mask = field_mask::generate_enum(ctx, def);
+    let double_ops_leaf = ctx.double_ops_leaf(def.type_name());
 
     quote! {
         // https://github.com/serde-rs/serde/issues/2195
@@ -29,6 +36,10 @@ pub fn generate(ctx: &Context, def: &EnumDefinition) -> TokenStream {
 
         #enum_
         #unknown
+        #arbitrary
+        #diff
+        #field_mask
+        #double_ops_leaf
     }
 }
 
@@ -68,7 +79,7 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         }
     });
 
-    let other_variant = if ctx.exhaustive() {
+    let other_variant = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         quote! {
@@ -88,7 +99,7 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         }
     });
 
-    let as_str_other = if ctx.exhaustive() {
+    let as_str_other = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         quote!(#name::#unknown(v) => &*v,)
@@ -104,7 +115,7 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         }
     });
 
-    let from_str_other = if ctx.exhaustive() {
+    let from_str_other = if ctx.exhaustive(def.type_name()) {
         quote! {
             _ => #err(conjure_object::plain::ParseEnumError::new()),
         }
@@ -114,6 +125,45 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
         }
     };
 
+    let values = def.values().iter().map(|v| {
+        let variant = ctx.type_name(v.value());
+        quote!(#name::#variant)
+    });
+
+    let is_known = if ctx.exhaustive(def.type_name()) {
+        quote!(true)
+    } else {
+        quote!(!matches!(self, #name::#unknown(_)))
+    };
+
+    let docs_arms = def.values().iter().map(|v| {
+        let variant = ctx.type_name(v.value());
+        let allow_deprecated = ctx.allow_deprecated(v.deprecated());
+        let docs = option_str(ctx, def, v.docs());
+        quote! {
+            #allow_deprecated
+            #name::#variant => #docs,
+        }
+    });
+
+    let deprecated_arms = def.values().iter().map(|v| {
+        let variant = ctx.type_name(v.value());
+        let allow_deprecated = ctx.allow_deprecated(v.deprecated());
+        let deprecated = option_str(ctx, def, v.deprecated());
+        quote! {
+            #allow_deprecated
+            #name::#variant => #deprecated,
+        }
+    });
+
+    let option = ctx.option_ident(def.type_name());
+    let none = ctx.none_ident(def.type_name());
+    let metadata_other = if ctx.exhaustive(def.type_name()) {
+        quote!()
+    } else {
+        quote!(#name::#unknown(_) => #none,)
+    };
+
     quote! {
         #root_docs
         #[derive(#(#derives),*)]
@@ -132,6 +182,37 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
                     #as_str_other
                 }
             }
+
+            /// All known values of the enum, in definition order.
+            pub const VALUES: &'static [#name] = &[#(#values,)*];
+
+            /// Returns an iterator over all known values of the enum, in definition order.
+            #[inline]
+            pub fn iter() -> std::slice::Iter<'static, #name> {
+                #name::VALUES.iter()
+            }
+
+            /// Returns `true` if this is a known value of the enum.
+            #[inline]
+            pub fn is_known(&self) -> bool {
+                #is_known
+            }
+
+            /// Returns the documentation of the enum value, if it is known and documented.
+            pub fn docs(&self) -> #option<&'static str> {
+                match self {
+                    #(#docs_arms)*
+                    #metadata_other
+                }
+            }
+
+            /// Returns the deprecation message of the enum value, if it is known and deprecated.
+            pub fn deprecated(&self) -> #option<&'static str> {
+                match self {
+                    #(#deprecated_arms)*
+                    #metadata_other
+                }
+            }
         }
 
         impl fmt::Display for #name {
@@ -169,6 +250,17 @@ fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
     }
 }
 
+fn option_str(ctx: &Context, def: &EnumDefinition, docs: Option<&Documentation>) -> TokenStream {
+    match docs {
+        Some(docs) => {
+            let some = ctx.some_ident(def.type_name());
+            let docs = &**docs;
+            quote!(#some(#docs))
+        }
+        None => ctx.none_ident(def.type_name()),
+    }
+}
+
 fn unknown(ctx: &Context, def: &EnumDefinition) -> TokenStream {
     if ctx.type_name(def.type_name().name()) == "Unknown" {
         quote!(Unknown_)
@@ -178,7 +270,7 @@ fn unknown(ctx: &Context, def: &EnumDefinition) -> TokenStream {
 }
 
 fn generate_unknown(ctx: &Context, def: &EnumDefinition) -> TokenStream {
-    if ctx.exhaustive() {
+    if ctx.exhaustive(def.type_name()) {
         return quote!();
     }
 
@@ -16,18 +16,35 @@ use quote::quote;
 
 use crate::context::{BaseModule, Context};
 use crate::objects;
-use crate::types::objects::{ErrorDefinition, ObjectDefinition};
+use crate::types::objects::{
+    field_definition, ErrorDefinition, FieldDefinition, LogSafety, ObjectDefinition,
+};
+
+/// Returns the definition of an error's parameters struct.
+///
+/// When `redact_unsafe_args` is set, unsafe args without an explicit safety are treated as unsafe so that they're
+/// redacted in the struct's `Debug` and `Diff` output.
+pub fn error_object_definition(
+    def: &ErrorDefinition,
+    redact_unsafe_args: bool,
+) -> ObjectDefinition {
+    let unsafe_args = def.unsafe_args().iter().map(|arg| {
+        if redact_unsafe_args {
+            unsafe_arg(arg)
+        } else {
+            arg.clone()
+        }
+    });
 
-pub fn error_object_definition(def: &ErrorDefinition) -> ObjectDefinition {
     ObjectDefinition::builder()
         .type_name(def.error_name().clone())
-        .fields(def.safe_args().iter().chain(def.unsafe_args()).cloned())
+        .fields(def.safe_args().iter().cloned().chain(unsafe_args))
         .docs(def.docs().cloned())
         .build()
 }
 
 pub fn generate(ctx: &Context, def: &ErrorDefinition) -> TokenStream {
-    let object = error_object_definition(def);
+    let object = error_object_definition(def, ctx.redact_unsafe_error_args());
     let object_def = objects::generate(ctx, BaseModule::Errors, &object);
     let error_type = generate_error_type(ctx, def);
 
@@ -37,6 +54,16 @@ pub fn generate(ctx: &Context, def: &ErrorDefinition) -> TokenStream {
     }
 }
 
+fn unsafe_arg(arg: &FieldDefinition) -> FieldDefinition {
+    if arg.safety().is_some() {
+        return arg.clone();
+    }
+
+    field_definition::Builder::from(arg.clone())
+        .safety(LogSafety::Unsafe)
+        .build()
+}
+
 fn generate_error_type(ctx: &Context, def: &ErrorDefinition) -> TokenStream {
     let type_name = ctx.type_name(def.error_name().name());
     let code = ctx.type_name(def.code().as_str());
@@ -34,6 +34,34 @@ impl EnumExample {
             EnumExample::Unknown(v) => &*v,
         }
     }
+    /// All known values of the enum, in definition order.
+    pub const VALUES: &'static [EnumExample] = &[EnumExample::One, EnumExample::Two];
+    /// Returns an iterator over all known values of the enum, in definition order.
+    #[inline]
+    pub fn iter() -> std::slice::Iter<'static, EnumExample> {
+        EnumExample::VALUES.iter()
+    }
+    /// Returns `true` if this is a known value of the enum.
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        !matches!(self, EnumExample::Unknown(_))
+    }
+    /// Returns the documentation of the enum value, if it is known and documented.
+    pub fn docs(&self) -> Option<&'static str> {
+        match self {
+            EnumExample::One => None,
+            EnumExample::Two => None,
+            EnumExample::Unknown(_) => None,
+        }
+    }
+    /// Returns the deprecation message of the enum value, if it is known and deprecated.
+    pub fn deprecated(&self) -> Option<&'static str> {
+        match self {
+            EnumExample::One => None,
+            EnumExample::Two => None,
+            EnumExample::Unknown(_) => None,
+        }
+    }
 }
 impl fmt::Display for EnumExample {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
@@ -23,7 +23,7 @@ pub use self::boolean_alias_example::BooleanAliasExample;
 #[doc(inline)]
 pub use self::safe_long_example::SafeLongExample;
 #[doc(inline)]
-pub use self::single_union::SingleUnion;
+pub use self::single_union::{SingleUnion, SingleUnionVisitor};
 #[doc(inline)]
 pub use self::date_time_example::DateTimeExample;
 #[doc(inline)]
@@ -57,7 +57,7 @@ pub use self::alias_as_map_key_example::AliasAsMapKeyExample;
 #[doc(inline)]
 pub use self::bearer_token_example::BearerTokenExample;
 #[doc(inline)]
-pub use self::union_type_example::UnionTypeExample;
+pub use self::union_type_example::{UnionTypeExample, UnionTypeExampleVisitor};
 #[doc(inline)]
 pub use self::list_example::ListExample;
 #[doc(inline)]
@@ -81,7 +81,7 @@ pub use self::optional_example::OptionalExample;
 #[doc(inline)]
 pub use self::enum_example::EnumExample;
 #[doc(inline)]
-pub use self::union_::Union;
+pub use self::union_::{Union, UnionVisitor};
 #[doc(inline)]
 pub use self::uuid_alias_example::UuidAliasExample;
 #[doc(inline)]
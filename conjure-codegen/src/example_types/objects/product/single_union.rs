@@ -8,6 +8,50 @@ pub enum SingleUnion {
     /// An unknown variant.
     Unknown(Unknown),
 }
+impl SingleUnion {
+    /// Returns `true` if this is the `foo` variant.
+    #[inline]
+    pub fn is_foo(&self) -> bool {
+        matches!(self, SingleUnion::Foo(_))
+    }
+    /// Returns a reference to the value of the `foo` variant, if this is that variant.
+    #[inline]
+    pub fn as_foo(&self) -> Option<&str> {
+        match self {
+            SingleUnion::Foo(value) => Some(&**value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `foo` variant, if this is that variant.
+    #[inline]
+    pub fn into_foo(self) -> Option<String> {
+        match self {
+            SingleUnion::Foo(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: SingleUnionVisitor,
+    {
+        match self {
+            SingleUnion::Foo(value) => visitor.visit_foo(value),
+            SingleUnion::Unknown(value) => visitor.visit_unknown(value.type_()),
+        }
+    }
+}
+/// A visitor over the variants of the [`SingleUnion`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait SingleUnionVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `foo` variant.
+    fn visit_foo(self, value: &str) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for SingleUnion {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -9,6 +9,74 @@ pub enum Union {
     /// An unknown variant.
     Unknown(Unknown),
 }
+impl Union {
+    /// Returns `true` if this is the `foo` variant.
+    #[inline]
+    pub fn is_foo(&self) -> bool {
+        matches!(self, Union::Foo(_))
+    }
+    /// Returns a reference to the value of the `foo` variant, if this is that variant.
+    #[inline]
+    pub fn as_foo(&self) -> Option<&str> {
+        match self {
+            Union::Foo(value) => Some(&**value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `foo` variant, if this is that variant.
+    #[inline]
+    pub fn into_foo(self) -> Option<String> {
+        match self {
+            Union::Foo(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `bar` variant.
+    #[inline]
+    pub fn is_bar(&self) -> bool {
+        matches!(self, Union::Bar(_))
+    }
+    /// Returns a reference to the value of the `bar` variant, if this is that variant.
+    #[inline]
+    pub fn as_bar(&self) -> Option<&i32> {
+        match self {
+            Union::Bar(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `bar` variant, if this is that variant.
+    #[inline]
+    pub fn into_bar(self) -> Option<i32> {
+        match self {
+            Union::Bar(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: UnionVisitor,
+    {
+        match self {
+            Union::Foo(value) => visitor.visit_foo(value),
+            Union::Bar(value) => visitor.visit_bar(value),
+            Union::Unknown(value) => visitor.visit_unknown(value.type_()),
+        }
+    }
+}
+/// A visitor over the variants of the [`Union`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait UnionVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `foo` variant.
+    fn visit_foo(self, value: &str) -> Self::Output;
+    /// Visits the `bar` variant.
+    fn visit_bar(self, value: &i32) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for Union {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -15,6 +15,199 @@ pub enum UnionTypeExample {
     /// An unknown variant.
     Unknown(Unknown),
 }
+impl UnionTypeExample {
+    /// Returns `true` if this is the `stringExample` variant.
+    #[inline]
+    pub fn is_string_example(&self) -> bool {
+        matches!(self, UnionTypeExample::StringExample(_))
+    }
+    /// Returns a reference to the value of the `stringExample` variant, if this is that variant.
+    #[inline]
+    pub fn as_string_example(&self) -> Option<&super::StringExample> {
+        match self {
+            UnionTypeExample::StringExample(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `stringExample` variant, if this is that variant.
+    #[inline]
+    pub fn into_string_example(self) -> Option<super::StringExample> {
+        match self {
+            UnionTypeExample::StringExample(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `set` variant.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        matches!(self, UnionTypeExample::Set(_))
+    }
+    /// Returns a reference to the value of the `set` variant, if this is that variant.
+    #[inline]
+    pub fn as_set(&self) -> Option<&std::collections::BTreeSet<String>> {
+        match self {
+            UnionTypeExample::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `set` variant, if this is that variant.
+    #[inline]
+    pub fn into_set(self) -> Option<std::collections::BTreeSet<String>> {
+        match self {
+            UnionTypeExample::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `thisFieldIsAnInteger` variant.
+    #[inline]
+    pub fn is_this_field_is_an_integer(&self) -> bool {
+        matches!(self, UnionTypeExample::ThisFieldIsAnInteger(_))
+    }
+    /// Returns a reference to the value of the `thisFieldIsAnInteger` variant, if this is that variant.
+    #[inline]
+    pub fn as_this_field_is_an_integer(&self) -> Option<&i32> {
+        match self {
+            UnionTypeExample::ThisFieldIsAnInteger(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `thisFieldIsAnInteger` variant, if this is that variant.
+    #[inline]
+    pub fn into_this_field_is_an_integer(self) -> Option<i32> {
+        match self {
+            UnionTypeExample::ThisFieldIsAnInteger(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `alsoAnInteger` variant.
+    #[inline]
+    pub fn is_also_an_integer(&self) -> bool {
+        matches!(self, UnionTypeExample::AlsoAnInteger(_))
+    }
+    /// Returns a reference to the value of the `alsoAnInteger` variant, if this is that variant.
+    #[inline]
+    pub fn as_also_an_integer(&self) -> Option<&i32> {
+        match self {
+            UnionTypeExample::AlsoAnInteger(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `alsoAnInteger` variant, if this is that variant.
+    #[inline]
+    pub fn into_also_an_integer(self) -> Option<i32> {
+        match self {
+            UnionTypeExample::AlsoAnInteger(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `if` variant.
+    #[inline]
+    pub fn is_if(&self) -> bool {
+        matches!(self, UnionTypeExample::If(_))
+    }
+    /// Returns a reference to the value of the `if` variant, if this is that variant.
+    #[inline]
+    pub fn as_if(&self) -> Option<&i32> {
+        match self {
+            UnionTypeExample::If(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `if` variant, if this is that variant.
+    #[inline]
+    pub fn into_if(self) -> Option<i32> {
+        match self {
+            UnionTypeExample::If(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `new` variant.
+    #[inline]
+    pub fn is_new(&self) -> bool {
+        matches!(self, UnionTypeExample::New(_))
+    }
+    /// Returns a reference to the value of the `new` variant, if this is that variant.
+    #[inline]
+    pub fn as_new(&self) -> Option<&i32> {
+        match self {
+            UnionTypeExample::New(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `new` variant, if this is that variant.
+    #[inline]
+    pub fn into_new(self) -> Option<i32> {
+        match self {
+            UnionTypeExample::New(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `interface` variant.
+    #[inline]
+    pub fn is_interface(&self) -> bool {
+        matches!(self, UnionTypeExample::Interface(_))
+    }
+    /// Returns a reference to the value of the `interface` variant, if this is that variant.
+    #[inline]
+    pub fn as_interface(&self) -> Option<&i32> {
+        match self {
+            UnionTypeExample::Interface(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `interface` variant, if this is that variant.
+    #[inline]
+    pub fn into_interface(self) -> Option<i32> {
+        match self {
+            UnionTypeExample::Interface(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: UnionTypeExampleVisitor,
+    {
+        match self {
+            UnionTypeExample::StringExample(value) => visitor.visit_string_example(value),
+            UnionTypeExample::Set(value) => visitor.visit_set(value),
+            UnionTypeExample::ThisFieldIsAnInteger(value) => {
+                visitor.visit_this_field_is_an_integer(value)
+            }
+            UnionTypeExample::AlsoAnInteger(value) => {
+                visitor.visit_also_an_integer(value)
+            }
+            UnionTypeExample::If(value) => visitor.visit_if(value),
+            UnionTypeExample::New(value) => visitor.visit_new(value),
+            UnionTypeExample::Interface(value) => visitor.visit_interface(value),
+            UnionTypeExample::Unknown(value) => visitor.visit_unknown(value.type_()),
+        }
+    }
+}
+/// A visitor over the variants of the [`UnionTypeExample`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait UnionTypeExampleVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `stringExample` variant.
+    /// Docs for when UnionTypeExample is of type StringExample.
+    fn visit_string_example(self, value: &super::StringExample) -> Self::Output;
+    /// Visits the `set` variant.
+    fn visit_set(self, value: &std::collections::BTreeSet<String>) -> Self::Output;
+    /// Visits the `thisFieldIsAnInteger` variant.
+    fn visit_this_field_is_an_integer(self, value: &i32) -> Self::Output;
+    /// Visits the `alsoAnInteger` variant.
+    fn visit_also_an_integer(self, value: &i32) -> Self::Output;
+    /// Visits the `if` variant.
+    fn visit_if(self, value: &i32) -> Self::Output;
+    /// Visits the `new` variant.
+    fn visit_new(self, value: &i32) -> Self::Output;
+    /// Visits the `interface` variant.
+    fn visit_interface(self, value: &i32) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for UnionTypeExample {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
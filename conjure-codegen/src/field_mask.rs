@@ -0,0 +1,303 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::context::{BaseModule, Context};
+use crate::objects;
+use crate::types::objects::{
+    AliasDefinition, EnumDefinition, ObjectDefinition, TypeName, UnionDefinition,
+};
+use crate::unions;
+
+pub fn field_enum_name(ctx: &Context, def: &ObjectDefinition) -> String {
+    format!("{}Field", ctx.type_name(def.type_name().name()))
+}
+
+pub fn generate_object(
+    ctx: &Context,
+    base_module: BaseModule,
+    def: &ObjectDefinition,
+) -> TokenStream {
+    if !ctx.field_masks() || !matches!(base_module, BaseModule::Objects) {
+        return quote!();
+    }
+
+    let field_enum = generate_field_enum(ctx, def);
+
+    let name = ctx.type_name(def.type_name().name());
+    let name_str = name.to_string();
+    let some = ctx.some_ident(def.type_name());
+
+    let mut required = 0usize;
+    let mut optional = vec![];
+    let masks = def
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let mask = Ident::new(&format!("mask_{i}"), Span::call_site());
+            let field = ctx.field_name(f.field_name());
+            let wire_name = &f.field_name().0;
+
+            if ctx.is_required(f.type_()) {
+                required += 1;
+                return quote!(let #mask = mask.get_required(#wire_name););
+            }
+
+            optional.push(mask.clone());
+            let is_empty = if ctx.serialize_empty_collections(def.type_name()) {
+                None
+            } else {
+                ctx.is_empty_method(def.type_name(), f.type_())
+            };
+            match is_empty {
+                Some(is_empty) => {
+                    let is_empty = is_empty.parse::<TokenStream>().unwrap();
+                    quote! {
+                        let #mask = mask.get(#wire_name).filter(|_| !#is_empty(&self.#field));
+                    }
+                }
+                None => quote!(let #mask = mask.get(#wire_name);),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let serialize_fields = def.fields().iter().enumerate().map(|(i, f)| {
+        let mask = Ident::new(&format!("mask_{i}"), Span::call_site());
+        let field = ctx.field_name(f.field_name());
+        let wire_name = &f.field_name().0;
+
+        let value = if objects::serializes_keys_as_strings(ctx, f) {
+            quote!(&conjure_object::private::ProjectedMapKeysAsStrings(&self.#field, #mask))
+        } else {
+            quote!(&conjure_object::field_mask::Projected::new(&self.#field, #mask))
+        };
+        let serialize_field = quote! {
+            conjure_object::serde::ser::SerializeStruct::serialize_field(&mut s, #wire_name, #value)?;
+        };
+
+        if ctx.is_required(f.type_()) {
+            serialize_field
+        } else {
+            quote! {
+                if let #some(#mask) = #mask {
+                    #serialize_field
+                } else {
+                    conjure_object::serde::ser::SerializeStruct::skip_field(&mut s, #wire_name)?;
+                }
+            }
+        }
+    });
+
+    let mut_ = if def.fields().is_empty() {
+        quote!()
+    } else {
+        quote!(mut)
+    };
+
+    let project = project_impl(
+        ctx,
+        def.type_name(),
+        quote!(mask),
+        quote! {
+            if mask.is_all() {
+                return conjure_object::serde::Serialize::serialize(self, serializer);
+            }
+
+            #(#masks)*
+            let len = #required #(+ usize::from(#optional.is_some()))*;
+
+            let #mut_ s = conjure_object::serde::Serializer::serialize_struct(serializer, #name_str, len)?;
+            #(#serialize_fields)*
+            conjure_object::serde::ser::SerializeStruct::end(s)
+        },
+    );
+
+    quote! {
+        #field_enum
+
+        #project
+    }
+}
+
+fn generate_field_enum(ctx: &Context, def: &ObjectDefinition) -> TokenStream {
+    let object = ctx.type_name(def.type_name().name());
+    let name = Ident::new(&field_enum_name(ctx, def), Span::call_site());
+    let doc = format!("The fields of the [`{object}`] object.");
+
+    let variants = def.fields().iter().map(|f| {
+        let docs = ctx.docs(f.docs());
+        let variant = ctx.type_name(f.field_name());
+        quote! {
+            #docs
+            #variant,
+        }
+    });
+
+    let as_str_arms = def.fields().iter().map(|f| {
+        let variant = ctx.type_name(f.field_name());
+        let wire_name = &f.field_name().0;
+        quote!(#name::#variant => #wire_name,)
+    });
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum #name {
+            #(#variants)*
+        }
+
+        impl #name {
+            /// Returns the serialized name of the field.
+            #[inline]
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#as_str_arms)*
+                }
+            }
+        }
+
+        impl std::convert::AsRef<str> for #name {
+            #[inline]
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl std::fmt::Display for #name {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(self.as_str(), fmt)
+            }
+        }
+    }
+}
+
+pub fn generate_union(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    if !ctx.field_masks() {
+        return quote!();
+    }
+
+    if def.union_().is_empty() && ctx.exhaustive(def.type_name()) {
+        return leaf_impl(ctx, def.type_name());
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let some = ctx.some_ident(def.type_name());
+
+    let serialize_unknown = if ctx.exhaustive(def.type_name()) {
+        quote!()
+    } else {
+        let unknown = unions::unknown(ctx, def);
+        quote! {
+            #name::#unknown(value) => {
+                conjure_object::serde::ser::SerializeMap::serialize_entry(&mut map, &"type", &value.type_)?;
+                conjure_object::serde::ser::SerializeMap::serialize_entry(&mut map, &value.type_, &value.value)?;
+            }
+        }
+    };
+
+    let variants = unions::variants(ctx, def);
+    let serialize_variants = def.union_().iter().zip(&variants).map(|(f, variant)| {
+        let variant_str = &f.field_name().0;
+        quote! {
+            #name::#variant(value) => {
+                conjure_object::serde::ser::SerializeMap::serialize_entry(&mut map, &"type", &#variant_str)?;
+                conjure_object::serde::ser::SerializeMap::serialize_entry(
+                    &mut map,
+                    &#variant_str,
+                    &conjure_object::field_mask::Projected::new(value, mask),
+                )?;
+            }
+        }
+    });
+
+    project_impl(
+        ctx,
+        def.type_name(),
+        quote!(mask),
+        quote! {
+            if mask.is_all() {
+                return conjure_object::serde::Serialize::serialize(self, serializer);
+            }
+
+            let mut map = conjure_object::serde::Serializer::serialize_map(serializer, #some(2))?;
+            match self {
+                #(#serialize_variants)*
+                #serialize_unknown
+            }
+            conjure_object::serde::ser::SerializeMap::end(map)
+        },
+    )
+}
+
+pub fn generate_enum(ctx: &Context, def: &EnumDefinition) -> TokenStream {
+    if !ctx.field_masks() {
+        return quote!();
+    }
+
+    leaf_impl(ctx, def.type_name())
+}
+
+pub fn generate_alias(ctx: &Context, def: &AliasDefinition) -> TokenStream {
+    if !ctx.field_masks() {
+        return quote!();
+    }
+
+    project_impl(
+        ctx,
+        def.type_name(),
+        quote!(mask),
+        quote!(conjure_object::field_mask::Project::serialize_projected(
+            &self.0, mask, serializer
+        )),
+    )
+}
+
+fn leaf_impl(ctx: &Context, type_name: &TypeName) -> TokenStream {
+    project_impl(
+        ctx,
+        type_name,
+        quote!(_),
+        quote!(conjure_object::serde::Serialize::serialize(
+            self, serializer
+        )),
+    )
+}
+
+fn project_impl(
+    ctx: &Context,
+    type_name: &TypeName,
+    mask: TokenStream,
+    body: TokenStream,
+) -> TokenStream {
+    let name = ctx.type_name(type_name.name());
+    let result = ctx.result_ident(type_name);
+
+    quote! {
+        impl conjure_object::field_mask::Project for #name {
+            #[allow(deprecated)]
+            fn serialize_projected<S>(
+                &self,
+                #mask: &conjure_object::field_mask::FieldMask,
+                serializer: S,
+            ) -> #result<S::Ok, S::Error>
+            where
+                S: conjure_object::serde::Serializer,
+            {
+                #body
+            }
+        }
+    }
+}
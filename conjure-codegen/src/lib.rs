@@ -67,6 +67,9 @@
 //! | `set<T>`      | `BTreeSet<T>`                        |
 //! | `map<K, V>`   | `BTreeMap<K, V>`                     |
 //!
+//! The representations of `string`, `set<T>`, and `map<K, V>` can be changed with [`Config::string_type`],
+//! [`Config::set_type`], and [`Config::map_type`].
+//!
 //! Many of these are exposed by the `conjure-object` crate, which is a required dependency of crates containing the
 //! generated code.
 //!
@@ -117,6 +120,11 @@
 //! The generated structs implement `Debug`, `Clone`, `PartialEq`, Eq, `PartialOrd`, `Ord`, `Hash`, `Serialize`, and
 //! `Deserialize`. They implement `Copy` if they consist entirely of copyable primitive types.
 //!
+//! If [`Config::field_masks`] is enabled, each object also has a `FooField` enum naming its fields, and all generated
+//! types implement `conjure_object::field_mask::Project`. Projecting an object through a `FieldMask` omits unselected
+//! optional and collection fields from its serialized form, while required fields are always included so that the
+//! output remains valid.
+//!
 //! ## Unions
 //!
 //! Conjure unions turn into Rust enums. By default, unions are *extensible* through an additional `Unknown` variant.
@@ -145,6 +153,10 @@
 //! `Deserialize`. Union variants which are themselves unions are boxed in the generated enum to avoid self-referential
 //! type definitions.
 //!
+//! Each variant also has `is_*`, `as_*`, and `into_*` methods, and a `FooVisitor` trait is generated alongside a union
+//! `Foo` with a method for each variant and a `visit_unknown` method. Code using the visitor via `Foo::accept` or the
+//! accessor methods doesn't need to change if the union is generated with or without exhaustive matching.
+//!
 //! ## Enums
 //!
 //! Conjure enums turn into Rust enums. By default, enums are *extensible*. This allows enums to be forward-compatible
@@ -163,6 +175,10 @@
 //! The generated enums implement `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `Display`,
 //! `Serialize`, and `Deserialize`.
 //!
+//! The known values of an enum can be enumerated via its `VALUES` constant or `iter` method, and the documentation and
+//! deprecation message of each value are available at runtime via its `docs` and `deprecated` methods. The `is_known`
+//! method returns `false` for unknown variants of extensible enums.
+//!
 //! ## Aliases
 //!
 //! Conjure aliases turn into Rust newtype structs that act like their inner value:
@@ -288,31 +304,40 @@
 
 use crate::context::Context;
 use crate::merge_toml::left_merge;
-use crate::types::objects::{ConjureDefinition, TypeDefinition};
+use crate::types::objects::{ConjureDefinition, TypeDefinition, TypeName};
 use anyhow::{bail, Context as _, Error};
 use context::BaseModule;
 use proc_macro2::TokenStream;
 use quote::quote;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::path::{Component, Path, PathBuf};
 use toml::Value;
 
 mod aliases;
+mod arbitrary;
 mod cargo_toml;
 mod clients;
 mod context;
+mod debug;
+mod diff;
 mod enums;
 mod errors;
+mod field_mask;
 mod merge_toml;
 mod objects;
+mod patches;
 mod servers;
-#[allow(dead_code, clippy::all)]
+/// The Conjure IR types.
+#[allow(dead_code, missing_docs, clippy::all)]
 #[rustfmt::skip]
-mod types;
+pub mod types;
 mod human_size;
+pub mod merge;
 mod unions;
+pub mod yaml;
 
 /// Examples of generated Conjure code.
 ///
@@ -333,10 +358,23 @@ pub struct Config {
     serialize_empty_collections: bool,
     use_legacy_error_serialization: bool,
     public_fields: bool,
+    arbitrary: bool,
+    patches: bool,
+    diff: bool,
+    redacted_debug: bool,
+    field_masks: bool,
     strip_prefix: Option<String>,
     version: Option<String>,
     build_crate: Option<CrateInfo>,
+    crate_per_package: bool,
+    map_type: MapType,
+    set_type: SetType,
+    string_type: StringType,
     extra_manifest_config: Option<Value>,
+    package_overrides: BTreeMap<String, Overrides>,
+    type_overrides: BTreeMap<String, Overrides>,
+    external_types: BTreeMap<String, String>,
+    external_crates: BTreeMap<String, Value>,
 }
 
 impl Default for Config {
@@ -353,10 +391,23 @@ impl Config {
             serialize_empty_collections: false,
             use_legacy_error_serialization: true,
             public_fields: false,
+            arbitrary: false,
+            patches: false,
+            diff: false,
+            redacted_debug: false,
+            field_masks: false,
             strip_prefix: None,
             version: None,
             build_crate: None,
+            crate_per_package: false,
+            map_type: MapType::BTreeMap,
+            set_type: SetType::BTreeSet,
+            string_type: StringType::String,
             extra_manifest_config: None,
+            package_overrides: BTreeMap::new(),
+            type_overrides: BTreeMap::new(),
+            external_types: BTreeMap::new(),
+            external_crates: BTreeMap::new(),
         }
     }
 
@@ -413,6 +464,98 @@ impl Config {
         self
     }
 
+    /// Controls generation of `proptest` `Arbitrary` implementations for objects, unions, enums, and aliases.
+    ///
+    /// The generated code requires the `proptest` feature of the `conjure-object` crate. Strategies for extensible
+    /// unions and enums also generate unknown variants, and fields which recursively contain their own type are left
+    /// empty.
+    ///
+    /// Defaults to `false`.
+    pub fn arbitrary(&mut self, arbitrary: bool) -> &mut Config {
+        self.arbitrary = arbitrary;
+        self
+    }
+
+    /// Controls generation of partial-update types for objects.
+    ///
+    /// If enabled, a `FooPatch` type is generated alongside each object `Foo`. It has an `Option`-wrapped public field
+    /// for each field of `Foo`, an `apply` method which overwrites the fields of a `Foo` that are set in the patch, and
+    /// a `diff` constructor which computes the patch between two `Foo`s. Objects with no required fields additionally
+    /// implement `Default`.
+    ///
+    /// Defaults to `false`.
+    pub fn patches(&mut self, patches: bool) -> &mut Config {
+        self.patches = patches;
+        self
+    }
+
+    /// Controls generation of `conjure_object::diff::Diff` implementations for objects, unions, enums, and aliases.
+    ///
+    /// The implementations report differences at the path of each field, list index, and map key. Values of fields
+    /// with a log safety of `UNSAFE` or `DO_NOT_LOG` are redacted in the reported differences.
+    ///
+    /// Defaults to `false`.
+    pub fn diff(&mut self, diff: bool) -> &mut Config {
+        self.diff = diff;
+        self
+    }
+
+    /// Controls redaction of unsafe values in the `Debug` implementations of generated types.
+    ///
+    /// If enabled, the `Debug` output of objects, unions, and errors replaces the values of fields with a log safety of
+    /// `UNSAFE` or `DO_NOT_LOG` with a placeholder, as does that of aliases which are themselves unsafe. The unsafe
+    /// arguments of errors are also redacted.
+    ///
+    /// Defaults to `false`.
+    pub fn redacted_debug(&mut self, redacted_debug: bool) -> &mut Config {
+        self.redacted_debug = redacted_debug;
+        self
+    }
+
+    /// Controls generation of field mask support for types and services.
+    ///
+    /// If enabled, a `FooField` enum naming the fields of each object `Foo` is generated, and objects, unions, enums,
+    /// and aliases implement `conjure_object::field_mask::Project`. Server endpoints with a JSON response honor a
+    /// `fields` query parameter, serializing only the selected fields of the response.
+    ///
+    /// Defaults to `false`.
+    pub fn field_masks(&mut self, field_masks: bool) -> &mut Config {
+        self.field_masks = field_masks;
+        self
+    }
+
+    /// Sets the Rust type used to represent Conjure `map`s.
+    ///
+    /// `HashMap` and `IndexMap` can't be compared or hashed themselves, so generated types containing them implement
+    /// those traits as if the entries were sorted by key. `IndexMap` preserves the order of entries when deserialized
+    /// values are reserialized, and requires the `indexmap` feature of the `conjure-object` crate.
+    ///
+    /// Defaults to [`MapType::BTreeMap`].
+    pub fn map_type(&mut self, map_type: MapType) -> &mut Config {
+        self.map_type = map_type;
+        self
+    }
+
+    /// Sets the Rust type used to represent Conjure `set`s.
+    ///
+    /// Like with [`Self::map_type`], generated types containing `HashSet` or `IndexSet` implement comparison and
+    /// hashing as if the elements were sorted, and `IndexSet` requires the `indexmap` feature of the `conjure-object`
+    /// crate.
+    ///
+    /// Defaults to [`SetType::BTreeSet`].
+    pub fn set_type(&mut self, set_type: SetType) -> &mut Config {
+        self.set_type = set_type;
+        self
+    }
+
+    /// Sets the Rust type used to represent Conjure `string`s.
+    ///
+    /// Defaults to [`StringType::String`].
+    pub fn string_type(&mut self, string_type: StringType) -> &mut Config {
+        self.string_type = string_type;
+        self
+    }
+
     /// Sets a prefix that will be stripped from package names.
     ///
     /// Defaults to `None`.
@@ -446,6 +589,59 @@ impl Config {
         self
     }
 
+    /// Overrides settings for the types in a package.
+    ///
+    /// The overrides apply to the package and all of its subpackages. If the overrides of multiple packages apply to a
+    /// type, those of the most specific package take precedence. Generation fails if the package does not contain any
+    /// types.
+    ///
+    /// Defaults to no overrides.
+    pub fn package_overrides(&mut self, package: &str, overrides: Overrides) -> &mut Config {
+        self.package_overrides
+            .insert(package.to_string(), overrides);
+        self
+    }
+
+    /// Overrides settings for a single type, identified by its fully qualified name (e.g. `com.company.foo.Foo`).
+    ///
+    /// Type overrides apply to objects, unions, enums, and errors, and take precedence over package overrides.
+    /// Generation fails if the type does not exist.
+    ///
+    /// Defaults to no overrides.
+    pub fn type_overrides(&mut self, type_name: &str, overrides: Overrides) -> &mut Config {
+        self.type_overrides.insert(type_name.to_string(), overrides);
+        self
+    }
+
+    /// Maps a Conjure type to an existing Rust type, identified by their fully qualified names (e.g.
+    /// `com.company.foo.Foo` and `foo_api::objects::Foo`).
+    ///
+    /// No code is generated for a mapped type defined in the IR, and references to it use the Rust type instead. This
+    /// allows types generated in one crate to be shared by others. IR external references can be mapped the same way
+    /// by their name, in which case the Rust type is treated like a generated alias of the reference's fallback type.
+    ///
+    /// Defaults to no mappings.
+    pub fn external_type(&mut self, conjure_type: &str, rust_type: &str) -> &mut Config {
+        self.external_types
+            .insert(conjure_type.to_string(), rust_type.to_string());
+        self
+    }
+
+    /// Sets the dependency added to the generated Cargo.toml for a crate containing external types.
+    ///
+    /// The dependency is added if the generated code references a type mapped by [`Self::external_type`] to a path in
+    /// the crate. It can be anything that is valid in a Cargo.toml, like a version string or a table with a `path` key.
+    ///
+    /// Defaults to no dependencies.
+    pub fn external_crate<T>(&mut self, name: &str, dependency: T) -> &mut Config
+    where
+        T: Into<Value>,
+    {
+        self.external_crates
+            .insert(name.to_string(), dependency.into());
+        self
+    }
+
     /// Switches generation to create a full crate.
     ///
     /// Defaults to just generating a single module.
@@ -457,6 +653,20 @@ impl Config {
         self
     }
 
+    /// Splits the generated crate into a Cargo workspace with one crate per top-level package.
+    ///
+    /// Top-level packages are determined after removing the prefix set by [`Self::strip_prefix`]. The crate for a
+    /// top-level package `foo` is named `<name>-foo`, and definitions directly in the stripped prefix are placed in a
+    /// crate named `<name>`, where `<name>` is the name passed to [`Self::build_crate`]. Each crate depends on the
+    /// crates whose types it references, so packages can't reference each other cyclically. This has no effect unless
+    /// [`Self::build_crate`] is set.
+    ///
+    /// Defaults to `false`.
+    pub fn crate_per_package(&mut self, crate_per_package: bool) -> &mut Config {
+        self.crate_per_package = crate_per_package;
+        self
+    }
+
     /// Generates Rust source files from a JSON-encoded Conjure IR file.
     pub fn generate_files<P, Q>(&self, ir_file: P, out_dir: Q) -> Result<(), Error>
     where
@@ -468,24 +678,176 @@ impl Config {
 
     fn generate_files_inner(&self, ir_file: &Path, out_dir: &Path) -> Result<(), Error> {
         let defs = self.parse_ir(ir_file)?;
+        self.generate(&defs)?.write_to(out_dir)
+    }
 
-        if defs.version() != 1 {
-            bail!("unsupported IR version {}", defs.version());
+    /// Checks that the files previously generated from a JSON-encoded Conjure IR file are up to date.
+    ///
+    /// An error is returned if [`Self::generate_files`] would make any changes to the output directory.
+    pub fn verify_files<P, Q>(&self, ir_file: P, out_dir: Q) -> Result<(), Error>
+    where
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let defs = self.parse_ir(ir_file.as_ref())?;
+        self.generate(&defs)?.verify(out_dir)
+    }
+
+    /// Generates Rust source files from multiple JSON-encoded Conjure IR files.
+    ///
+    /// The definitions in the files are combined into a single module tree as described in [`merge::merge`].
+    /// Definitions shared between the files are only generated once, and an error is returned if two files define the
+    /// same name differently.
+    pub fn generate_merged_files<I, P, Q>(&self, ir_files: I, out_dir: Q) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let defs = self.parse_merged_ir(ir_files)?;
+        self.generate(&defs)?.write_to(out_dir)
+    }
+
+    /// Checks that the files previously generated from multiple JSON-encoded Conjure IR files are up to date.
+    ///
+    /// An error is returned if [`Self::generate_merged_files`] would make any changes to the output directory.
+    pub fn verify_merged_files<I, P, Q>(&self, ir_files: I, out_dir: Q) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+        Q: AsRef<Path>,
+    {
+        let defs = self.parse_merged_ir(ir_files)?;
+        self.generate(&defs)?.verify(out_dir)
+    }
+
+    /// Generates Rust source files from a Conjure definition in memory.
+    ///
+    /// This behaves like [`Self::generate_files`], but returns the generated files rather than writing them to disk.
+    pub fn generate(&self, defs: &ConjureDefinition) -> Result<GeneratedCrate, Error> {
+        self.validate(defs)?;
+
+        if let (Some(info), true) = (&self.build_crate, self.crate_per_package) {
+            return self.generate_workspace(info, defs);
         }
 
-        let modules = self.create_modules(&defs);
+        let context = self.context(defs, None)?;
+        let modules = self
+            .create_modules(&context, defs)?
+            .remove(&None)
+            .unwrap_or_else(ModuleTrie::new);
+        let mut files = BTreeMap::new();
         let (src_dir, lib_root) = if self.build_crate.is_some() {
-            (out_dir.join("src"), true)
+            (PathBuf::from("src"), true)
         } else {
-            (out_dir.to_path_buf(), false)
+            (PathBuf::new(), false)
         };
 
         if let Some(info) = &self.build_crate {
-            self.write_cargo_toml(out_dir, info, &defs)?;
-            self.write_rustfmt_toml(out_dir)?;
+            files.insert(
+                PathBuf::from("Cargo.toml"),
+                self.cargo_toml(info, defs, &BTreeSet::new(), &context.external_crates(None))?,
+            );
+            files.insert(PathBuf::from("rustfmt.toml"), self.rustfmt_toml());
         }
 
-        modules.render(&src_dir, lib_root)?;
+        modules.render(&src_dir, lib_root, &mut files)?;
+
+        Ok(GeneratedCrate { files })
+    }
+
+    fn generate_workspace(
+        &self,
+        info: &CrateInfo,
+        defs: &ConjureDefinition,
+    ) -> Result<GeneratedCrate, Error> {
+        let context = self.context(defs, Some(&info.name))?;
+        let modules = self.create_modules(&context, defs)?;
+        let dependencies = context.crate_dependencies();
+        check_crate_cycles(&dependencies)?;
+
+        let members = modules
+            .keys()
+            .map(|name| name.clone().unwrap_or_else(|| info.name.clone()))
+            .collect::<Vec<_>>();
+
+        let mut files = BTreeMap::new();
+        files.insert(PathBuf::from("Cargo.toml"), self.workspace_toml(&members)?);
+        files.insert(PathBuf::from("rustfmt.toml"), self.rustfmt_toml());
+
+        for (name, modules) in members.iter().zip(modules.values()) {
+            let in_crate =
+                |type_name: &TypeName| context.crate_name(type_name).as_ref() == Some(name);
+            let member_defs = ConjureDefinition::builder()
+                .version(defs.version())
+                .types(
+                    defs.types()
+                        .iter()
+                        .filter(|def| in_crate(definition_name(def)))
+                        .cloned(),
+                )
+                .errors(
+                    defs.errors()
+                        .iter()
+                        .filter(|def| in_crate(def.error_name()))
+                        .cloned(),
+                )
+                .services(
+                    defs.services()
+                        .iter()
+                        .filter(|def| in_crate(def.service_name()))
+                        .cloned(),
+                )
+                .extensions(defs.extensions().clone())
+                .build();
+
+            let member_info = CrateInfo {
+                name: name.clone(),
+                version: info.version.clone(),
+            };
+            let member_dependencies = dependencies.get(name).cloned().unwrap_or_default();
+
+            let dir = PathBuf::from(name);
+            files.insert(
+                dir.join("Cargo.toml"),
+                self.cargo_toml(
+                    &member_info,
+                    &member_defs,
+                    &member_dependencies,
+                    &context.external_crates(Some(name)),
+                )?,
+            );
+            modules.render(&dir.join("src"), true, &mut files)?;
+        }
+
+        Ok(GeneratedCrate { files })
+    }
+
+    /// Generates Rust code from a Conjure definition as a sequence of inline modules.
+    ///
+    /// This is intended for use by procedural macros, and requires the `proc-macro` Cargo feature to produce tokens
+    /// usable by the compiler. The [`Self::build_crate`] setting is ignored.
+    pub fn generate_tokens(&self, defs: &ConjureDefinition) -> Result<TokenStream, Error> {
+        self.validate(defs)?;
+
+        let context = self.context(defs, None)?;
+        Ok(self
+            .create_modules(&context, defs)?
+            .remove(&None)
+            .unwrap_or_else(ModuleTrie::new)
+            .inline(true))
+    }
+
+    fn validate(&self, defs: &ConjureDefinition) -> Result<(), Error> {
+        if defs.version() != 1 {
+            bail!("unsupported IR version {}", defs.version());
+        }
+
+        for (conjure_type, rust_type) in &self.external_types {
+            if syn::parse_str::<syn::Path>(rust_type).is_err() {
+                bail!("invalid Rust type `{rust_type}` for external type {conjure_type}");
+            }
+        }
 
         Ok(())
     }
@@ -500,54 +862,133 @@ impl Config {
         Ok(defs)
     }
 
-    fn create_modules(&self, defs: &ConjureDefinition) -> ModuleTrie {
-        let context = Context::new(
+    fn parse_merged_ir<I, P>(&self, ir_files: I) -> Result<ConjureDefinition, Error>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let inputs = ir_files
+            .into_iter()
+            .map(|ir_file| {
+                let ir_file = ir_file.as_ref();
+                Ok((ir_file.display().to_string(), self.parse_ir(ir_file)?))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        merge::merge(inputs)
+    }
+
+    fn context(
+        &self,
+        defs: &ConjureDefinition,
+        crate_per_package: Option<&str>,
+    ) -> Result<Context, Error> {
+        Context::new(
             defs,
             self.exhaustive,
             self.serialize_empty_collections,
             self.use_legacy_error_serialization,
             self.public_fields,
+            self.arbitrary,
+            self.patches,
+            self.diff,
+            self.redacted_debug,
+            self.field_masks,
             self.strip_prefix.as_deref(),
+            crate_per_package,
+            self.map_type,
+            self.set_type,
+            self.string_type,
+            &self.package_overrides,
+            &self.type_overrides,
+            &self.external_types,
             self.version
                 .as_deref()
                 .or_else(|| self.build_crate.as_ref().map(|v| &*v.version)),
-        );
+        )
+    }
 
-        let mut root = ModuleTrie::new();
+    /// Creates the module trees of the generated code, keyed by crate name when generating a crate per package.
+    fn create_modules(
+        &self,
+        context: &Context,
+        defs: &ConjureDefinition,
+    ) -> Result<BTreeMap<Option<String>, ModuleTrie>, Error> {
+        let mut roots = BTreeMap::new();
+        let mut generated_names = HashMap::new();
+        let mut insert = |base: BaseModule, name: &TypeName, type_: Type| {
+            roots
+                .entry(context.crate_name(name))
+                .or_insert_with(ModuleTrie::new)
+                .insert(&context.module_path(base, name), type_);
+        };
 
         for def in defs.types() {
+            let type_name = definition_name(def);
+            if context.is_external(type_name) {
+                continue;
+            }
+
             let (type_name, contents) = match def {
-                TypeDefinition::Enum(def) => (def.type_name(), enums::generate(&context, def)),
-                TypeDefinition::Alias(def) => (def.type_name(), aliases::generate(&context, def)),
-                TypeDefinition::Union(def) => (def.type_name(), unions::generate(&context, def)),
+                TypeDefinition::Enum(def) => (def.type_name(), enums::generate(context, def)),
+                TypeDefinition::Alias(def) => (def.type_name(), aliases::generate(context, def)),
+                TypeDefinition::Union(def) => (def.type_name(), unions::generate(context, def)),
                 TypeDefinition::Object(def) => (
                     def.type_name(),
-                    objects::generate(&context, BaseModule::Objects, def),
+                    objects::generate(context, BaseModule::Objects, def),
                 ),
             };
 
+            let mut type_names = vec![context.type_name(type_name.name()).to_string()];
+            match def {
+                TypeDefinition::Object(def) => {
+                    if context.patches() {
+                        type_names.push(patches::patch_name(context, def));
+                    }
+                    if context.field_masks() {
+                        type_names.push(field_mask::field_enum_name(context, def));
+                    }
+                }
+                TypeDefinition::Union(def) => type_names.push(unions::visitor_name(context, def)),
+                _ => {}
+            }
+
+            // Types generated alongside a type can collide with other types in the same module.
+            for name in &type_names {
+                let key = (
+                    context.module_path(BaseModule::Objects, type_name),
+                    name.clone(),
+                );
+                if let Some(other) = generated_names.insert(key, type_name) {
+                    bail!(
+                        "type `{name}` is generated for both `{}.{}` and `{}.{}`",
+                        other.package(),
+                        other.name(),
+                        type_name.package(),
+                        type_name.name(),
+                    );
+                }
+            }
+
             let type_ = Type {
                 module_name: context.module_name(type_name),
-                type_names: vec![context.type_name(type_name.name()).to_string()],
+                type_names,
                 contents,
             };
-            root.insert(&context.module_path(BaseModule::Objects, type_name), type_);
+            insert(BaseModule::Objects, type_name, type_);
         }
 
         for def in defs.errors() {
             let type_ = Type {
                 module_name: context.module_name(def.error_name()),
                 type_names: vec![context.type_name(def.error_name().name()).to_string()],
-                contents: errors::generate(&context, def),
+                contents: errors::generate(context, def),
             };
-            root.insert(
-                &context.module_path(BaseModule::Errors, def.error_name()),
-                type_,
-            );
+            insert(BaseModule::Errors, def.error_name(), type_);
         }
 
         for def in defs.services() {
-            let client = clients::generate(&context, def);
+            let client = clients::generate(context, def);
 
             let contents = quote! {
                 #client
@@ -562,12 +1003,9 @@ impl Config {
                 ],
                 contents,
             };
-            root.insert(
-                &context.module_path(BaseModule::Clients, def.service_name()),
-                type_,
-            );
+            insert(BaseModule::Clients, def.service_name(), type_);
 
-            let server = servers::generate(&context, def);
+            let server = servers::generate(context, def);
 
             let contents = quote! {
                 #server
@@ -582,26 +1020,23 @@ impl Config {
                 ],
                 contents,
             };
-            root.insert(
-                &context.module_path(BaseModule::Endpoints, def.service_name()),
-                type_,
-            );
+            insert(BaseModule::Endpoints, def.service_name(), type_);
         }
 
-        root.deconflict();
+        for root in roots.values_mut() {
+            root.deconflict();
+        }
 
-        root
+        Ok(roots)
     }
 
-    fn write_cargo_toml(
+    fn cargo_toml(
         &self,
-        dir: &Path,
         info: &CrateInfo,
         def: &ConjureDefinition,
-    ) -> Result<(), Error> {
-        fs::create_dir_all(dir)
-            .with_context(|| format!("error creating directory {}", dir.display()))?;
-
+        crate_dependencies: &BTreeSet<String>,
+        external_crates: &BTreeSet<String>,
+    ) -> Result<String, Error> {
         let metadata = def
             .extensions()
             .get("recommended-product-dependencies")
@@ -632,13 +1067,48 @@ impl Config {
         let conjure_version = env!("CARGO_PKG_VERSION");
         let mut dependencies = BTreeMap::new();
         if needs_object {
-            dependencies.insert("conjure-object", conjure_version);
+            let mut features = vec![];
+            if self.arbitrary {
+                features.push("proptest");
+            }
+            if self.map_type == MapType::IndexMap || self.set_type == SetType::IndexSet {
+                features.push("indexmap");
+            }
+            let dependency = if features.is_empty() {
+                cargo_toml::Dependency::Version(conjure_version)
+            } else {
+                cargo_toml::Dependency::Detailed {
+                    version: conjure_version,
+                    features,
+                }
+            };
+            dependencies.insert("conjure-object", dependency);
         }
         if needs_error {
-            dependencies.insert("conjure-error", conjure_version);
+            dependencies.insert(
+                "conjure-error",
+                cargo_toml::Dependency::Version(conjure_version),
+            );
         }
         if needs_http {
-            dependencies.insert("conjure-http", conjure_version);
+            dependencies.insert(
+                "conjure-http",
+                cargo_toml::Dependency::Version(conjure_version),
+            );
+        }
+        for (name, dependency) in &self.external_crates {
+            if external_crates.contains(&name.replace('-', "_")) {
+                dependencies.insert(name, cargo_toml::Dependency::Raw(dependency));
+            }
+        }
+        for name in crate_dependencies {
+            dependencies.insert(
+                name,
+                cargo_toml::Dependency::Path {
+                    version: &info.version,
+                    path: format!("../{name}"),
+                },
+            );
         }
 
         let manifest = cargo_toml::Manifest {
@@ -659,25 +1129,332 @@ impl Config {
             toml::to_string_pretty(&manifest).unwrap()
         };
 
-        let file = dir.join("Cargo.toml");
+        Ok(manifest)
+    }
 
-        fs::write(&file, manifest)
-            .with_context(|| format!("error writing manifest file {}", file.display()))?;
+    fn workspace_toml(&self, members: &[String]) -> Result<String, Error> {
+        let manifest = cargo_toml::WorkspaceManifest {
+            workspace: cargo_toml::Workspace {
+                members: members.iter().map(|s| &**s).collect(),
+                resolver: "2",
+            },
+        };
 
-        Ok(())
+        Ok(toml::to_string_pretty(&manifest)?)
     }
 
-    fn write_rustfmt_toml(&self, dir: &Path) -> Result<(), Error> {
-        let contents = "\
+    fn rustfmt_toml(&self) -> String {
+        "\
 disable_all_formatting = true
-";
+"
+        .to_string()
+    }
+}
+
+pub(crate) fn definition_name(def: &TypeDefinition) -> &TypeName {
+    match def {
+        TypeDefinition::Enum(def) => def.type_name(),
+        TypeDefinition::Alias(def) => def.type_name(),
+        TypeDefinition::Union(def) => def.type_name(),
+        TypeDefinition::Object(def) => def.type_name(),
+    }
+}
+
+fn check_crate_cycles(dependencies: &BTreeMap<String, BTreeSet<String>>) -> Result<(), Error> {
+    fn visit<'a>(
+        name: &'a str,
+        dependencies: &'a BTreeMap<String, BTreeSet<String>>,
+        path: &mut Vec<&'a str>,
+        visited: &mut BTreeSet<&'a str>,
+    ) -> Result<(), Error> {
+        if let Some(start) = path.iter().position(|n| *n == name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(name);
+            bail!(
+                "packages can't be split into separate crates because they depend on each other: {}",
+                cycle.join(" -> "),
+            );
+        }
+        if !visited.insert(name) {
+            return Ok(());
+        }
+
+        path.push(name);
+        for dependency in dependencies.get(name).into_iter().flatten() {
+            visit(dependency, dependencies, path, visited)?;
+        }
+        path.pop();
+
+        Ok(())
+    }
+
+    let mut visited = BTreeSet::new();
+    for name in dependencies.keys() {
+        visit(name, dependencies, &mut vec![], &mut visited)?;
+    }
 
-        let file = dir.join("rustfmt.toml");
+    Ok(())
+}
+
+/// The output of in-memory code generation.
+///
+/// It contains the generated files, keyed by their paths relative to the output directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedCrate {
+    files: BTreeMap<PathBuf, String>,
+}
+
+impl GeneratedCrate {
+    /// Returns the generated files, keyed by their relative paths.
+    pub fn files(&self) -> &BTreeMap<PathBuf, String> {
+        &self.files
+    }
 
-        fs::write(file, contents).with_context(|| "error writing rustfmt.toml")?;
+    /// Returns the contents of the generated file at a relative path.
+    pub fn get<P>(&self, path: P) -> Option<&str>
+    where
+        P: AsRef<Path>,
+    {
+        self.files.get(path.as_ref()).map(|s| &**s)
+    }
+
+    /// Consumes the `GeneratedCrate`, returning its files.
+    pub fn into_files(self) -> BTreeMap<PathBuf, String> {
+        self.files
+    }
+
+    /// Writes the generated files to a directory.
+    ///
+    /// Files whose contents are unchanged are not rewritten, so their modification times are preserved. A manifest of
+    /// the written files is stored in the directory, and files listed in an existing manifest which are no longer
+    /// generated are deleted, along with any directories left empty.
+    pub fn write_to<P>(&self, out_dir: P) -> Result<(), Error>
+    where
+        P: AsRef<Path>,
+    {
+        let out_dir = out_dir.as_ref();
+        let previous = read_manifest(out_dir)?;
+
+        for (path, contents) in &self.files {
+            write_if_changed(&out_dir.join(path), contents)?;
+        }
+
+        for path in previous.difference(&self.files.keys().cloned().collect()) {
+            remove_stale(out_dir, path)?;
+        }
+
+        write_if_changed(&out_dir.join(MANIFEST), &self.manifest())?;
 
         Ok(())
     }
+
+    /// Checks that the contents of a directory match the generated files.
+    ///
+    /// An error is returned if any generated file is missing or differs from what would be written by
+    /// [`Self::write_to`], or if a previously generated file would be deleted.
+    pub fn verify<P>(&self, out_dir: P) -> Result<(), Error>
+    where
+        P: AsRef<Path>,
+    {
+        let out_dir = out_dir.as_ref();
+        let manifest = self.manifest();
+
+        let mut out_of_date = vec![];
+        let expected = self
+            .files
+            .iter()
+            .map(|(path, contents)| (&**path, &**contents))
+            .chain(Some((Path::new(MANIFEST), &*manifest)));
+        for (path, contents) in expected {
+            let matches = match fs::read(out_dir.join(path)) {
+                Ok(existing) => existing == contents.as_bytes(),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => false,
+                Err(e) => {
+                    return Err(Error::new(e).context(format!(
+                        "error reading file {}",
+                        out_dir.join(path).display()
+                    )))
+                }
+            };
+            if !matches {
+                out_of_date.push(path.to_path_buf());
+            }
+        }
+
+        for path in read_manifest(out_dir)? {
+            if !self.files.contains_key(&path) && out_dir.join(&path).exists() {
+                out_of_date.push(path);
+            }
+        }
+
+        if !out_of_date.is_empty() {
+            out_of_date.sort();
+            let paths = out_of_date
+                .iter()
+                .map(|p| format!("\n    {}", p.display()))
+                .collect::<String>();
+            bail!(
+                "generated code in {} is out of date:{}",
+                out_dir.display(),
+                paths,
+            );
+        }
+
+        Ok(())
+    }
+
+    fn manifest(&self) -> String {
+        self.files
+            .keys()
+            .map(|path| {
+                let components = path
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>();
+                format!("{}\n", components.join("/"))
+            })
+            .collect()
+    }
+}
+
+// Records the files written by the last generation so stale ones can be removed later.
+const MANIFEST: &str = ".conjure-manifest";
+
+fn read_manifest(out_dir: &Path) -> Result<BTreeSet<PathBuf>, Error> {
+    let file = out_dir.join(MANIFEST);
+    let manifest = match fs::read_to_string(&file) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+        Err(e) => {
+            return Err(Error::new(e).context(format!("error reading file {}", file.display())))
+        }
+    };
+
+    let paths = manifest
+        .lines()
+        .map(|line| line.split('/').collect::<PathBuf>())
+        // never touch anything outside of the output directory
+        .filter(|path| {
+            path.components().next().is_some()
+                && path.components().all(|c| matches!(c, Component::Normal(_)))
+        })
+        .collect();
+
+    Ok(paths)
+}
+
+fn write_if_changed(path: &Path, contents: &str) -> Result<(), Error> {
+    match fs::read(path) {
+        Ok(existing) if existing == contents.as_bytes() => return Ok(()),
+        _ => {}
+    }
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("error creating directory {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("error writing file {}", path.display()))
+}
+
+fn remove_stale(out_dir: &Path, path: &Path) -> Result<(), Error> {
+    let file = out_dir.join(path);
+    match fs::remove_file(&file) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => {
+            return Err(Error::new(e).context(format!("error removing file {}", file.display())))
+        }
+    }
+
+    for dir in path.ancestors().skip(1) {
+        if dir.as_os_str().is_empty() {
+            break;
+        }
+        let dir = out_dir.join(dir);
+        let is_empty = match fs::read_dir(&dir) {
+            Ok(mut entries) => entries.next().is_none(),
+            Err(_) => break,
+        };
+        if !is_empty {
+            break;
+        }
+        fs::remove_dir(&dir)
+            .with_context(|| format!("error removing directory {}", dir.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Overrides of global codegen settings for a subset of types.
+///
+/// Settings which are not set fall back to those of the [`Config`].
+#[derive(Clone, Debug, Default)]
+pub struct Overrides {
+    exhaustive: Option<bool>,
+    serialize_empty_collections: Option<bool>,
+    public_fields: Option<bool>,
+}
+
+impl Overrides {
+    /// Creates a new `Overrides` which doesn't override any settings.
+    pub fn new() -> Overrides {
+        Overrides::default()
+    }
+
+    /// Overrides [`Config::exhaustive`].
+    pub fn exhaustive(&mut self, exhaustive: bool) -> &mut Overrides {
+        self.exhaustive = Some(exhaustive);
+        self
+    }
+
+    /// Overrides [`Config::serialize_empty_collections`].
+    pub fn serialize_empty_collections(
+        &mut self,
+        serialize_empty_collections: bool,
+    ) -> &mut Overrides {
+        self.serialize_empty_collections = Some(serialize_empty_collections);
+        self
+    }
+
+    /// Overrides [`Config::public_fields`].
+    pub fn public_fields(&mut self, public_fields: bool) -> &mut Overrides {
+        self.public_fields = Some(public_fields);
+        self
+    }
+}
+
+/// The Rust representation of Conjure `map`s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MapType {
+    /// `std::collections::BTreeMap`.
+    BTreeMap,
+    /// `std::collections::HashMap`.
+    HashMap,
+    /// `indexmap::IndexMap`.
+    IndexMap,
+}
+
+/// The Rust representation of Conjure `set`s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SetType {
+    /// `std::collections::BTreeSet`.
+    BTreeSet,
+    /// `std::collections::HashSet`.
+    HashSet,
+    /// `indexmap::IndexSet`.
+    IndexSet,
+}
+
+/// The Rust representation of Conjure `string`s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StringType {
+    /// `std::string::String`.
+    String,
+    /// `std::sync::Arc<str>`, which is cheap to clone.
+    ArcStr,
 }
 
 struct Type {
@@ -722,46 +1499,87 @@ impl ModuleTrie {
         }
     }
 
-    fn render(&self, dir: &Path, lib_root: bool) -> Result<(), Error> {
-        fs::create_dir_all(dir)
-            .with_context(|| format!("error creating directory {}", dir.display()))?;
-
+    fn render(
+        &self,
+        dir: &Path,
+        lib_root: bool,
+        files: &mut BTreeMap<PathBuf, String>,
+    ) -> Result<(), Error> {
         for type_ in &self.types {
-            self.write_module(
-                &dir.join(format!("{}.rs", type_.module_name)),
+            self.render_module(
+                dir.join(format!("{}.rs", type_.module_name)),
                 &type_.contents,
+                files,
             )?;
         }
 
         for (name, module) in &self.submodules {
-            module.render(&dir.join(name), false)?;
+            module.render(&dir.join(name), false, files)?;
         }
 
         let root = self.create_root_module(lib_root);
         let file_name = if lib_root { "lib.rs" } else { "mod.rs" };
-        self.write_module(&dir.join(file_name), &root)?;
+        self.render_module(dir.join(file_name), &root, files)?;
 
         Ok(())
     }
 
-    fn write_module(&self, path: &Path, contents: &TokenStream) -> Result<(), Error> {
-        let file = syn::parse2(contents.clone())?;
+    fn render_module(
+        &self,
+        path: PathBuf,
+        contents: &TokenStream,
+        files: &mut BTreeMap<PathBuf, String>,
+    ) -> Result<(), Error> {
+        let file = syn::parse2(contents.clone())
+            .with_context(|| format!("error formatting module {}", path.display()))?;
         let formatted = prettyplease::unparse(&file);
 
-        fs::write(path, formatted)
-            .with_context(|| format!("error writing module {}", path.display()))?;
+        files.insert(path, formatted);
         Ok(())
     }
 
-    fn create_root_module(&self, lib_root: bool) -> TokenStream {
-        let attrs = if lib_root {
+    fn inline(&self, root: bool) -> TokenStream {
+        // The root modules are spliced into user code, so suppress lints like we do for the crate root.
+        let attrs = if root {
             quote! {
-                #![allow(warnings)]
+                #[allow(warnings)]
             }
         } else {
             quote! {}
         };
+        let uses = self.uses();
 
+        let type_mods = self.types.iter().map(|m| {
+            let module_name = m.module_name.parse::<TokenStream>().unwrap();
+            let contents = &m.contents;
+            quote! {
+                #attrs
+                pub mod #module_name {
+                    #contents
+                }
+            }
+        });
+
+        let sub_mods = self.submodules.iter().map(|(name, module)| {
+            let module_name = name.parse::<TokenStream>().unwrap();
+            let contents = module.inline(false);
+            quote! {
+                #attrs
+                pub mod #module_name {
+                    #contents
+                }
+            }
+        });
+
+        quote! {
+            #uses
+
+            #(#type_mods)*
+            #(#sub_mods)*
+        }
+    }
+
+    fn uses(&self) -> TokenStream {
         let uses = self.types.iter().map(|m| {
             let module_name = m.module_name.parse::<TokenStream>().unwrap();
             let type_names = m
@@ -774,6 +1592,22 @@ impl ModuleTrie {
             }
         });
 
+        quote! {
+            #(#uses)*
+        }
+    }
+
+    fn create_root_module(&self, lib_root: bool) -> TokenStream {
+        let attrs = if lib_root {
+            quote! {
+                #![allow(warnings)]
+            }
+        } else {
+            quote! {}
+        };
+
+        let uses = self.uses();
+
         let type_mods = self.types.iter().map(|m| {
             let module_name = m.module_name.parse::<TokenStream>().unwrap();
             quote! {
@@ -790,10 +1624,385 @@ impl ModuleTrie {
 
         quote! {
             #attrs
-            #(#uses)*
+            #uses
 
             #(#type_mods)*
             #(#sub_mods)*
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_ir() -> ConjureDefinition {
+        let ir = fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../conjure-test/test-ir.json"
+        ))
+        .unwrap();
+        conjure_serde::json::client_from_str(&ir).unwrap()
+    }
+
+    #[test]
+    fn generate_module() {
+        let generated = Config::new()
+            .strip_prefix("com.palantir.conjure".to_string())
+            .generate(&test_ir())
+            .unwrap();
+
+        assert!(generated
+            .get("mod.rs")
+            .unwrap()
+            .contains("pub mod objects;"));
+        assert!(generated.get("objects/mod.rs").is_some());
+        assert!(generated.get("Cargo.toml").is_none());
+        assert!(generated
+            .files()
+            .keys()
+            .all(|p| p.extension().unwrap() == "rs"));
+    }
+
+    #[test]
+    fn generate_crate() {
+        let generated = Config::new()
+            .strip_prefix("com.palantir.conjure".to_string())
+            .build_crate("foo-api", "1.0.0")
+            .generate(&test_ir())
+            .unwrap();
+
+        let manifest = generated.get("Cargo.toml").unwrap();
+        assert!(manifest.contains("name = \"foo-api\""));
+        assert_eq!(
+            generated.get("rustfmt.toml").unwrap(),
+            "disable_all_formatting = true\n"
+        );
+        assert!(generated.get("src/lib.rs").is_some());
+        assert!(generated.get("src/objects/mod.rs").is_some());
+    }
+
+    #[test]
+    fn generate_workspace() {
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api.common
+    objects:
+      Id:
+        alias: string
+      Foo:
+        package: com.palantir.api.foo
+        fields:
+          id: Id
+      Root:
+        package: com.palantir.api
+        fields:
+          foo: Foo
+",
+        )
+        .unwrap();
+        let generated = Config::new()
+            .strip_prefix("com.palantir.api".to_string())
+            .build_crate("my-api", "1.0.0")
+            .crate_per_package(true)
+            .generate(&defs)
+            .unwrap();
+
+        assert_eq!(
+            generated.get("Cargo.toml").unwrap(),
+            "\
+[workspace]
+members = [
+    \"my-api\",
+    \"my-api-common\",
+    \"my-api-foo\",
+]
+resolver = \"2\"
+"
+        );
+        assert!(generated.get("my-api-common/src/objects/id.rs").is_some());
+
+        let manifest = generated.get("my-api-foo/Cargo.toml").unwrap();
+        assert!(manifest.contains("name = \"my-api-foo\""), "{}", manifest);
+        assert!(
+            manifest.contains(
+                "[dependencies.my-api-common]\nversion = \"1.0.0\"\npath = \"../my-api-common\""
+            ),
+            "{}",
+            manifest
+        );
+        assert!(!manifest.contains("my-api-foo]"), "{}", manifest);
+
+        let foo = generated.get("my-api-foo/src/objects/foo.rs").unwrap();
+        assert!(foo.contains("::my_api_common::objects::Id"), "{}", foo);
+
+        let root = generated.get("my-api/src/objects/root.rs").unwrap();
+        assert!(root.contains("::my_api_foo::objects::Foo"), "{}", root);
+    }
+
+    #[test]
+    fn external_crates() {
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api.foo
+    objects:
+      Id:
+        alias: string
+      Foo:
+        fields:
+          id: Id
+      Bar:
+        package: com.palantir.api.bar
+        fields:
+          value: string
+",
+        )
+        .unwrap();
+        let generated = Config::new()
+            .strip_prefix("com.palantir.api".to_string())
+            .build_crate("my-api", "1.0.0")
+            .crate_per_package(true)
+            .external_type("com.palantir.api.foo.Id", "other_api::Id")
+            .external_type("com.palantir.api.Unused", "unused_api::Unused")
+            .external_crate("other-api", "1.2.3")
+            .external_crate("unused-api", "4.5.6")
+            .generate(&defs)
+            .unwrap();
+
+        let manifest = generated.get("my-api-foo/Cargo.toml").unwrap();
+        assert!(manifest.contains("other-api = \"1.2.3\""), "{}", manifest);
+        assert!(!manifest.contains("unused-api"), "{}", manifest);
+
+        let manifest = generated.get("my-api-bar/Cargo.toml").unwrap();
+        assert!(!manifest.contains("other-api"), "{}", manifest);
+    }
+
+    #[test]
+    fn generated_name_conflicts() {
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api
+    objects:
+      Foo:
+        fields:
+          bar: string
+      FooPatch:
+        fields:
+          baz: string
+",
+        )
+        .unwrap();
+        Config::new().generate(&defs).unwrap();
+        let err = Config::new().patches(true).generate(&defs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "type `FooPatch` is generated for both `com.palantir.api.Foo` and \
+             `com.palantir.api.FooPatch`"
+        );
+
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api
+    objects:
+      FooVisitor:
+        alias: string
+      Foo:
+        union:
+          bar: string
+",
+        )
+        .unwrap();
+        let err = Config::new().generate(&defs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "type `FooVisitor` is generated for both `com.palantir.api.Foo` and \
+             `com.palantir.api.FooVisitor`"
+        );
+
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api
+    objects:
+      Foo:
+        fields:
+          bar: string
+      FooField:
+        values:
+          - BAR
+",
+        )
+        .unwrap();
+        Config::new().generate(&defs).unwrap();
+        let err = Config::new().field_masks(true).generate(&defs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "type `FooField` is generated for both `com.palantir.api.Foo` and \
+             `com.palantir.api.FooField`"
+        );
+    }
+
+    #[test]
+    fn unknown_overrides() {
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api.foo
+    objects:
+      Foo:
+        fields:
+          bar: string
+",
+        )
+        .unwrap();
+        Config::new()
+            .package_overrides("com.palantir.api", Overrides::new())
+            .type_overrides("com.palantir.api.foo.Foo", Overrides::new())
+            .generate(&defs)
+            .unwrap();
+
+        let err = Config::new()
+            .type_overrides("com.palantir.api.foo.Bar", Overrides::new())
+            .generate(&defs)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "type overrides specified for unknown type `com.palantir.api.foo.Bar`"
+        );
+
+        let err = Config::new()
+            .package_overrides("com.palantir.api.fo", Overrides::new())
+            .generate(&defs)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "package overrides specified for unknown package `com.palantir.api.fo`"
+        );
+    }
+
+    #[test]
+    fn workspace_cycle() {
+        let defs = yaml::compile_str(
+            "
+types:
+  definitions:
+    default-package: com.palantir.api.foo
+    objects:
+      Foo:
+        fields:
+          bar: optional<Bar>
+      Bar:
+        package: com.palantir.api.bar
+        fields:
+          foo: optional<Foo>
+",
+        )
+        .unwrap();
+        let err = Config::new()
+            .strip_prefix("com.palantir.api".to_string())
+            .build_crate("my-api", "1.0.0")
+            .crate_per_package(true)
+            .generate(&defs)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "packages can't be split into separate crates because they depend on each other: \
+             my-api-bar -> my-api-foo -> my-api-bar"
+        );
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("conjure-codegen-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn generated(files: &[(&str, &str)]) -> GeneratedCrate {
+        GeneratedCrate {
+            files: files
+                .iter()
+                .map(|(path, contents)| (PathBuf::from(path), contents.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn write_unchanged() {
+        let dir = temp_dir("write-unchanged");
+        let generated = generated(&[("mod.rs", "pub mod foo;\n"), ("foo.rs", "struct Foo;\n")]);
+        generated.write_to(&dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join(".conjure-manifest")).unwrap(),
+            "foo.rs\nmod.rs\n"
+        );
+
+        let old = std::time::SystemTime::UNIX_EPOCH;
+        fs::File::options()
+            .write(true)
+            .open(dir.join("foo.rs"))
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+        generated.write_to(&dir).unwrap();
+        let modified = fs::metadata(dir.join("foo.rs"))
+            .unwrap()
+            .modified()
+            .unwrap();
+        assert_eq!(modified, old);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn prune_stale() {
+        let dir = temp_dir("prune-stale");
+        generated(&[
+            ("mod.rs", "pub mod foo;\npub mod bar;\n"),
+            ("foo.rs", "struct Foo;\n"),
+            ("bar/baz/mod.rs", "pub mod qux;\n"),
+            ("bar/baz/qux.rs", "struct Qux;\n"),
+        ])
+        .write_to(&dir)
+        .unwrap();
+        fs::write(dir.join("user.rs"), "").unwrap();
+
+        let generated = generated(&[("mod.rs", "pub mod foo;\n"), ("foo.rs", "struct Foo;\n")]);
+        let err = generated.verify(&dir).unwrap_err().to_string();
+        assert!(err.contains("bar/baz/qux.rs"), "{}", err);
+        assert!(err.contains("mod.rs"), "{}", err);
+
+        generated.write_to(&dir).unwrap();
+        generated.verify(&dir).unwrap();
+        assert!(!dir.join("bar").exists());
+        assert!(dir.join("user.rs").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn verify_modified() {
+        let dir = temp_dir("verify-modified");
+        let generated = generated(&[("mod.rs", "pub mod foo;\n"), ("foo.rs", "struct Foo;\n")]);
+        assert!(generated.verify(&dir).is_err());
+
+        generated.write_to(&dir).unwrap();
+        generated.verify(&dir).unwrap();
+
+        fs::write(dir.join("foo.rs"), "struct Bar;\n").unwrap();
+        let err = generated.verify(&dir).unwrap_err().to_string();
+        assert!(err.contains("foo.rs"), "{}", err);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
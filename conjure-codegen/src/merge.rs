@@ -0,0 +1,235 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Merging of multiple Conjure definitions.
+//!
+//! Products frequently depend on APIs spread across several IR files which share common types. Merging the files
+//! allows them to be generated into a single module tree with each shared definition generated once:
+//!
+//! ```no_run
+//! use conjure_codegen::types::objects::ConjureDefinition;
+//!
+//! let mut inputs = vec![];
+//! for path in ["foo-api.conjure.json", "bar-api.conjure.json"] {
+//!     let ir = std::fs::read_to_string(path)?;
+//!     inputs.push((path, conjure_serde::json::client_from_str::<ConjureDefinition>(&ir)?));
+//! }
+//! let defs = conjure_codegen::merge::merge(inputs)?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+use crate::definition_name;
+use crate::types::objects::{ConjureDefinition, TypeName};
+use anyhow::{bail, Error};
+use conjure_object::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// Merges multiple Conjure definitions into one.
+///
+/// Each input is paired with a name used to identify it in error messages, typically the path of the file it was read
+/// from. Types, errors, and services are combined in the order they first appear. A definition which appears in
+/// multiple inputs is only included once as long as every copy is identical; an error is returned if two inputs define
+/// the same name differently.
+///
+/// Extensions are combined in the same way, except that lists (like `recommended-product-dependencies`) are
+/// concatenated with duplicates removed.
+pub fn merge<I, N>(inputs: I) -> Result<ConjureDefinition, Error>
+where
+    I: IntoIterator<Item = (N, ConjureDefinition)>,
+    N: fmt::Display,
+{
+    let mut version = None::<(i32, String)>;
+    let mut types = Definitions::new("type");
+    let mut errors = Definitions::new("error");
+    let mut services = Definitions::new("service");
+    let mut extensions = BTreeMap::<String, (Any, String)>::new();
+
+    for (name, defs) in inputs {
+        let name = name.to_string();
+
+        match &version {
+            Some((version, first)) if *version != defs.version() => bail!(
+                "{first} and {name} have different IR versions ({version} and {})",
+                defs.version(),
+            ),
+            Some(_) => {}
+            None => version = Some((defs.version(), name.clone())),
+        }
+
+        for def in defs.types() {
+            types.insert(definition_name(def), def, &name)?;
+        }
+        for def in defs.errors() {
+            errors.insert(def.error_name(), def, &name)?;
+        }
+        for def in defs.services() {
+            services.insert(def.service_name(), def, &name)?;
+        }
+
+        for (key, value) in defs.extensions() {
+            match extensions.get_mut(key) {
+                Some((existing, _)) if existing == value => {}
+                Some((existing, first)) => match (existing.as_seq_mut(), value.as_seq()) {
+                    (Some(existing), Some(values)) => {
+                        for value in values {
+                            if !existing.contains(value) {
+                                existing.push(value.clone());
+                            }
+                        }
+                    }
+                    _ => bail!("extension `{key}` has conflicting values in {first} and {name}"),
+                },
+                None => {
+                    extensions.insert(key.clone(), (value.clone(), name.clone()));
+                }
+            }
+        }
+    }
+
+    let Some((version, _)) = version else {
+        bail!("no Conjure definitions to merge");
+    };
+
+    let mut builder = ConjureDefinition::builder()
+        .version(version)
+        .types(types.into_defs())
+        .errors(errors.into_defs())
+        .services(services.into_defs());
+    for (key, (value, _)) in extensions {
+        builder = builder.insert_extensions(key, value);
+    }
+
+    Ok(builder.build())
+}
+
+struct Definitions<T> {
+    kind: &'static str,
+    defs: Vec<T>,
+    indices: HashMap<TypeName, (usize, String)>,
+}
+
+impl<T> Definitions<T>
+where
+    T: Clone + PartialEq,
+{
+    fn new(kind: &'static str) -> Self {
+        Definitions {
+            kind,
+            defs: vec![],
+            indices: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, name: &TypeName, def: &T, input: &str) -> Result<(), Error> {
+        match self.indices.get(name) {
+            Some((index, _)) if self.defs[*index] == *def => Ok(()),
+            Some((_, first)) => bail!(
+                "{} `{}.{}` is defined differently in {first} and {input}",
+                self.kind,
+                name.package(),
+                name.name(),
+            ),
+            None => {
+                self.indices
+                    .insert(name.clone(), (self.defs.len(), input.to_string()));
+                self.defs.push(def.clone());
+                Ok(())
+            }
+        }
+    }
+
+    fn into_defs(self) -> Vec<T> {
+        self.defs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::objects::{AliasDefinition, PrimitiveType, Type, TypeDefinition};
+
+    fn alias(name: &str, target: PrimitiveType) -> TypeDefinition {
+        TypeDefinition::Alias(
+            AliasDefinition::builder()
+                .type_name(TypeName::new(name, "com.palantir.foo"))
+                .alias(Type::Primitive(target))
+                .build(),
+        )
+    }
+
+    fn defs(types: Vec<TypeDefinition>) -> ConjureDefinition {
+        ConjureDefinition::builder().version(1).types(types).build()
+    }
+
+    #[test]
+    fn dedupes_identical_definitions() {
+        let merged = merge([
+            (
+                "a.json",
+                defs(vec![
+                    alias("Shared", PrimitiveType::String),
+                    alias("A", PrimitiveType::Integer),
+                ]),
+            ),
+            (
+                "b.json",
+                defs(vec![
+                    alias("B", PrimitiveType::Boolean),
+                    alias("Shared", PrimitiveType::String),
+                ]),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            merged,
+            defs(vec![
+                alias("Shared", PrimitiveType::String),
+                alias("A", PrimitiveType::Integer),
+                alias("B", PrimitiveType::Boolean),
+            ]),
+        );
+    }
+
+    #[test]
+    fn conflicting_definitions() {
+        let err = merge([
+            ("a.json", defs(vec![alias("Shared", PrimitiveType::String)])),
+            ("b.json", defs(vec![alias("Shared", PrimitiveType::Rid)])),
+        ])
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "type `com.palantir.foo.Shared` is defined differently in a.json and b.json",
+        );
+    }
+
+    #[test]
+    fn merges_extension_lists() {
+        let with_deps = |deps: &[&str]| {
+            ConjureDefinition::builder()
+                .version(1)
+                .insert_extensions("recommended-product-dependencies", deps)
+                .build()
+        };
+
+        let merged = merge([
+            ("a.json", with_deps(&["foo", "bar"])),
+            ("b.json", with_deps(&["bar", "baz"])),
+        ])
+        .unwrap();
+
+        assert_eq!(merged, with_deps(&["foo", "bar", "baz"]));
+    }
+}
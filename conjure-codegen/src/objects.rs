@@ -13,7 +13,12 @@ use std::iter;
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use crate::arbitrary;
 use crate::context::{BaseModule, BuilderConfig, BuilderItemConfig, Context};
+use crate::debug;
+use crate::diff;
+use crate::field_mask;
+use crate::patches;
 use crate::types::objects::{FieldDefinition, ObjectDefinition};
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -24,13 +29,19 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
 
     let mut type_attrs = vec![quote!(#[serde(crate = "conjure_object::serde")])];
     let mut derives = vec![
-        "Debug",
         "Clone",
         "conjure_object::serde::Serialize",
         "conjure_object::serde::Deserialize",
     ];
+    if !debug::redacts_object(ctx, def) {
+        derives.insert(0, "Debug");
+    }
 
-    if def.fields().iter().any(|v| ctx.has_double(v.type_())) {
+    if def
+        .fields()
+        .iter()
+        .any(|v| ctx.has_double(v.type_()) || ctx.is_unordered(v.type_()))
+    {
         derives.push("conjure_object::private::DeriveWith");
         type_attrs.push(quote!(#[derive_with(PartialEq, Eq, PartialOrd, Ord, Hash)]));
     } else {
@@ -44,6 +55,12 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
     if def.fields().iter().all(|v| ctx.is_copy(v.type_())) {
         derives.push("Copy");
     }
+    if ctx.patches()
+        && matches!(base_module, BaseModule::Objects)
+        && def.fields().iter().all(|v| !ctx.is_required(v.type_()))
+    {
+        derives.push("Default");
+    }
     if ctx.is_safe_type(def.type_name()) {
         derives.push("conjure_object::log_safety::derive::LogSafe");
     }
@@ -52,7 +69,7 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
     // The derive attr has to be before the derive_with attr, so insert rather than push
     type_attrs.insert(0, quote!(#[derive(#(#derives),*)]));
 
-    if ctx.public_fields() {
+    if ctx.public_fields(def.type_name()) {
         type_attrs.push(quote!(#[non_exhaustive]));
     }
 
@@ -60,7 +77,7 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
     let field_attrs = def.fields().iter().map(|s| {
         let builder_attr = field_builder_attr(ctx, base_module, def, s);
         let serde_attr = serde_field_attr(ctx, def, s);
-        let educe_attr = if ctx.is_double(s.type_()) {
+        let educe_attr = if ctx.is_double(s.type_()) || ctx.is_unordered(s.type_()) {
             quote! {
                 #[derive_with(with = conjure_object::private::DoubleWrapper)]
             }
@@ -72,7 +89,7 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
         } else {
             quote!()
         };
-        let (docs, deprecated) = if ctx.public_fields() {
+        let (docs, deprecated) = if ctx.public_fields(def.type_name()) {
             (ctx.docs(s.docs()), ctx.deprecated(s.deprecated()))
         } else {
             (quote!(), quote!())
@@ -88,7 +105,7 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
         }
     });
 
-    let pub_ = if ctx.public_fields() {
+    let pub_ = if ctx.public_fields(def.type_name()) {
         quote!(pub)
     } else {
         quote!()
@@ -102,9 +119,15 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
         .collect::<Vec<_>>();
 
     let constructor = generate_constructor(ctx, base_module, def);
+    let arbitrary = arbitrary::generate_object(ctx, base_module, def);
+    let patch = patches::generate(ctx, base_module, def);
+    let diff = diff::generate_object(ctx, def);
+    let field_mask = field_mask::generate_object(ctx, base_module, def);
+    let debug = debug::generate_object(ctx, def);
+    let double_ops_leaf = ctx.double_ops_leaf(def.type_name());
 
     let accessors = def.fields().iter().map(|s| {
-        if ctx.public_fields() {
+        if ctx.public_fields(def.type_name()) {
             return quote!();
         }
 
@@ -145,6 +168,18 @@ pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition)
 
             #(#accessors)*
         }
+
+        #arbitrary
+
+        #patch
+
+        #diff
+
+        #field_mask
+
+        #debug
+
+        #double_ops_leaf
     }
 }
 
@@ -202,28 +237,34 @@ fn generate_constructor(
     }
 }
 
-fn serde_field_attr(ctx: &Context, def: &ObjectDefinition, field: &FieldDefinition) -> TokenStream {
+/// Returns `true` if the field is a map whose keys are serialized as strings even in binary formats.
+pub fn serializes_keys_as_strings(ctx: &Context, field: &FieldDefinition) -> bool {
     use crate::types::objects::{PrimitiveType, Type};
 
+    match field.type_() {
+        // Resolve aliases to check if the key is actually a UUID
+        Type::Map(map_type) => matches!(
+            ctx.dealiased_type(map_type.key_type()),
+            Type::Primitive(PrimitiveType::Uuid)
+        ),
+        _ => false,
+    }
+}
+
+fn serde_field_attr(ctx: &Context, def: &ObjectDefinition, field: &FieldDefinition) -> TokenStream {
     let mut parts = vec![];
 
     let name = &field.field_name().0;
     parts.push(quote!(rename = #name));
 
     // Add serialize_with for maps with UUID keys (for Java CBOR compatibility)
-    if let Type::Map(map_type) = field.type_() {
-        let key_type = map_type.key_type();
-        // Resolve aliases to check if the key is actually a UUID
-        let dealiased_key = ctx.dealiased_type(key_type);
-
-        if matches!(dealiased_key, Type::Primitive(PrimitiveType::Uuid)) {
-            // Use the specialized function for direct UUID keys
-            let serialize_fn = "conjure_object::private::serialize_map_keys_as_strings";
-            parts.push(quote!(serialize_with = #serialize_fn));
-        }
+    if serializes_keys_as_strings(ctx, field) {
+        // Use the specialized function for direct UUID keys
+        let serialize_fn = "conjure_object::private::serialize_map_keys_as_strings";
+        parts.push(quote!(serialize_with = #serialize_fn));
     }
 
-    if !ctx.serialize_empty_collections() {
+    if !ctx.serialize_empty_collections(def.type_name()) {
         if let Some(is_empty) = ctx.is_empty_method(def.type_name(), field.type_()) {
             parts.push(quote!(skip_serializing_if = #is_empty));
         }
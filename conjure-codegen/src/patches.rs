@@ -0,0 +1,125 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::context::{BaseModule, Context};
+use crate::types::objects::ObjectDefinition;
+
+pub fn patch_name(ctx: &Context, def: &ObjectDefinition) -> String {
+    format!("{}Patch", ctx.type_name(def.type_name().name()))
+}
+
+pub fn generate(ctx: &Context, base_module: BaseModule, def: &ObjectDefinition) -> TokenStream {
+    if !ctx.patches() || !matches!(base_module, BaseModule::Objects) {
+        return quote!();
+    }
+
+    let name = ctx.type_name(def.type_name().name());
+    let patch_name = Ident::new(&patch_name(ctx, def), Span::call_site());
+    let non_exhaustive = if ctx.exhaustive(def.type_name()) {
+        quote!()
+    } else {
+        quote!(#[non_exhaustive])
+    };
+
+    let option = ctx.option_ident(def.type_name());
+    let fields = &def
+        .fields()
+        .iter()
+        .map(|f| ctx.field_name(f.field_name()))
+        .collect::<Vec<_>>();
+    let types = def
+        .fields()
+        .iter()
+        .map(|f| ctx.boxed_rust_type(base_module, def.type_name(), f.type_()));
+    let docs = def.fields().iter().map(|f| {
+        let doc = format!(
+            "The new value of the `{}` field, or `None` to leave it unchanged.",
+            f.field_name().0
+        );
+        quote!(#[doc = #doc])
+    });
+
+    let diffs = def.fields().iter().map(|f| {
+        let field = ctx.field_name(f.field_name());
+        let changed = if ctx.is_double(f.type_()) {
+            quote! {
+                conjure_object::private::DoubleWrapper(&old.#field)
+                    != conjure_object::private::DoubleWrapper(&new.#field)
+            }
+        } else {
+            quote!(old.#field != new.#field)
+        };
+        quote! {
+            if #changed {
+                #option::Some(new.#field.clone())
+            } else {
+                #option::None
+            }
+        }
+    });
+
+    let (old, new, value) = if fields.is_empty() {
+        (quote!(_), quote!(_), quote!(_))
+    } else {
+        (quote!(old), quote!(new), quote!(value))
+    };
+    let is_empty = if fields.is_empty() {
+        quote!(true)
+    } else {
+        quote!(#(self.#fields.is_none())&&*)
+    };
+    let struct_doc = format!("A partial update to a [`{name}`].");
+
+    quote! {
+        #[doc = #struct_doc]
+        #[derive(Debug, Clone, Default, PartialEq)]
+        #non_exhaustive
+        pub struct #patch_name {
+            #(
+                #docs
+                pub #fields: #option<#types>,
+            )*
+        }
+
+        impl #patch_name {
+            /// Returns a patch which transforms `old` into `new` when applied.
+            ///
+            /// Only fields which differ between the two values are set.
+            #[allow(deprecated)]
+            pub fn diff(#old: &#name, #new: &#name) -> Self {
+                #patch_name {
+                    #(#fields: #diffs,)*
+                }
+            }
+
+            /// Returns `true` if the patch doesn't modify any fields.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                #is_empty
+            }
+
+            /// Overwrites each field of `value` which is set in the patch.
+            #[allow(deprecated)]
+            pub fn apply(self, #value: &mut #name) {
+                #(
+                    if let #option::Some(v) = self.#fields {
+                        value.#fields = v;
+                    }
+                )*
+            }
+        }
+    }
+}
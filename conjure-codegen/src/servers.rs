@@ -180,7 +180,12 @@ fn generate_trait_endpoint(
     let produces = match endpoint.returns() {
         Some(ty) => {
             let produces = produces(ctx, ty);
-            quote!(, produces = #produces)
+            let field_mask = if supports_field_mask(ctx, endpoint, ty) {
+                quote!(, field_mask)
+            } else {
+                quote!()
+            };
+            quote!(, produces = #produces #field_mask)
         }
         None => quote!(),
     };
@@ -215,6 +220,23 @@ fn produces(ctx: &Context, ty: &Type) -> TokenStream {
     }
 }
 
+// Endpoints which already declare their own `fields` query parameter keep it.
+fn supports_field_mask(ctx: &Context, endpoint: &EndpointDefinition, ty: &Type) -> bool {
+    ctx.field_masks()
+        && !is_binary_response(ctx, ty)
+        && !endpoint.args().iter().any(|a| match a.param_type() {
+            ParameterType::Query(query) => &**query.param_id() == "fields",
+            _ => false,
+        })
+}
+
+fn is_binary_response(ctx: &Context, ty: &Type) -> bool {
+    ctx.is_binary(ty)
+        || ctx
+            .is_optional(ty)
+            .is_some_and(|inner| ctx.is_binary(inner))
+}
+
 fn auth_arg(endpoint: &EndpointDefinition) -> TokenStream {
     match endpoint.auth() {
         Some(auth) => {
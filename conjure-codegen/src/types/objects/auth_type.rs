@@ -7,6 +7,73 @@ pub enum AuthType {
     Header(super::HeaderAuthType),
     Cookie(super::CookieAuthType),
 }
+impl AuthType {
+    /// Returns `true` if this is the `header` variant.
+    #[inline]
+    pub fn is_header(&self) -> bool {
+        matches!(self, AuthType::Header(_))
+    }
+    /// Returns a reference to the value of the `header` variant, if this is that variant.
+    #[inline]
+    pub fn as_header(&self) -> Option<&super::HeaderAuthType> {
+        match self {
+            AuthType::Header(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `header` variant, if this is that variant.
+    #[inline]
+    pub fn into_header(self) -> Option<super::HeaderAuthType> {
+        match self {
+            AuthType::Header(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `cookie` variant.
+    #[inline]
+    pub fn is_cookie(&self) -> bool {
+        matches!(self, AuthType::Cookie(_))
+    }
+    /// Returns a reference to the value of the `cookie` variant, if this is that variant.
+    #[inline]
+    pub fn as_cookie(&self) -> Option<&super::CookieAuthType> {
+        match self {
+            AuthType::Cookie(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `cookie` variant, if this is that variant.
+    #[inline]
+    pub fn into_cookie(self) -> Option<super::CookieAuthType> {
+        match self {
+            AuthType::Cookie(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: AuthTypeVisitor,
+    {
+        match self {
+            AuthType::Header(value) => visitor.visit_header(value),
+            AuthType::Cookie(value) => visitor.visit_cookie(value),
+        }
+    }
+}
+/// A visitor over the variants of the [`AuthType`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait AuthTypeVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `header` variant.
+    fn visit_header(self, value: &super::HeaderAuthType) -> Self::Output;
+    /// Visits the `cookie` variant.
+    fn visit_cookie(self, value: &super::CookieAuthType) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for AuthType {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
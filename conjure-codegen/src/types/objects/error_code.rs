@@ -53,6 +53,59 @@ impl ErrorCode {
             ErrorCode::CustomServer => "CUSTOM_SERVER",
         }
     }
+    /// All known values of the enum, in definition order.
+    pub const VALUES: &'static [ErrorCode] = &[
+        ErrorCode::PermissionDenied,
+        ErrorCode::InvalidArgument,
+        ErrorCode::NotFound,
+        ErrorCode::Conflict,
+        ErrorCode::RequestEntityTooLarge,
+        ErrorCode::FailedPrecondition,
+        ErrorCode::Internal,
+        ErrorCode::Timeout,
+        ErrorCode::CustomClient,
+        ErrorCode::CustomServer,
+    ];
+    /// Returns an iterator over all known values of the enum, in definition order.
+    #[inline]
+    pub fn iter() -> std::slice::Iter<'static, ErrorCode> {
+        ErrorCode::VALUES.iter()
+    }
+    /// Returns `true` if this is a known value of the enum.
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        true
+    }
+    /// Returns the documentation of the enum value, if it is known and documented.
+    pub fn docs(&self) -> Option<&'static str> {
+        match self {
+            ErrorCode::PermissionDenied => None,
+            ErrorCode::InvalidArgument => None,
+            ErrorCode::NotFound => None,
+            ErrorCode::Conflict => None,
+            ErrorCode::RequestEntityTooLarge => None,
+            ErrorCode::FailedPrecondition => None,
+            ErrorCode::Internal => None,
+            ErrorCode::Timeout => None,
+            ErrorCode::CustomClient => None,
+            ErrorCode::CustomServer => None,
+        }
+    }
+    /// Returns the deprecation message of the enum value, if it is known and deprecated.
+    pub fn deprecated(&self) -> Option<&'static str> {
+        match self {
+            ErrorCode::PermissionDenied => None,
+            ErrorCode::InvalidArgument => None,
+            ErrorCode::NotFound => None,
+            ErrorCode::Conflict => None,
+            ErrorCode::RequestEntityTooLarge => None,
+            ErrorCode::FailedPrecondition => None,
+            ErrorCode::Internal => None,
+            ErrorCode::Timeout => None,
+            ErrorCode::CustomClient => None,
+            ErrorCode::CustomServer => None,
+        }
+    }
 }
 impl fmt::Display for ErrorCode {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
@@ -35,6 +35,41 @@ impl HttpMethod {
             HttpMethod::Delete => "DELETE",
         }
     }
+    /// All known values of the enum, in definition order.
+    pub const VALUES: &'static [HttpMethod] = &[
+        HttpMethod::Get,
+        HttpMethod::Post,
+        HttpMethod::Put,
+        HttpMethod::Delete,
+    ];
+    /// Returns an iterator over all known values of the enum, in definition order.
+    #[inline]
+    pub fn iter() -> std::slice::Iter<'static, HttpMethod> {
+        HttpMethod::VALUES.iter()
+    }
+    /// Returns `true` if this is a known value of the enum.
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        true
+    }
+    /// Returns the documentation of the enum value, if it is known and documented.
+    pub fn docs(&self) -> Option<&'static str> {
+        match self {
+            HttpMethod::Get => None,
+            HttpMethod::Post => None,
+            HttpMethod::Put => None,
+            HttpMethod::Delete => None,
+        }
+    }
+    /// Returns the deprecation message of the enum value, if it is known and deprecated.
+    pub fn deprecated(&self) -> Option<&'static str> {
+        match self {
+            HttpMethod::Get => None,
+            HttpMethod::Post => None,
+            HttpMethod::Put => None,
+            HttpMethod::Delete => None,
+        }
+    }
 }
 impl fmt::Display for HttpMethod {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
@@ -36,6 +36,46 @@ impl LogSafety {
             LogSafety::DoNotLog => "DO_NOT_LOG",
         }
     }
+    /// All known values of the enum, in definition order.
+    pub const VALUES: &'static [LogSafety] = &[
+        LogSafety::Safe,
+        LogSafety::Unsafe,
+        LogSafety::DoNotLog,
+    ];
+    /// Returns an iterator over all known values of the enum, in definition order.
+    #[inline]
+    pub fn iter() -> std::slice::Iter<'static, LogSafety> {
+        LogSafety::VALUES.iter()
+    }
+    /// Returns `true` if this is a known value of the enum.
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        true
+    }
+    /// Returns the documentation of the enum value, if it is known and documented.
+    pub fn docs(&self) -> Option<&'static str> {
+        match self {
+            LogSafety::Safe => Some("Explicitly marks an element as safe."),
+            LogSafety::Unsafe => {
+                Some(
+                    "Explicitly marks an element as unsafe, diallowing contents from being logged as `SAFE`.",
+                )
+            }
+            LogSafety::DoNotLog => {
+                Some(
+                    "Marks elements that must never be logged. For example, credentials, keys, and other secrets cannot be logged because such an action would compromise security.\n",
+                )
+            }
+        }
+    }
+    /// Returns the deprecation message of the enum value, if it is known and deprecated.
+    pub fn deprecated(&self) -> Option<&'static str> {
+        match self {
+            LogSafety::Safe => None,
+            LogSafety::Unsafe => None,
+            LogSafety::DoNotLog => None,
+        }
+    }
 }
 impl fmt::Display for LogSafety {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
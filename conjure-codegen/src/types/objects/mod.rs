@@ -5,7 +5,7 @@ pub use self::argument_definition::ArgumentDefinition;
 #[doc(inline)]
 pub use self::argument_name::ArgumentName;
 #[doc(inline)]
-pub use self::auth_type::AuthType;
+pub use self::auth_type::{AuthType, AuthTypeVisitor};
 #[doc(inline)]
 pub use self::body_parameter_type::BodyParameterType;
 #[doc(inline)]
@@ -55,7 +55,7 @@ pub use self::optional_type::OptionalType;
 #[doc(inline)]
 pub use self::parameter_id::ParameterId;
 #[doc(inline)]
-pub use self::parameter_type::ParameterType;
+pub use self::parameter_type::{ParameterType, ParameterTypeVisitor};
 #[doc(inline)]
 pub use self::path_parameter_type::PathParameterType;
 #[doc(inline)]
@@ -67,9 +67,9 @@ pub use self::service_definition::ServiceDefinition;
 #[doc(inline)]
 pub use self::set_type::SetType;
 #[doc(inline)]
-pub use self::type_::Type;
+pub use self::type_::{Type, TypeVisitor};
 #[doc(inline)]
-pub use self::type_definition::TypeDefinition;
+pub use self::type_definition::{TypeDefinition, TypeDefinitionVisitor};
 #[doc(inline)]
 pub use self::type_name::TypeName;
 #[doc(inline)]
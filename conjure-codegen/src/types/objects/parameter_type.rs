@@ -9,6 +9,121 @@ pub enum ParameterType {
     Path(super::PathParameterType),
     Query(super::QueryParameterType),
 }
+impl ParameterType {
+    /// Returns `true` if this is the `body` variant.
+    #[inline]
+    pub fn is_body(&self) -> bool {
+        matches!(self, ParameterType::Body(_))
+    }
+    /// Returns a reference to the value of the `body` variant, if this is that variant.
+    #[inline]
+    pub fn as_body(&self) -> Option<&super::BodyParameterType> {
+        match self {
+            ParameterType::Body(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `body` variant, if this is that variant.
+    #[inline]
+    pub fn into_body(self) -> Option<super::BodyParameterType> {
+        match self {
+            ParameterType::Body(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `header` variant.
+    #[inline]
+    pub fn is_header(&self) -> bool {
+        matches!(self, ParameterType::Header(_))
+    }
+    /// Returns a reference to the value of the `header` variant, if this is that variant.
+    #[inline]
+    pub fn as_header(&self) -> Option<&super::HeaderParameterType> {
+        match self {
+            ParameterType::Header(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `header` variant, if this is that variant.
+    #[inline]
+    pub fn into_header(self) -> Option<super::HeaderParameterType> {
+        match self {
+            ParameterType::Header(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `path` variant.
+    #[inline]
+    pub fn is_path(&self) -> bool {
+        matches!(self, ParameterType::Path(_))
+    }
+    /// Returns a reference to the value of the `path` variant, if this is that variant.
+    #[inline]
+    pub fn as_path(&self) -> Option<&super::PathParameterType> {
+        match self {
+            ParameterType::Path(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `path` variant, if this is that variant.
+    #[inline]
+    pub fn into_path(self) -> Option<super::PathParameterType> {
+        match self {
+            ParameterType::Path(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `query` variant.
+    #[inline]
+    pub fn is_query(&self) -> bool {
+        matches!(self, ParameterType::Query(_))
+    }
+    /// Returns a reference to the value of the `query` variant, if this is that variant.
+    #[inline]
+    pub fn as_query(&self) -> Option<&super::QueryParameterType> {
+        match self {
+            ParameterType::Query(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `query` variant, if this is that variant.
+    #[inline]
+    pub fn into_query(self) -> Option<super::QueryParameterType> {
+        match self {
+            ParameterType::Query(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: ParameterTypeVisitor,
+    {
+        match self {
+            ParameterType::Body(value) => visitor.visit_body(value),
+            ParameterType::Header(value) => visitor.visit_header(value),
+            ParameterType::Path(value) => visitor.visit_path(value),
+            ParameterType::Query(value) => visitor.visit_query(value),
+        }
+    }
+}
+/// A visitor over the variants of the [`ParameterType`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait ParameterTypeVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `body` variant.
+    fn visit_body(self, value: &super::BodyParameterType) -> Self::Output;
+    /// Visits the `header` variant.
+    fn visit_header(self, value: &super::HeaderParameterType) -> Self::Output;
+    /// Visits the `path` variant.
+    fn visit_path(self, value: &super::PathParameterType) -> Self::Output;
+    /// Visits the `query` variant.
+    fn visit_query(self, value: &super::QueryParameterType) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for ParameterType {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
@@ -56,6 +56,62 @@ impl PrimitiveType {
             PrimitiveType::Bearertoken => "BEARERTOKEN",
         }
     }
+    /// All known values of the enum, in definition order.
+    pub const VALUES: &'static [PrimitiveType] = &[
+        PrimitiveType::String,
+        PrimitiveType::Datetime,
+        PrimitiveType::Integer,
+        PrimitiveType::Double,
+        PrimitiveType::Safelong,
+        PrimitiveType::Binary,
+        PrimitiveType::Any,
+        PrimitiveType::Boolean,
+        PrimitiveType::Uuid,
+        PrimitiveType::Rid,
+        PrimitiveType::Bearertoken,
+    ];
+    /// Returns an iterator over all known values of the enum, in definition order.
+    #[inline]
+    pub fn iter() -> std::slice::Iter<'static, PrimitiveType> {
+        PrimitiveType::VALUES.iter()
+    }
+    /// Returns `true` if this is a known value of the enum.
+    #[inline]
+    pub fn is_known(&self) -> bool {
+        true
+    }
+    /// Returns the documentation of the enum value, if it is known and documented.
+    pub fn docs(&self) -> Option<&'static str> {
+        match self {
+            PrimitiveType::String => None,
+            PrimitiveType::Datetime => None,
+            PrimitiveType::Integer => None,
+            PrimitiveType::Double => None,
+            PrimitiveType::Safelong => None,
+            PrimitiveType::Binary => None,
+            PrimitiveType::Any => None,
+            PrimitiveType::Boolean => None,
+            PrimitiveType::Uuid => None,
+            PrimitiveType::Rid => None,
+            PrimitiveType::Bearertoken => None,
+        }
+    }
+    /// Returns the deprecation message of the enum value, if it is known and deprecated.
+    pub fn deprecated(&self) -> Option<&'static str> {
+        match self {
+            PrimitiveType::String => None,
+            PrimitiveType::Datetime => None,
+            PrimitiveType::Integer => None,
+            PrimitiveType::Double => None,
+            PrimitiveType::Safelong => None,
+            PrimitiveType::Binary => None,
+            PrimitiveType::Any => None,
+            PrimitiveType::Boolean => None,
+            PrimitiveType::Uuid => None,
+            PrimitiveType::Rid => None,
+            PrimitiveType::Bearertoken => None,
+        }
+    }
 }
 impl fmt::Display for PrimitiveType {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
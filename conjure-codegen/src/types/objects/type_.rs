@@ -13,6 +13,194 @@ pub enum Type {
     Reference(super::TypeName),
     External(super::ExternalReference),
 }
+impl Type {
+    /// Returns `true` if this is the `primitive` variant.
+    #[inline]
+    pub fn is_primitive(&self) -> bool {
+        matches!(self, Type::Primitive(_))
+    }
+    /// Returns a reference to the value of the `primitive` variant, if this is that variant.
+    #[inline]
+    pub fn as_primitive(&self) -> Option<&super::PrimitiveType> {
+        match self {
+            Type::Primitive(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `primitive` variant, if this is that variant.
+    #[inline]
+    pub fn into_primitive(self) -> Option<super::PrimitiveType> {
+        match self {
+            Type::Primitive(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `optional` variant.
+    #[inline]
+    pub fn is_optional(&self) -> bool {
+        matches!(self, Type::Optional(_))
+    }
+    /// Returns a reference to the value of the `optional` variant, if this is that variant.
+    #[inline]
+    pub fn as_optional(&self) -> Option<&super::OptionalType> {
+        match self {
+            Type::Optional(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `optional` variant, if this is that variant.
+    #[inline]
+    pub fn into_optional(self) -> Option<super::OptionalType> {
+        match self {
+            Type::Optional(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `list` variant.
+    #[inline]
+    pub fn is_list(&self) -> bool {
+        matches!(self, Type::List(_))
+    }
+    /// Returns a reference to the value of the `list` variant, if this is that variant.
+    #[inline]
+    pub fn as_list(&self) -> Option<&super::ListType> {
+        match self {
+            Type::List(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `list` variant, if this is that variant.
+    #[inline]
+    pub fn into_list(self) -> Option<super::ListType> {
+        match self {
+            Type::List(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `set` variant.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        matches!(self, Type::Set(_))
+    }
+    /// Returns a reference to the value of the `set` variant, if this is that variant.
+    #[inline]
+    pub fn as_set(&self) -> Option<&super::SetType> {
+        match self {
+            Type::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `set` variant, if this is that variant.
+    #[inline]
+    pub fn into_set(self) -> Option<super::SetType> {
+        match self {
+            Type::Set(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `map` variant.
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, Type::Map(_))
+    }
+    /// Returns a reference to the value of the `map` variant, if this is that variant.
+    #[inline]
+    pub fn as_map(&self) -> Option<&super::MapType> {
+        match self {
+            Type::Map(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `map` variant, if this is that variant.
+    #[inline]
+    pub fn into_map(self) -> Option<super::MapType> {
+        match self {
+            Type::Map(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `reference` variant.
+    #[inline]
+    pub fn is_reference(&self) -> bool {
+        matches!(self, Type::Reference(_))
+    }
+    /// Returns a reference to the value of the `reference` variant, if this is that variant.
+    #[inline]
+    pub fn as_reference(&self) -> Option<&super::TypeName> {
+        match self {
+            Type::Reference(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `reference` variant, if this is that variant.
+    #[inline]
+    pub fn into_reference(self) -> Option<super::TypeName> {
+        match self {
+            Type::Reference(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `external` variant.
+    #[inline]
+    pub fn is_external(&self) -> bool {
+        matches!(self, Type::External(_))
+    }
+    /// Returns a reference to the value of the `external` variant, if this is that variant.
+    #[inline]
+    pub fn as_external(&self) -> Option<&super::ExternalReference> {
+        match self {
+            Type::External(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `external` variant, if this is that variant.
+    #[inline]
+    pub fn into_external(self) -> Option<super::ExternalReference> {
+        match self {
+            Type::External(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: TypeVisitor,
+    {
+        match self {
+            Type::Primitive(value) => visitor.visit_primitive(value),
+            Type::Optional(value) => visitor.visit_optional(value),
+            Type::List(value) => visitor.visit_list(value),
+            Type::Set(value) => visitor.visit_set(value),
+            Type::Map(value) => visitor.visit_map(value),
+            Type::Reference(value) => visitor.visit_reference(value),
+            Type::External(value) => visitor.visit_external(value),
+        }
+    }
+}
+/// A visitor over the variants of the [`Type`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait TypeVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `primitive` variant.
+    fn visit_primitive(self, value: &super::PrimitiveType) -> Self::Output;
+    /// Visits the `optional` variant.
+    fn visit_optional(self, value: &super::OptionalType) -> Self::Output;
+    /// Visits the `list` variant.
+    fn visit_list(self, value: &super::ListType) -> Self::Output;
+    /// Visits the `set` variant.
+    fn visit_set(self, value: &super::SetType) -> Self::Output;
+    /// Visits the `map` variant.
+    fn visit_map(self, value: &super::MapType) -> Self::Output;
+    /// Visits the `reference` variant.
+    /// The name and package of a custom Conjure type. The custom type must be defined in the "types" section.
+    fn visit_reference(self, value: &super::TypeName) -> Self::Output;
+    /// Visits the `external` variant.
+    fn visit_external(self, value: &super::ExternalReference) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for Type {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
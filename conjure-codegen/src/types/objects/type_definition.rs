@@ -9,6 +9,121 @@ pub enum TypeDefinition {
     Object(super::ObjectDefinition),
     Union(super::UnionDefinition),
 }
+impl TypeDefinition {
+    /// Returns `true` if this is the `alias` variant.
+    #[inline]
+    pub fn is_alias(&self) -> bool {
+        matches!(self, TypeDefinition::Alias(_))
+    }
+    /// Returns a reference to the value of the `alias` variant, if this is that variant.
+    #[inline]
+    pub fn as_alias(&self) -> Option<&super::AliasDefinition> {
+        match self {
+            TypeDefinition::Alias(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `alias` variant, if this is that variant.
+    #[inline]
+    pub fn into_alias(self) -> Option<super::AliasDefinition> {
+        match self {
+            TypeDefinition::Alias(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `enum` variant.
+    #[inline]
+    pub fn is_enum(&self) -> bool {
+        matches!(self, TypeDefinition::Enum(_))
+    }
+    /// Returns a reference to the value of the `enum` variant, if this is that variant.
+    #[inline]
+    pub fn as_enum(&self) -> Option<&super::EnumDefinition> {
+        match self {
+            TypeDefinition::Enum(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `enum` variant, if this is that variant.
+    #[inline]
+    pub fn into_enum(self) -> Option<super::EnumDefinition> {
+        match self {
+            TypeDefinition::Enum(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `object` variant.
+    #[inline]
+    pub fn is_object(&self) -> bool {
+        matches!(self, TypeDefinition::Object(_))
+    }
+    /// Returns a reference to the value of the `object` variant, if this is that variant.
+    #[inline]
+    pub fn as_object(&self) -> Option<&super::ObjectDefinition> {
+        match self {
+            TypeDefinition::Object(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `object` variant, if this is that variant.
+    #[inline]
+    pub fn into_object(self) -> Option<super::ObjectDefinition> {
+        match self {
+            TypeDefinition::Object(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns `true` if this is the `union` variant.
+    #[inline]
+    pub fn is_union(&self) -> bool {
+        matches!(self, TypeDefinition::Union(_))
+    }
+    /// Returns a reference to the value of the `union` variant, if this is that variant.
+    #[inline]
+    pub fn as_union(&self) -> Option<&super::UnionDefinition> {
+        match self {
+            TypeDefinition::Union(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Returns the value of the `union` variant, if this is that variant.
+    #[inline]
+    pub fn into_union(self) -> Option<super::UnionDefinition> {
+        match self {
+            TypeDefinition::Union(value) => Some(value),
+            _ => None,
+        }
+    }
+    /// Passes the value of this union's variant to the corresponding method of a visitor.
+    pub fn accept<V>(&self, visitor: V) -> V::Output
+    where
+        V: TypeDefinitionVisitor,
+    {
+        match self {
+            TypeDefinition::Alias(value) => visitor.visit_alias(value),
+            TypeDefinition::Enum(value) => visitor.visit_enum(value),
+            TypeDefinition::Object(value) => visitor.visit_object(value),
+            TypeDefinition::Union(value) => visitor.visit_union(value),
+        }
+    }
+}
+/// A visitor over the variants of the [`TypeDefinition`] union.
+///
+/// `visit_unknown` is never called for unions generated with exhaustive matching.
+pub trait TypeDefinitionVisitor {
+    /// The value produced by the visitor.
+    type Output;
+    /// Visits the `alias` variant.
+    fn visit_alias(self, value: &super::AliasDefinition) -> Self::Output;
+    /// Visits the `enum` variant.
+    fn visit_enum(self, value: &super::EnumDefinition) -> Self::Output;
+    /// Visits the `object` variant.
+    fn visit_object(self, value: &super::ObjectDefinition) -> Self::Output;
+    /// Visits the `union` variant.
+    fn visit_union(self, value: &super::UnionDefinition) -> Self::Output;
+    /// Visits an unknown variant, identified by its type name.
+    fn visit_unknown(self, type_: &str) -> Self::Output;
+}
 impl ser::Serialize for TypeDefinition {
     fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
     where
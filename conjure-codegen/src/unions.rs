@@ -11,19 +11,35 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use proc_macro2::{Ident, TokenStream};
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::iter;
 
+use crate::arbitrary;
 use crate::context::{BaseModule, Context};
-use crate::types::objects::UnionDefinition;
+use crate::debug;
+use crate::diff;
+use crate::field_mask;
+use crate::types::objects::{FieldDefinition, PrimitiveType, Type, UnionDefinition};
+
+pub fn visitor_name(ctx: &Context, def: &UnionDefinition) -> String {
+    format!("{}Visitor", ctx.type_name(def.type_name().name()))
+}
 
 pub fn generate(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     let enum_ = generate_enum(ctx, def);
+    let accessors = generate_accessors(ctx, def);
+    let visitor = generate_visitor(ctx, def);
     let serialize = generate_serialize(ctx, def);
     let deserialize = generate_deserialize(ctx, def);
     let variant = generate_variant(ctx, def);
     let unknown = generate_unknown(ctx, def);
+    let arbitrary = arbitrary::generate_union(ctx, def, &self::unknown(ctx, def));
+    let diff = diff::generate_union(ctx, def, &self::unknown(ctx, def));
+    let field_mask = field_mask::generate_union(ctx, def);
+    let debug = debug::generate_union(ctx, def, &self::unknown(ctx, def));
+    let double_ops_leaf = ctx.double_ops_leaf(def.type_name());
 
     quote! {
         use conjure_object::serde::{ser, de};
@@ -32,21 +48,28 @@ pub fn generate(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         use std::fmt;
 
         #enum_
+        #accessors
+        #visitor
         #serialize
         #deserialize
         #variant
         #unknown
+        #arbitrary
+        #diff
+        #field_mask
+        #debug
+        #double_ops_leaf
     }
 }
 
-fn variants(ctx: &Context, def: &UnionDefinition) -> Vec<Ident> {
+pub fn variants(ctx: &Context, def: &UnionDefinition) -> Vec<Ident> {
     def.union_()
         .iter()
         .map(|f| ctx.type_name(f.field_name()))
         .collect()
 }
 
-fn unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+pub fn unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     if variants(ctx, def).iter().any(|f| f == "Unknown") {
         quote!(Unknown_)
     } else {
@@ -58,8 +81,15 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     let name = ctx.type_name(def.type_name().name());
 
     let mut type_attrs = vec![];
-    let mut derives = vec!["Debug", "Clone"];
-    if def.union_().iter().any(|v| ctx.has_double(v.type_())) {
+    let mut derives = vec!["Clone"];
+    if !debug::redacts_union(ctx, def) {
+        derives.insert(0, "Debug");
+    }
+    if def
+        .union_()
+        .iter()
+        .any(|v| ctx.has_double(v.type_()) || ctx.is_unordered(v.type_()))
+    {
         derives.push("conjure_object::private::DeriveWith");
         type_attrs.push(quote!(#[derive_with(PartialEq, Eq, PartialOrd, Ord, Hash)]));
     } else {
@@ -86,7 +116,7 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         .union_()
         .iter()
         .map(|f| {
-            let double_attr = if ctx.is_double(f.type_()) {
+            let double_attr = if ctx.is_double(f.type_()) || ctx.is_unordered(f.type_()) {
                 quote! {
                     #[derive_with(with = conjure_object::private::DoubleWrapper)]
                 }
@@ -107,7 +137,7 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         .collect::<Vec<_>>();
 
     let unknown = unknown(ctx, def);
-    let unknown_variant = if ctx.exhaustive() {
+    let unknown_variant = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         quote! {
@@ -129,12 +159,239 @@ fn generate_enum(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     }
 }
 
+struct VariantType {
+    // The type of the variant's value, without the box that recursive types are stored in
+    owned: TokenStream,
+    // The type referenced by accessors and visitors
+    borrowed: TokenStream,
+    boxed: bool,
+    deref: bool,
+}
+
+fn variant_type(ctx: &Context, def: &UnionDefinition, field: &FieldDefinition) -> VariantType {
+    let boxed_type = ctx.boxed_rust_type(BaseModule::Objects, def.type_name(), field.type_());
+    let unboxed_type = ctx.rust_type(BaseModule::Objects, def.type_name(), field.type_());
+    let box_ = ctx.box_ident(def.type_name());
+
+    if boxed_type.to_string() == quote!(#box_<#unboxed_type>).to_string() {
+        return VariantType {
+            borrowed: unboxed_type.clone(),
+            owned: unboxed_type,
+            boxed: true,
+            deref: true,
+        };
+    }
+
+    let borrowed = match field.type_() {
+        Type::Primitive(PrimitiveType::String) => Some(quote!(str)),
+        Type::List(list) => {
+            let item = ctx.rust_type(BaseModule::Objects, def.type_name(), list.item_type());
+            Some(quote!([#item]))
+        }
+        _ => None,
+    };
+    match borrowed {
+        Some(borrowed) => VariantType {
+            owned: boxed_type,
+            borrowed,
+            boxed: false,
+            deref: true,
+        },
+        None => VariantType {
+            owned: boxed_type.clone(),
+            borrowed: boxed_type,
+            boxed: false,
+            deref: false,
+        },
+    }
+}
+
+fn method_name(prefix: &str, field: &FieldDefinition) -> Ident {
+    Ident::new(
+        &format!("{prefix}_{}", field.field_name().to_snake_case()),
+        Span::call_site(),
+    )
+}
+
+fn generate_accessors(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let option = ctx.option_ident(def.type_name());
+    let some = ctx.some_ident(def.type_name());
+    let none = ctx.none_ident(def.type_name());
+    let variants = variants(ctx, def);
+
+    let accessors = def.union_().iter().zip(&variants).map(|(f, variant)| {
+        let deprecated = ctx.deprecated(f.deprecated());
+        let allow_deprecated = ctx.allow_deprecated(f.deprecated());
+        let type_ = variant_type(ctx, def, f);
+        let owned = &type_.owned;
+        let borrowed = &type_.borrowed;
+        let is = method_name("is", f);
+        let as_ = method_name("as", f);
+        let into = method_name("into", f);
+        let is_doc = format!(
+            " Returns `true` if this is the `{}` variant.",
+            f.field_name()
+        );
+        let as_doc = format!(
+            " Returns a reference to the value of the `{}` variant, if this is that variant.",
+            f.field_name(),
+        );
+        let into_doc = format!(
+            " Returns the value of the `{}` variant, if this is that variant.",
+            f.field_name(),
+        );
+        let as_value = if type_.deref {
+            quote!(&**value)
+        } else {
+            quote!(value)
+        };
+        let into_value = if type_.boxed {
+            quote!(*value)
+        } else {
+            quote!(value)
+        };
+        // Matches on unions with a single variant are irrefutable
+        let other = if def.union_().len() == 1 && ctx.exhaustive(def.type_name()) {
+            quote!()
+        } else {
+            quote!(_ => #none,)
+        };
+
+        quote! {
+            #[doc = #is_doc]
+            #[inline]
+            #deprecated
+            #allow_deprecated
+            pub fn #is(&self) -> bool {
+                matches!(self, #name::#variant(_))
+            }
+
+            #[doc = #as_doc]
+            #[inline]
+            #deprecated
+            #allow_deprecated
+            pub fn #as_(&self) -> #option<&#borrowed> {
+                match self {
+                    #name::#variant(value) => #some(#as_value),
+                    #other
+                }
+            }
+
+            #[doc = #into_doc]
+            #[inline]
+            #deprecated
+            #allow_deprecated
+            pub fn #into(self) -> #option<#owned> {
+                match self {
+                    #name::#variant(value) => #some(#into_value),
+                    #other
+                }
+            }
+        }
+    });
+
+    let visitor_name = Ident::new(&visitor_name(ctx, def), Span::call_site());
+    let visit_unknown = visit_unknown(def);
+    let visit_arms = def.union_().iter().zip(&variants).map(|(f, variant)| {
+        let allow_deprecated = ctx.allow_deprecated(f.deprecated());
+        let visit = method_name("visit", f);
+        quote! {
+            #allow_deprecated
+            #name::#variant(value) => visitor.#visit(value),
+        }
+    });
+    let visit_unknown_arm = if ctx.exhaustive(def.type_name()) {
+        quote!()
+    } else {
+        let unknown = unknown(ctx, def);
+        quote! {
+            #name::#unknown(value) => visitor.#visit_unknown(value.type_()),
+        }
+    };
+
+    // Matching on a reference to an empty enum isn't considered exhaustive
+    let (self_, visitor) = if def.union_().is_empty() && ctx.exhaustive(def.type_name()) {
+        (quote!(*self), quote!(_))
+    } else {
+        (quote!(self), quote!(visitor))
+    };
+
+    quote! {
+        impl #name {
+            #(#accessors)*
+
+            /// Passes the value of this union's variant to the corresponding method of a visitor.
+            pub fn accept<V>(&self, #visitor: V) -> V::Output
+            where
+                V: #visitor_name,
+            {
+                match #self_ {
+                    #(#visit_arms)*
+                    #visit_unknown_arm
+                }
+            }
+        }
+    }
+}
+
+fn visit_unknown(def: &UnionDefinition) -> Ident {
+    let name = if def
+        .union_()
+        .iter()
+        .any(|f| f.field_name().to_snake_case() == "unknown")
+    {
+        "visit_unknown_"
+    } else {
+        "visit_unknown"
+    };
+    Ident::new(name, Span::call_site())
+}
+
+fn generate_visitor(ctx: &Context, def: &UnionDefinition) -> TokenStream {
+    let name = ctx.type_name(def.type_name().name());
+    let visitor_name = Ident::new(&visitor_name(ctx, def), Span::call_site());
+    let doc = format!(" A visitor over the variants of the [`{name}`] union.");
+
+    let methods = def.union_().iter().map(|f| {
+        let docs = ctx.docs(f.docs());
+        let deprecated = ctx.deprecated(f.deprecated());
+        let visit = method_name("visit", f);
+        let borrowed = variant_type(ctx, def, f).borrowed;
+        let doc = format!(" Visits the `{}` variant.", f.field_name());
+
+        quote! {
+            #[doc = #doc]
+            #docs
+            #deprecated
+            fn #visit(self, value: &#borrowed) -> Self::Output;
+        }
+    });
+
+    let visit_unknown = visit_unknown(def);
+
+    quote! {
+        #[doc = #doc]
+        ///
+        /// `visit_unknown` is never called for unions generated with exhaustive matching.
+        pub trait #visitor_name {
+            /// The value produced by the visitor.
+            type Output;
+
+            #(#methods)*
+
+            /// Visits an unknown variant, identified by its type name.
+            fn #visit_unknown(self, type_: &str) -> Self::Output;
+        }
+    }
+}
+
 fn generate_serialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
     let name = ctx.type_name(def.type_name().name());
     let result = ctx.result_ident(def.type_name());
     let some = ctx.some_ident(def.type_name());
 
-    if def.union_().is_empty() && ctx.exhaustive() {
+    if def.union_().is_empty() && ctx.exhaustive(def.type_name()) {
         return quote! {
             impl ser::Serialize for #name {
                 fn serialize<S>(&self, _: S) -> #result<S::Ok, S::Error>
@@ -147,7 +404,7 @@ fn generate_serialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         };
     }
 
-    let serialize_unknown = if ctx.exhaustive() {
+    let serialize_unknown = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         let unknown = unknown(ctx, def);
@@ -222,7 +479,7 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let err = ctx.err_ident(def.type_name());
 
-    let unknown_match1 = if ctx.exhaustive() {
+    let unknown_match1 = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         quote! {
@@ -241,7 +498,7 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let name_repeat2 = iter::repeat(&name);
 
-    let unknown_match2 = if ctx.exhaustive() {
+    let unknown_match2 = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         quote! {
@@ -254,7 +511,7 @@ fn generate_deserialize(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let ok = ctx.ok_ident(def.type_name());
 
-    let visit_map_body = if def.union_().is_empty() && ctx.exhaustive() {
+    let visit_map_body = if def.union_().is_empty() && ctx.exhaustive(def.type_name()) {
         quote! {
             match map.next_key::<UnionField_<Variant_>>()? {
                 #some(UnionField_::Type) => match map.next_value::<Variant_>()? {}
@@ -363,7 +620,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let unknown = unknown(ctx, def);
 
-    let unknown_variant = if ctx.exhaustive() {
+    let unknown_variant = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         let box_ = ctx.box_ident(def.type_name());
@@ -376,7 +633,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         .map(|f| &f.field_name().0)
         .collect::<Vec<_>>();
 
-    let unknown_as_str = if ctx.exhaustive() {
+    let unknown_as_str = if ctx.exhaustive(def.type_name()) {
         quote!()
     } else {
         quote! {
@@ -386,7 +643,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 
     let result = ctx.result_ident(def.type_name());
 
-    let unknown_de_visit_str = if ctx.exhaustive() {
+    let unknown_de_visit_str = if ctx.exhaustive(def.type_name()) {
         let err = ctx.err_ident(def.type_name());
         quote! {
             value => return #err(de::Error::unknown_variant(value, &[#(#variant_strs, )*])),
@@ -408,7 +665,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
         }
     };
 
-    let de_visit_str_body = if def.union_().is_empty() && ctx.exhaustive() {
+    let de_visit_str_body = if def.union_().is_empty() && ctx.exhaustive(def.type_name()) {
         de_visit_str_match
     } else {
         quote! {
@@ -464,7 +721,7 @@ fn generate_variant(ctx: &Context, def: &UnionDefinition) -> TokenStream {
 }
 
 fn generate_unknown(ctx: &Context, def: &UnionDefinition) -> TokenStream {
-    if ctx.exhaustive() {
+    if ctx.exhaustive(def.type_name()) {
         return quote!();
     }
 
@@ -0,0 +1,938 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Conversion of parsed Conjure YAML files into the IR.
+use crate::definition_name;
+use crate::types::objects::{
+    AliasDefinition, ArgumentDefinition, ArgumentName, AuthType, BodyParameterType,
+    ConjureDefinition, CookieAuthType, Documentation, EndpointDefinition, EndpointName,
+    EnumDefinition, EnumValueDefinition, ErrorCode, ErrorDefinition, ErrorNamespace,
+    ExternalReference, FieldDefinition, FieldName, HeaderAuthType, HeaderParameterType, HttpMethod,
+    HttpPath, ListType, LogSafety, MapType, ObjectDefinition, OptionalType, ParameterId,
+    ParameterType, PathParameterType, PrimitiveType, QueryParameterType, ServiceDefinition,
+    SetType, Type, TypeDefinition, TypeName, UnionDefinition,
+};
+use crate::yaml::node::{Entry, Error, Mark, Node, Object};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+const ROOT_KEYS: &[&str] = &["types", "services"];
+const TYPES_KEYS: &[&str] = &["conjure-imports", "imports", "definitions"];
+const DEFINITIONS_KEYS: &[&str] = &["default-package", "objects", "errors"];
+const TYPE_KEYS: &[&str] = &[
+    "alias", "fields", "union", "values", "package", "docs", "safety",
+];
+
+/// A parsed Conjure YAML file.
+pub struct File {
+    pub node: Node,
+    /// The files referenced by the file's `conjure-imports`, keyed by namespace.
+    pub imports: BTreeMap<String, usize>,
+}
+
+/// Returns the `conjure-imports` of a file as `(namespace, path)` pairs.
+pub fn conjure_imports(node: &Node) -> Result<Vec<(String, Mark, String)>, Error> {
+    let types = match types(node)? {
+        Some(types) => types,
+        None => return Ok(vec![]),
+    };
+    let imports = match types.get("conjure-imports") {
+        Some(imports) => imports,
+        None => return Ok(vec![]),
+    };
+
+    imports
+        .entries()?
+        .into_iter()
+        .map(|e| {
+            Ok((
+                e.key.to_string(),
+                e.value.mark,
+                e.value.as_str()?.to_string(),
+            ))
+        })
+        .collect()
+}
+
+/// Compiles a set of files into a single definition.
+///
+/// On error, the index of the file containing the error is returned along with the error itself.
+pub fn compile(files: &[File]) -> Result<ConjureDefinition, (usize, Error)> {
+    let scopes = files
+        .iter()
+        .enumerate()
+        .map(|(i, file)| Scope::new(&file.node).map_err(|e| (i, e)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut definitions = Definitions {
+        types: vec![],
+        errors: vec![],
+        services: vec![],
+        names: HashSet::new(),
+    };
+    for (i, file) in files.iter().enumerate() {
+        FileCompiler {
+            file,
+            scope: &scopes[i],
+            scopes: &scopes,
+        }
+        .compile(&mut definitions)
+        .map_err(|e| (i, e))?;
+    }
+
+    definitions
+        .types
+        .sort_by(|a, b| definition_name(a).cmp(definition_name(b)));
+    definitions
+        .errors
+        .sort_by(|a, b| a.error_name().cmp(b.error_name()));
+    definitions
+        .services
+        .sort_by(|a, b| a.service_name().cmp(b.service_name()));
+
+    Ok(ConjureDefinition::builder()
+        .version(1)
+        .errors(definitions.errors)
+        .types(definitions.types)
+        .services(definitions.services)
+        .build())
+}
+
+fn types(node: &Node) -> Result<Option<Object<'_>>, Error> {
+    if node.is_null() {
+        return Ok(None);
+    }
+
+    node.object(ROOT_KEYS)?
+        .get("types")
+        .map(|types| types.object(TYPES_KEYS))
+        .transpose()
+}
+
+fn package<'a>(
+    object: &Object<'a>,
+    default_package: Option<&'a str>,
+    entry: &Entry<'_>,
+) -> Result<&'a str, Error> {
+    object.str("package")?.or(default_package).ok_or_else(|| {
+        Error::new(
+            entry.mark,
+            format!(
+                "`{}` has no package and there is no `default-package`",
+                entry.key
+            ),
+        )
+    })
+}
+
+/// The types which can be referenced by name from within a file.
+struct Scope {
+    types: HashMap<String, Type>,
+    /// The names of objects and unions, which can't be used as map keys.
+    complex_types: HashSet<TypeName>,
+}
+
+impl Scope {
+    fn new(node: &Node) -> Result<Scope, Error> {
+        let mut scope = Scope {
+            types: HashMap::new(),
+            complex_types: HashSet::new(),
+        };
+
+        let types = match types(node)? {
+            Some(types) => types,
+            None => return Ok(scope),
+        };
+
+        if let Some(imports) = types.get("imports") {
+            for entry in imports.entries()? {
+                let import = entry.value.object(&["base-type", "external", "safety"])?;
+                // The IR doesn't record the safety of external imports, but it's still validated.
+                safety(&import)?;
+                let external = import.required("external")?.object(&["java"])?;
+                let java = external.required("java")?;
+                let (package, name) = java.as_str()?.rsplit_once('.').ok_or_else(|| {
+                    Error::new(java.mark, "expected a fully qualified class name")
+                })?;
+                let fallback = match import.get("base-type") {
+                    Some(base_type) => {
+                        Type::Primitive(primitive(base_type.as_str()?).ok_or_else(|| {
+                            Error::new(base_type.mark, "`base-type` must be a primitive type")
+                        })?)
+                    }
+                    None => Type::Primitive(PrimitiveType::Any),
+                };
+                scope.insert(
+                    &entry,
+                    Type::External(ExternalReference::new(
+                        TypeName::new(name, package),
+                        fallback,
+                    )),
+                )?;
+            }
+        }
+
+        if let Some(definitions) = types.get("definitions") {
+            let definitions = definitions.object(DEFINITIONS_KEYS)?;
+            let default_package = definitions.str("default-package")?;
+            if let Some(objects) = definitions.get("objects") {
+                for entry in objects.entries()? {
+                    let object = entry.value.object(TYPE_KEYS)?;
+                    let name = TypeName::new(entry.key, package(&object, default_package, &entry)?);
+                    if object.get("fields").is_some() || object.get("union").is_some() {
+                        scope.complex_types.insert(name.clone());
+                    }
+                    scope.insert(&entry, Type::Reference(name))?;
+                }
+            }
+        }
+
+        Ok(scope)
+    }
+
+    fn insert(&mut self, entry: &Entry<'_>, type_: Type) -> Result<(), Error> {
+        if self.types.insert(entry.key.to_string(), type_).is_some() {
+            return Err(Error::new(
+                entry.mark,
+                format!("`{}` is defined more than once", entry.key),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+struct Definitions {
+    types: Vec<TypeDefinition>,
+    errors: Vec<ErrorDefinition>,
+    services: Vec<ServiceDefinition>,
+    names: HashSet<TypeName>,
+}
+
+impl Definitions {
+    fn check_unique(&mut self, name: &TypeName, mark: Mark) -> Result<(), Error> {
+        if !self.names.insert(name.clone()) {
+            return Err(Error::new(
+                mark,
+                format!(
+                    "`{}.{}` is defined more than once",
+                    name.package(),
+                    name.name()
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+struct FileCompiler<'a> {
+    file: &'a File,
+    scope: &'a Scope,
+    scopes: &'a [Scope],
+}
+
+impl FileCompiler<'_> {
+    fn compile(&self, definitions: &mut Definitions) -> Result<(), Error> {
+        if self.file.node.is_null() {
+            return Ok(());
+        }
+        let root = self.file.node.object(ROOT_KEYS)?;
+
+        if let Some(types) = root.get("types") {
+            let types = types.object(TYPES_KEYS)?;
+            if let Some(defs) = types.get("definitions") {
+                let defs = defs.object(DEFINITIONS_KEYS)?;
+                let default_package = defs.str("default-package")?;
+
+                if let Some(objects) = defs.get("objects") {
+                    for entry in objects.entries()? {
+                        let def = self.type_definition(&entry, default_package)?;
+                        definitions.check_unique(definition_name(&def), entry.mark)?;
+                        definitions.types.push(def);
+                    }
+                }
+
+                if let Some(errors) = defs.get("errors") {
+                    for entry in errors.entries()? {
+                        let def = self.error_definition(&entry, default_package)?;
+                        definitions.check_unique(def.error_name(), entry.mark)?;
+                        definitions.errors.push(def);
+                    }
+                }
+            }
+        }
+
+        if let Some(services) = root.get("services") {
+            for entry in services.entries()? {
+                let def = self.service_definition(&entry)?;
+                definitions.check_unique(def.service_name(), entry.mark)?;
+                definitions.services.push(def);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_definition(
+        &self,
+        entry: &Entry<'_>,
+        default_package: Option<&str>,
+    ) -> Result<TypeDefinition, Error> {
+        let object = entry.value.object(TYPE_KEYS)?;
+        let type_name = TypeName::new(entry.key, package(&object, default_package, entry)?);
+        let docs = docs(&object, "docs")?;
+
+        let kinds = ["alias", "fields", "union", "values"]
+            .iter()
+            .filter(|k| object.get(k).is_some())
+            .collect::<Vec<_>>();
+        if kinds.len() != 1 {
+            return Err(Error::new(
+                entry.mark,
+                format!(
+                    "`{}` must have exactly one of `alias`, `fields`, `union`, or `values`",
+                    entry.key
+                ),
+            ));
+        }
+        if *kinds[0] != "alias" && object.get("safety").is_some() {
+            return Err(Error::new(
+                entry.mark,
+                "`safety` can only be set on aliases",
+            ));
+        }
+
+        let def = match *kinds[0] {
+            "alias" => TypeDefinition::Alias(
+                AliasDefinition::builder()
+                    .type_name(type_name)
+                    .alias(self.resolve(object.required("alias")?)?)
+                    .docs(docs)
+                    .safety(safety(&object)?)
+                    .build(),
+            ),
+            "fields" => TypeDefinition::Object(
+                ObjectDefinition::builder()
+                    .type_name(type_name)
+                    .fields(self.fields(object.required("fields")?)?)
+                    .docs(docs)
+                    .build(),
+            ),
+            "union" => TypeDefinition::Union(
+                UnionDefinition::builder()
+                    .type_name(type_name)
+                    .union_(self.fields(object.required("union")?)?)
+                    .docs(docs)
+                    .build(),
+            ),
+            _ => TypeDefinition::Enum(
+                EnumDefinition::builder()
+                    .type_name(type_name)
+                    .values(enum_values(object.required("values")?)?)
+                    .docs(docs)
+                    .build(),
+            ),
+        };
+
+        Ok(def)
+    }
+
+    fn fields(&self, node: &Node) -> Result<Vec<FieldDefinition>, Error> {
+        node.entries()?
+            .iter()
+            .map(|entry| self.field(entry))
+            .collect()
+    }
+
+    fn field(&self, entry: &Entry<'_>) -> Result<FieldDefinition, Error> {
+        if !is_camel_case(entry.key) {
+            return Err(Error::new(
+                entry.mark,
+                format!("field name `{}` must be lowerCamelCase", entry.key),
+            ));
+        }
+        let field_name = FieldName(entry.key.to_string());
+        if entry.value.as_str().is_ok() {
+            return Ok(FieldDefinition::new(field_name, self.resolve(entry.value)?));
+        }
+
+        let object = entry
+            .value
+            .object(&["type", "docs", "deprecated", "safety"])?;
+        Ok(FieldDefinition::builder()
+            .field_name(field_name)
+            .type_(self.resolve(object.required("type")?)?)
+            .docs(docs(&object, "docs")?)
+            .deprecated(docs(&object, "deprecated")?)
+            .safety(safety(&object)?)
+            .build())
+    }
+
+    fn error_definition(
+        &self,
+        entry: &Entry<'_>,
+        default_package: Option<&str>,
+    ) -> Result<ErrorDefinition, Error> {
+        let object = entry.value.object(&[
+            "namespace",
+            "code",
+            "package",
+            "docs",
+            "safe-args",
+            "unsafe-args",
+        ])?;
+
+        let code = object.required("code")?;
+        let code = code.as_str()?.parse::<ErrorCode>().map_err(|_| {
+            Error::new(
+                code.mark,
+                format!("unknown error code `{}`", code.as_str().unwrap()),
+            )
+        })?;
+
+        let safe_args = match object.get("safe-args") {
+            Some(args) => self.fields(args)?,
+            None => vec![],
+        };
+        let unsafe_args = match object.get("unsafe-args") {
+            Some(args) => self.fields(args)?,
+            None => vec![],
+        };
+
+        Ok(ErrorDefinition::builder()
+            .error_name(TypeName::new(
+                entry.key,
+                package(&object, default_package, entry)?,
+            ))
+            .namespace(ErrorNamespace(
+                object.required("namespace")?.as_str()?.to_string(),
+            ))
+            .code(code)
+            .docs(docs(&object, "docs")?)
+            .safe_args(safe_args)
+            .unsafe_args(unsafe_args)
+            .build())
+    }
+
+    fn service_definition(&self, entry: &Entry<'_>) -> Result<ServiceDefinition, Error> {
+        let object = entry.value.object(&[
+            "name",
+            "package",
+            "base-path",
+            "default-auth",
+            "docs",
+            "endpoints",
+        ])?;
+
+        let base_path = match object.get("base-path") {
+            Some(base_path) => {
+                let path = base_path.as_str()?;
+                if !path.starts_with('/') {
+                    return Err(Error::new(
+                        base_path.mark,
+                        "`base-path` must start with `/`",
+                    ));
+                }
+                path
+            }
+            None => "",
+        };
+        let default_auth = match object.get("default-auth") {
+            Some(auth) => self::auth(auth)?,
+            None => None,
+        };
+
+        let mut endpoints = vec![];
+        if let Some(node) = object.get("endpoints") {
+            for entry in node.entries()? {
+                endpoints.push(self.endpoint(&entry, base_path, &default_auth)?);
+            }
+        }
+
+        Ok(ServiceDefinition::builder()
+            .service_name(TypeName::new(
+                entry.key,
+                object.required("package")?.as_str()?,
+            ))
+            .endpoints(endpoints)
+            .docs(docs(&object, "docs")?)
+            .build())
+    }
+
+    fn endpoint(
+        &self,
+        entry: &Entry<'_>,
+        base_path: &str,
+        default_auth: &Option<AuthType>,
+    ) -> Result<EndpointDefinition, Error> {
+        let object = entry.value.object(&[
+            "http",
+            "auth",
+            "returns",
+            "args",
+            "docs",
+            "deprecated",
+            "markers",
+            "tags",
+        ])?;
+
+        let http = object.required("http")?;
+        let (method, path) = http
+            .as_str()?
+            .split_once(' ')
+            .ok_or_else(|| Error::new(http.mark, "expected `<method> <path>`"))?;
+        let method = method
+            .parse::<HttpMethod>()
+            .map_err(|_| Error::new(http.mark, format!("unknown HTTP method `{method}`")))?;
+        let path = path.trim();
+        if !path.starts_with('/') {
+            return Err(Error::new(http.mark, "endpoint path must start with `/`"));
+        }
+        let path_params = path_params(path);
+
+        let auth = match object.get("auth") {
+            Some(auth) => self::auth(auth)?,
+            None => default_auth.clone(),
+        };
+
+        let mut args = vec![];
+        if let Some(node) = object.get("args") {
+            for entry in node.entries()? {
+                args.push(self.argument(&entry, &path_params)?);
+            }
+        }
+
+        for param in &path_params {
+            let found = args.iter().any(|a: &ArgumentDefinition| {
+                a.arg_name().0 == *param && matches!(a.param_type(), ParameterType::Path(_))
+            });
+            if !found {
+                return Err(Error::new(
+                    http.mark,
+                    format!("path parameter `{param}` has no corresponding argument"),
+                ));
+            }
+        }
+        let bodies = args
+            .iter()
+            .filter(|a| matches!(a.param_type(), ParameterType::Body(_)))
+            .count();
+        if bodies > 1 {
+            return Err(Error::new(
+                entry.mark,
+                format!("`{}` has more than one body argument", entry.key),
+            ));
+        }
+        if bodies == 1 && method == HttpMethod::Get {
+            return Err(Error::new(
+                entry.mark,
+                format!("`{}` is a GET endpoint but has a body argument", entry.key),
+            ));
+        }
+
+        Ok(EndpointDefinition::builder()
+            .endpoint_name(EndpointName(entry.key.to_string()))
+            .http_method(method)
+            .http_path(HttpPath(join_path(base_path, path)))
+            .auth(auth)
+            .args(args)
+            .returns(
+                object
+                    .get("returns")
+                    .map(|returns| self.resolve(returns))
+                    .transpose()?,
+            )
+            .docs(docs(&object, "docs")?)
+            .deprecated(docs(&object, "deprecated")?)
+            .markers(self.markers(&object)?)
+            .tags(tags(&object)?)
+            .build())
+    }
+
+    fn argument(
+        &self,
+        entry: &Entry<'_>,
+        path_params: &[&str],
+    ) -> Result<ArgumentDefinition, Error> {
+        let is_path_param = path_params.contains(&entry.key);
+        let auto = || {
+            if is_path_param {
+                ParameterType::Path(PathParameterType::new())
+            } else {
+                ParameterType::Body(BodyParameterType::new())
+            }
+        };
+
+        if entry.value.as_str().is_ok() {
+            return Ok(ArgumentDefinition::new(
+                ArgumentName(entry.key.to_string()),
+                self.resolve(entry.value)?,
+                auto(),
+            ));
+        }
+
+        let object = entry.value.object(&[
+            "type",
+            "param-type",
+            "param-id",
+            "docs",
+            "markers",
+            "tags",
+            "safety",
+        ])?;
+        let param_id = object.str("param-id")?;
+
+        let param_type = match object.str("param-type")?.unwrap_or("auto") {
+            "auto" => auto(),
+            "path" => {
+                if !is_path_param {
+                    return Err(Error::new(
+                        entry.mark,
+                        format!("`{}` does not appear in the endpoint's path", entry.key),
+                    ));
+                }
+                ParameterType::Path(PathParameterType::new())
+            }
+            "body" => ParameterType::Body(BodyParameterType::new()),
+            "query" => ParameterType::Query(QueryParameterType::new(ParameterId(
+                param_id.unwrap_or(entry.key).to_string(),
+            ))),
+            "header" => {
+                let param_id = param_id.ok_or_else(|| {
+                    Error::new(
+                        entry.mark,
+                        format!("header argument `{}` must set `param-id`", entry.key),
+                    )
+                })?;
+                ParameterType::Header(HeaderParameterType::new(ParameterId(param_id.to_string())))
+            }
+            other => {
+                return Err(Error::new(
+                    object.required("param-type")?.mark,
+                    format!("unknown parameter type `{other}`"),
+                ))
+            }
+        };
+        if param_id.is_some()
+            && matches!(param_type, ParameterType::Path(_) | ParameterType::Body(_))
+        {
+            return Err(Error::new(
+                object.required("param-id")?.mark,
+                "`param-id` can only be set on query and header arguments",
+            ));
+        }
+
+        Ok(ArgumentDefinition::builder()
+            .arg_name(ArgumentName(entry.key.to_string()))
+            .type_(self.resolve(object.required("type")?)?)
+            .param_type(param_type)
+            .safety(safety(&object)?)
+            .docs(docs(&object, "docs")?)
+            .markers(self.markers(&object)?)
+            .tags(tags(&object)?)
+            .build())
+    }
+
+    fn markers(&self, object: &Object<'_>) -> Result<Vec<Type>, Error> {
+        match object.get("markers") {
+            Some(markers) => markers
+                .as_sequence()?
+                .iter()
+                .map(|m| self.resolve(m))
+                .collect(),
+            None => Ok(vec![]),
+        }
+    }
+
+    fn resolve(&self, node: &Node) -> Result<Type, Error> {
+        let s = node.as_str()?;
+        let expr = TypeParser { s, pos: 0 }
+            .parse()
+            .map_err(|e| Error::new(node.mark, format!("invalid type `{s}`: {e}")))?;
+        self.resolve_expr(&expr, node.mark)
+    }
+
+    fn resolve_expr(&self, expr: &TypeExpr<'_>, mark: Mark) -> Result<Type, Error> {
+        let type_ = match expr {
+            TypeExpr::Named(name) => return self.resolve_name(name, mark),
+            TypeExpr::Optional(item) => {
+                if let TypeExpr::Optional(_) = **item {
+                    return Err(Error::new(mark, "optional types cannot be nested"));
+                }
+                Type::Optional(OptionalType::new(self.resolve_expr(item, mark)?))
+            }
+            TypeExpr::List(item) => Type::List(ListType::new(self.resolve_expr(item, mark)?)),
+            TypeExpr::Set(item) => Type::Set(SetType::new(self.resolve_expr(item, mark)?)),
+            TypeExpr::Map(key, value) => {
+                let key = self.resolve_expr(key, mark)?;
+                if !self.is_valid_map_key(&key) {
+                    return Err(Error::new(
+                        mark,
+                        "map keys must be primitives, enums, or aliases",
+                    ));
+                }
+                Type::Map(MapType::new(key, self.resolve_expr(value, mark)?))
+            }
+        };
+
+        Ok(type_)
+    }
+
+    fn is_valid_map_key(&self, key: &Type) -> bool {
+        match key {
+            Type::Primitive(_) | Type::External(_) => true,
+            Type::Optional(_) | Type::List(_) | Type::Set(_) | Type::Map(_) => false,
+            Type::Reference(name) => !self
+                .scopes
+                .iter()
+                .any(|scope| scope.complex_types.contains(name)),
+        }
+    }
+
+    fn resolve_name(&self, name: &str, mark: Mark) -> Result<Type, Error> {
+        if let Some(primitive) = primitive(name) {
+            return Ok(Type::Primitive(primitive));
+        }
+
+        let (scope, local_name) = match name.split_once('.') {
+            Some((namespace, local_name)) => match self.file.imports.get(namespace) {
+                Some(&i) => (&self.scopes[i], local_name),
+                None => {
+                    return Err(Error::new(
+                        mark,
+                        format!("unknown conjure import namespace `{namespace}`"),
+                    ))
+                }
+            },
+            None => (self.scope, name),
+        };
+
+        scope
+            .types
+            .get(local_name)
+            .cloned()
+            .ok_or_else(|| Error::new(mark, format!("unknown type `{name}`")))
+    }
+}
+
+fn primitive(name: &str) -> Option<PrimitiveType> {
+    let primitive = match name {
+        "string" => PrimitiveType::String,
+        "datetime" => PrimitiveType::Datetime,
+        "integer" => PrimitiveType::Integer,
+        "double" => PrimitiveType::Double,
+        "safelong" => PrimitiveType::Safelong,
+        "binary" => PrimitiveType::Binary,
+        "any" => PrimitiveType::Any,
+        "boolean" => PrimitiveType::Boolean,
+        "uuid" => PrimitiveType::Uuid,
+        "rid" => PrimitiveType::Rid,
+        "bearertoken" => PrimitiveType::Bearertoken,
+        _ => return None,
+    };
+
+    Some(primitive)
+}
+
+fn docs(object: &Object<'_>, key: &str) -> Result<Option<Documentation>, Error> {
+    Ok(object.str(key)?.map(|s| Documentation(s.to_string())))
+}
+
+fn safety(object: &Object<'_>) -> Result<Option<LogSafety>, Error> {
+    let node = match object.get("safety") {
+        Some(node) => node,
+        None => return Ok(None),
+    };
+
+    let safety = match node.as_str()? {
+        "safe" => LogSafety::Safe,
+        "unsafe" => LogSafety::Unsafe,
+        "do-not-log" => LogSafety::DoNotLog,
+        other => {
+            return Err(Error::new(
+                node.mark,
+                format!("unknown safety `{other}`, expected `safe`, `unsafe`, or `do-not-log`"),
+            ))
+        }
+    };
+
+    Ok(Some(safety))
+}
+
+fn tags(object: &Object<'_>) -> Result<Vec<String>, Error> {
+    match object.get("tags") {
+        Some(tags) => tags
+            .as_sequence()?
+            .iter()
+            .map(|t| t.as_str().map(str::to_string))
+            .collect(),
+        None => Ok(vec![]),
+    }
+}
+
+fn auth(node: &Node) -> Result<Option<AuthType>, Error> {
+    let auth = match node.as_str()? {
+        "none" => None,
+        "header" => Some(AuthType::Header(HeaderAuthType::new())),
+        auth => match auth.strip_prefix("cookie:") {
+            Some(cookie_name) => Some(AuthType::Cookie(CookieAuthType::new(cookie_name))),
+            None => {
+                return Err(Error::new(
+                    node.mark,
+                    format!("unknown auth `{auth}`, expected `none`, `header`, or `cookie:<name>`"),
+                ))
+            }
+        },
+    };
+
+    Ok(auth)
+}
+
+fn enum_values(node: &Node) -> Result<Vec<EnumValueDefinition>, Error> {
+    node.as_sequence()?
+        .iter()
+        .map(|value| {
+            if value.as_str().is_ok() {
+                return Ok(EnumValueDefinition::new(enum_value(value)?));
+            }
+
+            let object = value.object(&["value", "docs", "deprecated"])?;
+            Ok(EnumValueDefinition::builder()
+                .value(enum_value(object.required("value")?)?)
+                .docs(docs(&object, "docs")?)
+                .deprecated(docs(&object, "deprecated")?)
+                .build())
+        })
+        .collect()
+}
+
+fn enum_value(node: &Node) -> Result<&str, Error> {
+    let value = node.as_str()?;
+    let valid = value.split('_').all(|part| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    }) && value.starts_with(|c: char| c.is_ascii_uppercase());
+    if !valid {
+        return Err(Error::new(
+            node.mark,
+            format!("enum value `{value}` must be UPPER_CASE"),
+        ));
+    }
+
+    Ok(value)
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn path_params(path: &str) -> Vec<&str> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+        .map(|param| param.trim_end_matches('*'))
+        .collect()
+}
+
+fn join_path(base_path: &str, path: &str) -> String {
+    let path = format!("{}{}", base_path.trim_end_matches('/'), path);
+    if path.is_empty() {
+        "/".to_string()
+    } else {
+        path
+    }
+}
+
+enum TypeExpr<'a> {
+    Named(&'a str),
+    Optional(Box<TypeExpr<'a>>),
+    List(Box<TypeExpr<'a>>),
+    Set(Box<TypeExpr<'a>>),
+    Map(Box<TypeExpr<'a>>, Box<TypeExpr<'a>>),
+}
+
+struct TypeParser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> TypeParser<'a> {
+    fn parse(mut self) -> Result<TypeExpr<'a>, String> {
+        let expr = self.type_()?;
+        self.skip_whitespace();
+        match self.s[self.pos..].chars().next() {
+            Some(c) => Err(format!("unexpected `{c}`")),
+            None => Ok(expr),
+        }
+    }
+
+    fn type_(&mut self) -> Result<TypeExpr<'a>, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        while let Some(c) = self.s[self.pos..].chars().next() {
+            if !(c.is_ascii_alphanumeric() || c == '.' || c == '_') {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        if start == self.pos {
+            return Err("expected a type name".to_string());
+        }
+        let name = &self.s[start..self.pos];
+
+        self.skip_whitespace();
+        if !self.eat('<') {
+            return Ok(TypeExpr::Named(name));
+        }
+
+        let expr = match name {
+            "optional" => TypeExpr::Optional(Box::new(self.type_()?)),
+            "list" => TypeExpr::List(Box::new(self.type_()?)),
+            "set" => TypeExpr::Set(Box::new(self.type_()?)),
+            "map" => {
+                let key = self.type_()?;
+                self.expect(',')?;
+                TypeExpr::Map(Box::new(key), Box::new(self.type_()?))
+            }
+            _ => return Err(format!("`{name}` does not take type parameters")),
+        };
+        self.expect('>')?;
+
+        Ok(expr)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.s[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.s[self.pos..].starts_with(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(format!("expected `{c}`"))
+        }
+    }
+}
@@ -0,0 +1,510 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! A compiler for Conjure YAML definitions.
+//!
+//! This allows code to be generated directly from [Conjure YAML] files without first compiling them to the JSON IR
+//! with the Java Conjure compiler:
+//!
+//! ```no_run
+//! let defs = conjure_codegen::yaml::compile("src/main/conjure")?;
+//! conjure_codegen::Config::new()
+//!     .generate(&defs)?
+//!     .write_to("src/conjure")?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+//!
+//! Errors identify the file, line, and column of the offending definition.
+//!
+//! [Conjure YAML]: https://github.com/palantir/conjure/blob/master/docs/spec/conjure_definitions.md
+use crate::types::objects::ConjureDefinition;
+use anyhow::{anyhow, bail, Context as _, Error};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+mod compiler;
+mod node;
+
+/// Compiles Conjure YAML definitions into the IR.
+///
+/// The path may either be a single YAML file or a directory, in which case all `.yml` and `.yaml` files inside of it
+/// are compiled together. Files referenced via `conjure-imports` are resolved relative to the importing file, and their
+/// definitions are included in the output.
+pub fn compile<P>(path: P) -> Result<ConjureDefinition, Error>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+
+    let mut loader = Loader {
+        files: vec![],
+        names: vec![],
+        indices: HashMap::new(),
+    };
+    if path.is_dir() {
+        let mut paths = vec![];
+        find_yaml_files(path, &mut paths)?;
+        if paths.is_empty() {
+            bail!("no Conjure YAML files found in {}", path.display());
+        }
+        paths.sort();
+        for path in paths {
+            loader.load(&path)?;
+        }
+    } else {
+        loader.load(path)?;
+    }
+
+    compiler::compile(&loader.files).map_err(|(i, e)| located(Some(&loader.names[i]), e))
+}
+
+/// Compiles a single Conjure YAML document into the IR.
+///
+/// `conjure-imports` are not supported since there is no file to resolve them against.
+pub fn compile_str(source: &str) -> Result<ConjureDefinition, Error> {
+    let node = node::parse(source).map_err(|e| located(None, e))?;
+    if let Some((_, mark, _)) = compiler::conjure_imports(&node)
+        .map_err(|e| located(None, e))?
+        .first()
+    {
+        return Err(located(
+            None,
+            node::Error::new(*mark, "conjure-imports are not supported in a string"),
+        ));
+    }
+
+    let files = [compiler::File {
+        node,
+        imports: BTreeMap::new(),
+    }];
+    compiler::compile(&files).map_err(|(_, e)| located(None, e))
+}
+
+fn located(name: Option<&str>, error: node::Error) -> Error {
+    match name {
+        Some(name) => anyhow!(
+            "{}:{}:{}: {}",
+            name,
+            error.mark.line,
+            error.mark.column,
+            error.message
+        ),
+        None => anyhow!(
+            "line {}, column {}: {}",
+            error.mark.line,
+            error.mark.column,
+            error.message
+        ),
+    }
+}
+
+fn find_yaml_files(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<(), Error> {
+    let entries =
+        fs::read_dir(dir).with_context(|| format!("error reading directory {}", dir.display()))?;
+    for entry in entries {
+        let path = entry
+            .with_context(|| format!("error reading directory {}", dir.display()))?
+            .path();
+        if path.is_dir() {
+            find_yaml_files(&path, paths)?;
+        } else if matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("yml" | "yaml")
+        ) {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+struct Loader {
+    files: Vec<compiler::File>,
+    names: Vec<String>,
+    indices: HashMap<PathBuf, usize>,
+}
+
+impl Loader {
+    fn load(&mut self, path: &Path) -> Result<usize, Error> {
+        let canonical = fs::canonicalize(path)
+            .with_context(|| format!("error reading file {}", path.display()))?;
+        if let Some(&index) = self.indices.get(&canonical) {
+            return Ok(index);
+        }
+
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("error reading file {}", path.display()))?;
+        let name = path.display().to_string();
+        let node = node::parse(&source).map_err(|e| located(Some(&name), e))?;
+        let imports = compiler::conjure_imports(&node).map_err(|e| located(Some(&name), e))?;
+
+        let index = self.files.len();
+        self.indices.insert(canonical, index);
+        self.files.push(compiler::File {
+            node,
+            imports: BTreeMap::new(),
+        });
+        self.names.push(name);
+
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        for (namespace, mark, import) in imports {
+            let import = dir.join(import);
+            if !import.is_file() {
+                return Err(located(
+                    Some(&self.names[index]),
+                    node::Error::new(
+                        mark,
+                        format!("conjure import {} does not exist", import.display()),
+                    ),
+                ));
+            }
+            let import = self.load(&import)?;
+            self.files[index].imports.insert(namespace, import);
+        }
+
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    fn compile_err(source: &str) -> String {
+        compile_str(source).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_ir() {
+        let compiled = compile(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../conjure-test/test.yml"
+        ))
+        .unwrap();
+
+        let ir = fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../conjure-test/test-ir.json"
+        ))
+        .unwrap();
+        let expected = conjure_serde::json::client_from_str::<ConjureDefinition>(&ir).unwrap();
+
+        // the hand-maintained IR isn't consistently ordered
+        let sorted = |defs: &ConjureDefinition| {
+            let mut types = defs.types().to_vec();
+            types.sort();
+            let mut errors = defs.errors().to_vec();
+            errors.sort();
+            let mut services = defs.services().to_vec();
+            services.sort();
+            (types, errors, services)
+        };
+        assert_eq!(sorted(&compiled), sorted(&expected));
+    }
+
+    #[test]
+    fn conjure_imports() {
+        let dir = env::temp_dir().join(format!("conjure-yaml-imports-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("common")).unwrap();
+        fs::write(
+            dir.join("common/common.yml"),
+            "
+types:
+  definitions:
+    default-package: com.palantir.common
+    objects:
+      Id:
+        alias: string
+",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("api.yml"),
+            "
+types:
+  conjure-imports:
+    common: common/common.yml
+  definitions:
+    default-package: com.palantir.api
+    objects:
+      Thing:
+        fields:
+          id: common.Id
+",
+        )
+        .unwrap();
+
+        let defs = compile(dir.join("api.yml")).unwrap();
+        let json = conjure_serde::json::to_string(&defs).unwrap();
+        assert!(
+            json.contains(r#"{"name":"Id","package":"com.palantir.common"}"#),
+            "{}",
+            json
+        );
+        assert_eq!(defs.types().len(), 2);
+
+        // compiling the whole directory only includes each file once
+        assert_eq!(compile(&dir).unwrap(), defs);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn unknown_type() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        fields:
+          bar: optional<Bar>
+",
+        );
+        assert_eq!(err, "line 8, column 16: unknown type `Bar`");
+    }
+
+    #[test]
+    fn unknown_key() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        fields: {}
+        bogus: true
+",
+        );
+        assert!(
+            err.starts_with("line 8, column 9: unknown key `bogus`"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn invalid_type() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        alias: map<string>
+",
+        );
+        assert_eq!(
+            err,
+            "line 7, column 16: invalid type `map<string>`: expected `,`"
+        );
+    }
+
+    #[test]
+    fn import_safety() {
+        let defs = compile_str(
+            "
+types:
+  imports:
+    Secret:
+      base-type: string
+      external:
+        java: com.palantir.Secret
+      safety: do-not-log
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        alias: Secret
+",
+        )
+        .unwrap();
+        assert_eq!(defs.types().len(), 1);
+
+        let err = compile_err(
+            "
+types:
+  imports:
+    Secret:
+      external:
+        java: com.palantir.Secret
+      safety: secret
+",
+        );
+        assert_eq!(
+            err,
+            "line 7, column 15: unknown safety `secret`, expected `safe`, `unsafe`, or `do-not-log`"
+        );
+    }
+
+    #[test]
+    fn complex_map_key() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        fields:
+          bar: map<Bar, string>
+      Bar:
+        fields: {}
+",
+        );
+        assert_eq!(
+            err,
+            "line 8, column 16: map keys must be primitives, enums, or aliases"
+        );
+
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        alias: map<list<string>, string>
+",
+        );
+        assert_eq!(
+            err,
+            "line 7, column 16: map keys must be primitives, enums, or aliases"
+        );
+    }
+
+    #[test]
+    fn nested_optional() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        fields:
+          bar: list<optional<optional<string>>>
+",
+        );
+        assert_eq!(err, "line 8, column 16: optional types cannot be nested");
+    }
+
+    #[test]
+    fn field_name_case() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        fields:
+          fooBar: string
+          foo_bar: string
+",
+        );
+        assert_eq!(
+            err,
+            "line 9, column 11: field name `foo_bar` must be lowerCamelCase"
+        );
+    }
+
+    #[test]
+    fn enum_value_case() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    default-package: com.palantir.foo
+    objects:
+      Foo:
+        values:
+          - FOO_BAR
+          - value: FooBar
+",
+        );
+        assert_eq!(
+            err,
+            "line 9, column 20: enum value `FooBar` must be UPPER_CASE"
+        );
+    }
+
+    #[test]
+    fn missing_package() {
+        let err = compile_err(
+            "
+types:
+  definitions:
+    objects:
+      Foo:
+        alias: string
+",
+        );
+        assert_eq!(
+            err,
+            "line 5, column 7: `Foo` has no package and there is no `default-package`"
+        );
+    }
+
+    #[test]
+    fn missing_path_argument() {
+        let err = compile_err(
+            "
+services:
+  FooService:
+    package: com.palantir.foo
+    endpoints:
+      get:
+        http: GET /foo/{bar}
+",
+        );
+        assert_eq!(
+            err,
+            "line 7, column 15: path parameter `bar` has no corresponding argument"
+        );
+    }
+
+    #[test]
+    fn header_param_id() {
+        let err = compile_err(
+            "
+services:
+  FooService:
+    package: com.palantir.foo
+    endpoints:
+      get:
+        http: GET /foo
+        args:
+          bar:
+            type: string
+            param-type: header
+",
+        );
+        assert_eq!(
+            err,
+            "line 9, column 11: header argument `bar` must set `param-id`"
+        );
+    }
+
+    #[test]
+    fn syntax_error() {
+        let err = compile_err("types: foo: bar");
+        assert!(err.starts_with("line 1, column 11: "), "{}", err);
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! A minimal YAML document model which tracks the source location of every value.
+use std::collections::HashMap;
+use std::fmt;
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+
+/// A position in a YAML document.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Mark {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Mark {
+    fn new(marker: &Marker) -> Mark {
+        Mark {
+            line: marker.line(),
+            // yaml-rust2 columns are 0-based
+            column: marker.col() + 1,
+        }
+    }
+}
+
+/// An error associated with a position in a YAML document.
+#[derive(Debug)]
+pub struct Error {
+    pub mark: Mark,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new<T>(mark: Mark, message: T) -> Error
+    where
+        T: fmt::Display,
+    {
+        Error {
+            mark,
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Value {
+    Null,
+    Scalar(String),
+    Sequence(Vec<Node>),
+    Mapping(Vec<(Node, Node)>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub value: Value,
+    pub mark: Mark,
+}
+
+impl Node {
+    pub fn is_null(&self) -> bool {
+        matches!(self.value, Value::Null)
+    }
+
+    pub fn as_str(&self) -> Result<&str, Error> {
+        match &self.value {
+            Value::Scalar(s) => Ok(s),
+            _ => Err(Error::new(self.mark, "expected a string")),
+        }
+    }
+
+    pub fn as_sequence(&self) -> Result<&[Node], Error> {
+        match &self.value {
+            Value::Sequence(nodes) => Ok(nodes),
+            _ => Err(Error::new(self.mark, "expected a list")),
+        }
+    }
+
+    /// Returns the entries of a mapping with string keys.
+    pub fn entries(&self) -> Result<Vec<Entry<'_>>, Error> {
+        let entries = match &self.value {
+            Value::Mapping(entries) => entries,
+            _ => return Err(Error::new(self.mark, "expected a map")),
+        };
+
+        let mut out: Vec<Entry<'_>> = vec![];
+        for (key, value) in entries {
+            let name = key.as_str()?;
+            if out.iter().any(|e| e.key == name) {
+                return Err(Error::new(key.mark, format!("duplicate key `{name}`")));
+            }
+            out.push(Entry {
+                key: name,
+                mark: key.mark,
+                value,
+            });
+        }
+
+        Ok(out)
+    }
+
+    /// Returns a mapping with a fixed set of allowed keys.
+    pub fn object(&self, keys: &[&str]) -> Result<Object<'_>, Error> {
+        let entries = self.entries()?;
+        for entry in &entries {
+            if !keys.contains(&entry.key) {
+                return Err(Error::new(
+                    entry.mark,
+                    format!(
+                        "unknown key `{}`, expected one of {}",
+                        entry.key,
+                        keys.iter()
+                            .map(|k| format!("`{k}`"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ),
+                ));
+            }
+        }
+
+        Ok(Object {
+            mark: self.mark,
+            entries,
+        })
+    }
+}
+
+pub struct Entry<'a> {
+    pub key: &'a str,
+    pub mark: Mark,
+    pub value: &'a Node,
+}
+
+pub struct Object<'a> {
+    mark: Mark,
+    entries: Vec<Entry<'a>>,
+}
+
+impl<'a> Object<'a> {
+    pub fn get(&self, key: &str) -> Option<&'a Node> {
+        self.entries
+            .iter()
+            .find(|e| e.key == key)
+            .map(|e| e.value)
+            .filter(|v| !v.is_null())
+    }
+
+    pub fn required(&self, key: &str) -> Result<&'a Node, Error> {
+        self.get(key)
+            .ok_or_else(|| Error::new(self.mark, format!("missing required key `{key}`")))
+    }
+
+    pub fn str(&self, key: &str) -> Result<Option<&'a str>, Error> {
+        self.get(key).map(|v| v.as_str()).transpose()
+    }
+}
+
+/// Parses a single YAML document.
+pub fn parse(source: &str) -> Result<Node, Error> {
+    let mut builder = Builder {
+        stack: vec![],
+        anchors: HashMap::new(),
+        root: None,
+        error: None,
+    };
+    Parser::new_from_str(source)
+        .load(&mut builder, false)
+        .map_err(|e| Error::new(Mark::new(e.marker()), e.info()))?;
+
+    if let Some(error) = builder.error {
+        return Err(error);
+    }
+
+    Ok(builder.root.unwrap_or(Node {
+        value: Value::Null,
+        mark: Mark { line: 1, column: 1 },
+    }))
+}
+
+enum Partial {
+    Sequence {
+        nodes: Vec<Node>,
+        mark: Mark,
+        anchor: usize,
+    },
+    Mapping {
+        entries: Vec<(Node, Node)>,
+        key: Option<Node>,
+        mark: Mark,
+        anchor: usize,
+    },
+}
+
+struct Builder {
+    stack: Vec<Partial>,
+    anchors: HashMap<usize, Node>,
+    root: Option<Node>,
+    error: Option<Error>,
+}
+
+impl Builder {
+    fn push(&mut self, node: Node, anchor: usize) {
+        if anchor != 0 {
+            self.anchors.insert(anchor, node.clone());
+        }
+
+        match self.stack.last_mut() {
+            None => self.root = Some(node),
+            Some(Partial::Sequence { nodes, .. }) => nodes.push(node),
+            Some(Partial::Mapping { entries, key, .. }) => match key.take() {
+                Some(key) => entries.push((key, node)),
+                None => *key = Some(node),
+            },
+        }
+    }
+}
+
+impl MarkedEventReceiver for Builder {
+    fn on_event(&mut self, event: Event, marker: Marker) {
+        let mark = Mark::new(&marker);
+        match event {
+            Event::Scalar(value, style, anchor, _) => {
+                let value = match (style, &*value) {
+                    (TScalarStyle::Plain, "" | "~" | "null" | "Null" | "NULL") => Value::Null,
+                    _ => Value::Scalar(value),
+                };
+                self.push(Node { value, mark }, anchor);
+            }
+            Event::SequenceStart(anchor, _) => self.stack.push(Partial::Sequence {
+                nodes: vec![],
+                mark,
+                anchor,
+            }),
+            Event::MappingStart(anchor, _) => self.stack.push(Partial::Mapping {
+                entries: vec![],
+                key: None,
+                mark,
+                anchor,
+            }),
+            Event::SequenceEnd | Event::MappingEnd => {
+                let (value, mark, anchor) = match self.stack.pop() {
+                    Some(Partial::Sequence {
+                        nodes,
+                        mark,
+                        anchor,
+                    }) => (Value::Sequence(nodes), mark, anchor),
+                    Some(Partial::Mapping {
+                        entries,
+                        mark,
+                        anchor,
+                        ..
+                    }) => (Value::Mapping(entries), mark, anchor),
+                    None => return,
+                };
+                self.push(Node { value, mark }, anchor);
+            }
+            Event::Alias(anchor) => match self.anchors.get(&anchor) {
+                Some(node) => self.push(node.clone(), 0),
+                None => {
+                    if self.error.is_none() {
+                        self.error = Some(Error::new(mark, "unknown anchor"));
+                    }
+                    self.push(
+                        Node {
+                            value: Value::Null,
+                            mark,
+                        },
+                        0,
+                    );
+                }
+            },
+            Event::Nothing
+            | Event::StreamStart
+            | Event::StreamEnd
+            | Event::DocumentStart
+            | Event::DocumentEnd => {}
+        }
+    }
+}
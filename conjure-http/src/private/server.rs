@@ -4,7 +4,8 @@ use crate::server::{
     LocalAsyncResponseBody, LocalAsyncSerializeResponse, ResponseBody, SerializeResponse,
 };
 use crate::PathParams;
-use conjure_error::{Error, PermissionDenied};
+use conjure_error::{Error, InvalidArgument, PermissionDenied};
+use conjure_object::field_mask::FieldMask;
 use conjure_object::log_safety::AssertLogSafe;
 use conjure_object::BearerToken;
 use http::header::{HeaderName, AUTHORIZATION, COOKIE};
@@ -49,6 +50,18 @@ pub fn parse_query_params(parts: &request::Parts) -> HashMap<Cow<'_, str>, Vec<C
     map
 }
 
+pub fn field_mask(
+    query_params: &HashMap<Cow<'_, str>, Vec<Cow<'_, str>>>,
+) -> Result<Option<FieldMask>, Error> {
+    let Some(values) = query_params.get("fields") else {
+        return Ok(None);
+    };
+
+    values.join(",").parse().map(Some).map_err(|e| {
+        Error::service(e, InvalidArgument::new()).with_safe_param("param", AssertLogSafe("fields"))
+    })
+}
+
 pub fn query_param<T, D>(
     runtime: &ConjureRuntime,
     query_params: &HashMap<Cow<'_, str>, Vec<Cow<'_, str>>>,
@@ -18,6 +18,7 @@ use std::{error, iter::FromIterator, marker::PhantomData};
 
 use bytes::Bytes;
 use conjure_error::{Error, InvalidArgument};
+use conjure_object::field_mask::Project;
 use conjure_object::FromPlain;
 use futures_core::Stream;
 use http::{header::CONTENT_TYPE, HeaderMap, HeaderValue, Response};
@@ -28,7 +29,7 @@ use crate::private::APPLICATION_OCTET_STREAM;
 use super::{
     AsyncDeserializeRequest, AsyncResponseBody, AsyncSerializeResponse, AsyncWriteBody,
     ConjureRuntime, DecodeHeader, DecodeParam, DeserializeRequest, EmptyResponseSerializer,
-    LocalAsyncDeserializeRequest, LocalAsyncResponseBody, LocalAsyncSerializeResponse,
+    FieldMasked, LocalAsyncDeserializeRequest, LocalAsyncResponseBody, LocalAsyncSerializeResponse,
     LocalAsyncWriteBody, ResponseBody, SerializeResponse, StdRequestDeserializer,
     StdResponseSerializer, WriteBody,
 };
@@ -219,6 +220,81 @@ where
     }
 }
 
+impl<T, W> SerializeResponse<FieldMasked<T>, W> for CollectionResponseSerializer
+where
+    T: Project + PartialEq + Default,
+{
+    fn serialize(
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        value: FieldMasked<T>,
+    ) -> Result<Response<ResponseBody<W>>, Error> {
+        if *value.value() == T::default() {
+            <EmptyResponseSerializer as SerializeResponse<_, _>>::serialize(
+                runtime,
+                request_headers,
+                (),
+            )
+        } else {
+            <StdResponseSerializer as SerializeResponse<_, _>>::serialize(
+                runtime,
+                request_headers,
+                value,
+            )
+        }
+    }
+}
+
+impl<T, W> AsyncSerializeResponse<FieldMasked<T>, W> for CollectionResponseSerializer
+where
+    T: Project + PartialEq + Default,
+{
+    fn serialize(
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        value: FieldMasked<T>,
+    ) -> Result<Response<AsyncResponseBody<W>>, Error> {
+        if *value.value() == T::default() {
+            <EmptyResponseSerializer as AsyncSerializeResponse<_, _>>::serialize(
+                runtime,
+                request_headers,
+                (),
+            )
+        } else {
+            <StdResponseSerializer as AsyncSerializeResponse<_, _>>::serialize(
+                runtime,
+                request_headers,
+                value,
+            )
+        }
+    }
+}
+
+impl<T, W> LocalAsyncSerializeResponse<FieldMasked<T>, W> for CollectionResponseSerializer
+where
+    T: Project + PartialEq + Default,
+{
+    fn serialize(
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        value: FieldMasked<T>,
+    ) -> Result<Response<LocalAsyncResponseBody<W>>, Error> {
+        if *value.value() == T::default() {
+            <EmptyResponseSerializer as LocalAsyncSerializeResponse<_, _>>::serialize(
+                runtime,
+                request_headers,
+                (),
+            )
+        } else {
+            <StdResponseSerializer as LocalAsyncSerializeResponse<_, _>>::serialize(
+                runtime,
+                request_headers,
+                value,
+            )
+        }
+    }
+}
+
 /// A response serializer for binary types.
 pub enum BinaryResponseSerializer {}
 
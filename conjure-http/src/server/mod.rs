@@ -19,6 +19,7 @@ pub use crate::server::encoding::*;
 pub use crate::server::runtime::ConjureRuntime;
 use bytes::Bytes;
 use conjure_error::{Error, InvalidArgument};
+use conjure_object::field_mask::{FieldMask, Project};
 use conjure_object::log_safety::AssertLogSafe;
 use futures_core::Stream;
 use http::header::CONTENT_TYPE;
@@ -636,6 +637,20 @@ impl<'a> RequestContext<'a> {
     pub fn response_extensions_mut(&mut self) -> &mut Extensions {
         self.response_extensions
     }
+
+    /// Returns the unverified JWT claims of the request's `Authorization` header bearer token.
+    ///
+    /// Returns `None` if the request has no bearer token or the token is not a valid JWT. The token's signature is
+    /// *not* verified, so the claims must not be trusted unless the token has been validated separately.
+    ///
+    /// Requires the `jwt` Cargo feature.
+    #[cfg(feature = "jwt")]
+    pub fn unverified_claims(&self) -> Option<conjure_object::bearer_token::Claims> {
+        private::parse_header_auth(self.request_parts)
+            .ok()?
+            .unverified_claims()
+            .ok()
+    }
 }
 
 /// A marker value to opt into legacy error serialization.
@@ -928,6 +943,108 @@ where
     }
 }
 
+/// A response value along with the field mask requested by the client.
+///
+/// [`StdResponseSerializer`] serializes only the fields of the value selected by the mask, or the entire value if
+/// there is no mask. Endpoints with the `field_mask` parameter return responses of this type, with the mask taken
+/// from the `fields` query parameter.
+pub struct FieldMasked<T> {
+    value: T,
+    mask: Option<FieldMask>,
+}
+
+impl<T> FieldMasked<T> {
+    /// Creates a new `FieldMasked`.
+    #[inline]
+    pub fn new(value: T, mask: Option<FieldMask>) -> Self {
+        FieldMasked { value, mask }
+    }
+
+    /// Returns the value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the field mask.
+    #[inline]
+    pub fn mask(&self) -> Option<&FieldMask> {
+        self.mask.as_ref()
+    }
+
+    /// Returns the value, discarding the field mask.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> FieldMasked<T>
+where
+    T: Project,
+{
+    fn serialize_with<B>(
+        &self,
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        make_body: impl FnOnce(Bytes) -> B,
+    ) -> Result<Response<B>, Error> {
+        match &self.mask {
+            Some(mask) => StdResponseSerializer::serialize_inner(
+                runtime,
+                request_headers,
+                &self.value.project(mask),
+                make_body,
+            ),
+            None => StdResponseSerializer::serialize_inner(
+                runtime,
+                request_headers,
+                &self.value,
+                make_body,
+            ),
+        }
+    }
+}
+
+impl<T, W> SerializeResponse<FieldMasked<T>, W> for StdResponseSerializer
+where
+    T: Project,
+{
+    fn serialize(
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        value: FieldMasked<T>,
+    ) -> Result<Response<ResponseBody<W>>, Error> {
+        value.serialize_with(runtime, request_headers, ResponseBody::Fixed)
+    }
+}
+
+impl<T, W> AsyncSerializeResponse<FieldMasked<T>, W> for StdResponseSerializer
+where
+    T: Project,
+{
+    fn serialize(
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        value: FieldMasked<T>,
+    ) -> Result<Response<AsyncResponseBody<W>>, Error> {
+        value.serialize_with(runtime, request_headers, AsyncResponseBody::Fixed)
+    }
+}
+
+impl<T, W> LocalAsyncSerializeResponse<FieldMasked<T>, W> for StdResponseSerializer
+where
+    T: Project,
+{
+    fn serialize(
+        runtime: &ConjureRuntime,
+        request_headers: &HeaderMap,
+        value: FieldMasked<T>,
+    ) -> Result<Response<LocalAsyncResponseBody<W>>, Error> {
+        value.serialize_with(runtime, request_headers, LocalAsyncResponseBody::Fixed)
+    }
+}
+
 /// A trait implemented by header decoders used by custom Conjure server trait implementations.
 pub trait DecodeHeader<T> {
     /// Decodes the value from headers.
@@ -302,6 +302,7 @@ fn generate_endpoint_handler(service: &Service, endpoint: &Endpoint) -> TokenStr
     let parts = quote!(__parts);
     let body = quote!(__body);
     let query_params = quote!(__query_params);
+    let field_mask = quote!(__field_mask);
     let safe_params = quote!(__safe_params);
     let response = quote!(__response);
     let method = &endpoint.ident;
@@ -339,7 +340,7 @@ fn generate_endpoint_handler(service: &Service, endpoint: &Endpoint) -> TokenStr
         quote!()
     };
 
-    let generate_query_params = if has_query_params(endpoint) {
+    let generate_query_params = if has_query_params(endpoint) || endpoint.params.field_mask {
         quote! {
             let #query_params = conjure_http::private::parse_query_params(&#parts);
         }
@@ -347,6 +348,14 @@ fn generate_endpoint_handler(service: &Service, endpoint: &Endpoint) -> TokenStr
         quote!()
     };
 
+    let generate_field_mask = if endpoint.params.field_mask {
+        quote! {
+            let #field_mask = conjure_http::private::field_mask(&#query_params)?;
+        }
+    } else {
+        quote!()
+    };
+
     let generate_safe_params = if has_safe_params(endpoint) {
         quote! {
             #response_extensions.insert(conjure_http::SafeParams::new());
@@ -375,6 +384,14 @@ fn generate_endpoint_handler(service: &Service, endpoint: &Endpoint) -> TokenStr
         Asyncness::Async | Asyncness::LocalAsync => quote!(.await),
     };
 
+    let mask_response = if endpoint.params.field_mask {
+        quote! {
+            let #response = conjure_http::server::FieldMasked::new(#response, #field_mask);
+        }
+    } else {
+        quote!()
+    };
+
     let generate_response = generate_response(&parts, &response, service, endpoint);
 
     quote! {
@@ -393,9 +410,11 @@ fn generate_endpoint_handler(service: &Service, endpoint: &Endpoint) -> TokenStr
                 #use_legacy_error_serialization
                 let (#parts, #body) = #request.into_parts();
                 #generate_query_params
+                #generate_field_mask
                 #generate_safe_params
                 #(#generate_args)*
                 let #response = self.handler.#method(#(#args),*) #await_ ?;
+                #mask_response
                 #generate_response
             }
         }
@@ -777,6 +796,15 @@ impl Endpoint {
             }
         }
 
+        if let Some(params) = &params {
+            if params.field_mask && params.produces.is_none() {
+                errors.push(Error::new_spanned(
+                    item,
+                    "field_mask requires a produces parameter",
+                ));
+            }
+        }
+
         let path = match params.as_ref().map(|p| path::parse(&p.path)).transpose() {
             Ok(path) => path,
             Err(e) => {
@@ -811,6 +839,7 @@ struct EndpointParams {
     path: LitStr,
     name: Option<LitStr>,
     produces: Option<Type>,
+    field_mask: bool,
 }
 
 enum ArgType {
@@ -252,6 +252,9 @@ pub fn conjure_client(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///   name.
 /// * `produces` - A type implementing `SerializeResponse` which will be used to convert the value
 ///   returned by the method into a response. Defaults to `EmptyResponseSerializer`.
+/// * `field_mask` - If set, the `fields` query parameter is parsed as a `FieldMask` and the value
+///   returned by the method is wrapped in a `FieldMasked` before being passed to the `produces`
+///   serializer. Requires `produces`.
 ///
 /// Each method argument must have an annotation describing the type of parameter. One of:
 ///
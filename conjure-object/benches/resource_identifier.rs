@@ -0,0 +1,55 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use conjure_object::ResourceIdentifier;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const RID: &str = "ri.compass.main.folder.7f3b6c1e-3c2a-4d4e-9a8b-0123456789ab";
+const INVALID_RID: &str = "ri.compass.main.Folder.7f3b6c1e-3c2a-4d4e-9a8b-0123456789ab";
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    group.bench_function("valid", |b| {
+        b.iter(|| black_box(RID).parse::<ResourceIdentifier>().unwrap())
+    });
+    group.bench_function("invalid", |b| {
+        b.iter(|| {
+            black_box(INVALID_RID)
+                .parse::<ResourceIdentifier>()
+                .unwrap_err()
+        })
+    });
+    group.finish();
+}
+
+fn display(c: &mut Criterion) {
+    let rid = RID.parse::<ResourceIdentifier>().unwrap();
+    c.bench_function("display", |b| b.iter(|| black_box(&rid).to_string()));
+}
+
+fn serde(c: &mut Criterion) {
+    let rid = RID.parse::<ResourceIdentifier>().unwrap();
+    let json = serde_json::to_string(&rid).unwrap();
+
+    let mut group = c.benchmark_group("serde");
+    group.bench_function("serialize", |b| {
+        b.iter(|| serde_json::to_string(black_box(&rid)).unwrap())
+    });
+    group.bench_function("deserialize", |b| {
+        b.iter(|| serde_json::from_str::<ResourceIdentifier>(black_box(&json)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse, display, serde);
+criterion_main!(benches);
@@ -0,0 +1,100 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Conversions between `Any` and `serde_cbor_2::Value`.
+use crate::any::{Any, Error, Inner};
+use ordered_float::OrderedFloat;
+use serde_cbor_2::Value;
+use std::convert::TryFrom;
+
+/// Converts a CBOR value into an `Any`.
+///
+/// Integers are stored as `u64`s if non-negative, `i64`s if negative, and `i128`s otherwise. Tags are discarded,
+/// leaving their inner values. Non-finite floats and non-string map keys are preserved as-is.
+///
+/// # Errors
+///
+/// Returns an error for CBOR value variants unknown to this crate.
+impl TryFrom<Value> for Any {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Any, Error> {
+        match value {
+            Value::Null => Ok(Any(Inner::Null)),
+            Value::Bool(v) => Ok(Any(Inner::Bool(v))),
+            Value::Integer(v) => Ok(u64::try_from(v)
+                .map(|v| Any(Inner::U64(v)))
+                .or_else(|_| i64::try_from(v).map(|v| Any(Inner::I64(v))))
+                .unwrap_or(Any(Inner::I128(v)))),
+            Value::Float(v) => Ok(Any(Inner::F64(OrderedFloat(v)))),
+            Value::Bytes(v) => Ok(Any(Inner::Bytes(v))),
+            Value::Text(v) => Ok(Any(Inner::String(v))),
+            Value::Array(v) => v
+                .into_iter()
+                .map(Any::try_from)
+                .collect::<Result<_, _>>()
+                .map(|v| Any(Inner::Seq(v))),
+            Value::Map(v) => v
+                .into_iter()
+                .map(|(k, v)| Ok((Any::try_from(k)?, Any::try_from(v)?)))
+                .collect::<Result<_, _>>()
+                .map(|v| Any(Inner::Map(v))),
+            Value::Tag(_, v) => Any::try_from(*v),
+            _ => Err(Error("unsupported CBOR value".to_string())),
+        }
+    }
+}
+
+/// Converts an `Any` into a CBOR value.
+///
+/// All integers are stored in CBOR's single integer representation, and non-finite floats remain floats.
+///
+/// # Errors
+///
+/// Returns an error if the value contains a `u128` larger than `i128::MAX`.
+impl TryFrom<Any> for Value {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Value, Error> {
+        match value.0 {
+            Inner::Null => Ok(Value::Null),
+            Inner::Bool(v) => Ok(Value::Bool(v)),
+            Inner::I8(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::I16(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::I32(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::I64(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::I128(v) => Ok(Value::Integer(v)),
+            Inner::U8(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::U16(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::U32(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::U64(v) => Ok(Value::Integer(i128::from(v))),
+            Inner::U128(v) => i128::try_from(v)
+                .map(Value::Integer)
+                .map_err(|_| Error(format!("integer {} is out of range for CBOR", v))),
+            Inner::F32(v) => Ok(Value::Float(f64::from(v.0))),
+            Inner::F64(v) => Ok(Value::Float(v.0)),
+            Inner::String(v) => Ok(Value::Text(v)),
+            Inner::Bytes(v) => Ok(Value::Bytes(v)),
+            Inner::Seq(v) => v
+                .into_iter()
+                .map(Value::try_from)
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            Inner::Map(v) => v
+                .into_iter()
+                .map(|(k, v)| Ok((Value::try_from(k)?, Value::try_from(v)?)))
+                .collect::<Result<_, _>>()
+                .map(Value::Map),
+        }
+    }
+}
@@ -139,7 +139,7 @@ impl<'de> Visitor<'de> for AnyVisitor {
     where
         E: de::Error,
     {
-        Ok(Any(Inner::Char(v)))
+        Ok(Any(Inner::String(v.to_string())))
     }
 
     fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
@@ -240,7 +240,6 @@ impl<'de> Deserializer<'de> for Any {
             Inner::U128(v) => visitor.visit_u128(v),
             Inner::F32(v) => visitor.visit_f32(v.0),
             Inner::F64(v) => visitor.visit_f64(v.0),
-            Inner::Char(v) => visitor.visit_char(v),
             Inner::String(v) => visitor.visit_string(v),
             Inner::Bytes(v) => visitor.visit_byte_buf(v),
             Inner::Seq(v) => visitor.visit_seq(SeqDeserializer(v.into_iter())),
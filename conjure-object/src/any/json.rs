@@ -0,0 +1,106 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Conversions between `Any` and `serde_json::Value`.
+use crate::any::{non_finite_str, Any, Error, Inner};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ordered_float::OrderedFloat;
+use serde_json::{Map, Number, Value};
+use std::convert::TryFrom;
+
+/// Converts a JSON value into an `Any`.
+///
+/// The result is identical to deserializing the value's JSON representation into an `Any`: non-negative integers are
+/// stored as `u64`s, negative integers as `i64`s, and all other numbers as `f64`s. Strings are never reinterpreted, so
+/// `"NaN"` remains a string rather than becoming a float.
+impl From<Value> for Any {
+    fn from(value: Value) -> Any {
+        match value {
+            Value::Null => Any(Inner::Null),
+            Value::Bool(v) => Any(Inner::Bool(v)),
+            Value::Number(v) => {
+                if let Some(v) = v.as_u64() {
+                    Any(Inner::U64(v))
+                } else if let Some(v) = v.as_i64() {
+                    Any(Inner::I64(v))
+                } else {
+                    Any(Inner::F64(OrderedFloat(v.as_f64().unwrap_or(f64::NAN))))
+                }
+            }
+            Value::String(v) => Any(Inner::String(v)),
+            Value::Array(v) => Any(Inner::Seq(v.into_iter().map(Any::from).collect())),
+            Value::Object(v) => Any(Inner::Map(
+                v.into_iter()
+                    .map(|(k, v)| (Any(Inner::String(k)), Any::from(v)))
+                    .collect(),
+            )),
+        }
+    }
+}
+
+/// Converts an `Any` into a JSON value.
+///
+/// Values are encoded as they would be by Conjure's JSON serializer: non-finite floats become the strings `"NaN"`,
+/// `"Infinity"`, and `"-Infinity"`, binary values become base64 strings, and map keys are converted to strings.
+///
+/// # Errors
+///
+/// Returns an error if the value contains an `i128` or `u128` outside the range of `i64` and `u64`, or a map key
+/// which is null, a sequence, or a map.
+impl TryFrom<Any> for Value {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Value, Error> {
+        match value.0 {
+            Inner::Null => Ok(Value::Null),
+            Inner::Bool(v) => Ok(Value::Bool(v)),
+            Inner::I8(v) => Ok(Value::from(v)),
+            Inner::I16(v) => Ok(Value::from(v)),
+            Inner::I32(v) => Ok(Value::from(v)),
+            Inner::I64(v) => Ok(Value::from(v)),
+            Inner::I128(v) => i64::try_from(v)
+                .map(Value::from)
+                .or_else(|_| u64::try_from(v).map(Value::from))
+                .map_err(|_| Error(format!("integer {} is out of range for JSON", v))),
+            Inner::U8(v) => Ok(Value::from(v)),
+            Inner::U16(v) => Ok(Value::from(v)),
+            Inner::U32(v) => Ok(Value::from(v)),
+            Inner::U64(v) => Ok(Value::from(v)),
+            Inner::U128(v) => u64::try_from(v)
+                .map(Value::from)
+                .map_err(|_| Error(format!("integer {} is out of range for JSON", v))),
+            Inner::F32(v) => Ok(float(f64::from(v.0))),
+            Inner::F64(v) => Ok(float(v.0)),
+            Inner::String(v) => Ok(Value::String(v)),
+            Inner::Bytes(v) => Ok(Value::String(STANDARD.encode(v))),
+            Inner::Seq(v) => v
+                .into_iter()
+                .map(Value::try_from)
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            Inner::Map(v) => v
+                .into_iter()
+                .map(|(k, v)| Ok((k.into_string_key()?, Value::try_from(v)?)))
+                .collect::<Result<Map<_, _>, _>>()
+                .map(Value::Object),
+        }
+    }
+}
+
+fn float(v: f64) -> Value {
+    match Number::from_f64(v) {
+        Some(v) => Value::Number(v),
+        None => Value::String(non_finite_str(v).unwrap().to_string()),
+    }
+}
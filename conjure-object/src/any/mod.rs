@@ -12,18 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 //! The Conjure `any` type.
+//!
+//! Conversions between `Any` and the dynamic value types of other serialization crates are available behind cargo
+//! features: `serde_json` for `serde_json::Value`, `serde_cbor_2` for `serde_cbor_2::Value`, and `serde-smile` for
+//! `serde_smile::value::Value`.
 
 use crate::any::ser::AnySerializer;
+use crate::diff::{Diff, Differ};
 use ordered_float::OrderedFloat;
 use serde::de::{DeserializeOwned, Unexpected};
 use serde::Serialize;
 use std::collections::BTreeMap;
+use std::convert::TryFrom;
 use std::error;
 use std::f64;
 use std::fmt;
+use std::iter::FromIterator;
+use std::mem;
 
+#[cfg(feature = "serde_cbor_2")]
+mod cbor;
 mod de;
+#[cfg(feature = "serde_json")]
+mod json;
 mod ser;
+#[cfg(feature = "serde-smile")]
+mod smile;
 
 /// An error serializing to or from an `Any` value.
 #[derive(Debug)]
@@ -71,7 +85,6 @@ enum Inner {
     U128(u128),
     F32(OrderedFloat<f32>),
     F64(OrderedFloat<f64>),
-    Char(char),
     String(String),
     Bytes(Vec<u8>),
     Seq(Vec<Any>),
@@ -84,6 +97,23 @@ enum Inner {
 /// regarding various edge cases such as base64 encoded binary values and non-finite floats. Its internal structure is
 /// opaque. Values can be converted to and from it with the `Any::new` and `Any::deserialize_into` methods, and it can
 /// be deserialized to and from JSON via its `Serialize` and `Deserialize` implementations.
+///
+/// The shape of a value can be inspected with methods like `Any::kind`, `Any::as_str`, and `Any::pointer`, and values
+/// can be built up directly from their components with the `From` and `FromIterator` implementations:
+///
+/// ```
+/// use conjure_object::Any;
+/// use conjure_object::any::Kind;
+///
+/// let mut value = vec![("name", Any::from("foo")), ("tags", vec![Any::from("a")].into())]
+///     .into_iter()
+///     .collect::<Any>();
+/// value.get_mut("tags").unwrap().as_seq_mut().unwrap().push(Any::from("b"));
+///
+/// assert_eq!(value.kind(), Kind::Map);
+/// assert_eq!(value.get("name").and_then(Any::as_str), Some("foo"));
+/// assert_eq!(value.pointer("/tags/1").and_then(Any::as_str), Some("b"));
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Any(Inner);
 
@@ -115,6 +145,244 @@ impl Any {
         T::deserialize(self)
     }
 
+    /// An `Any` representing a null value.
+    pub const NULL: Any = Any(Inner::Null);
+
+    /// Returns the kind of the value.
+    pub fn kind(&self) -> Kind {
+        match &self.0 {
+            Inner::Null => Kind::Null,
+            Inner::Bool(_) => Kind::Bool,
+            Inner::I8(_)
+            | Inner::I16(_)
+            | Inner::I32(_)
+            | Inner::I64(_)
+            | Inner::I128(_)
+            | Inner::U8(_)
+            | Inner::U16(_)
+            | Inner::U32(_)
+            | Inner::U64(_)
+            | Inner::U128(_) => Kind::Integer,
+            Inner::F32(_) | Inner::F64(_) => Kind::Float,
+            Inner::String(_) => Kind::String,
+            Inner::Bytes(_) => Kind::Bytes,
+            Inner::Seq(_) => Kind::Seq,
+            Inner::Map(_) => Kind::Map,
+        }
+    }
+
+    /// Returns `true` if the value is null.
+    #[inline]
+    pub fn is_null(&self) -> bool {
+        matches!(self.0, Inner::Null)
+    }
+
+    /// Returns the value as a boolean, if it is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.0 {
+            Inner::Bool(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `i64`, if it is an integer in the range of the type.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self.0 {
+            Inner::I8(v) => Some(i64::from(v)),
+            Inner::I16(v) => Some(i64::from(v)),
+            Inner::I32(v) => Some(i64::from(v)),
+            Inner::I64(v) => Some(v),
+            Inner::I128(v) => i64::try_from(v).ok(),
+            Inner::U8(v) => Some(i64::from(v)),
+            Inner::U16(v) => Some(i64::from(v)),
+            Inner::U32(v) => Some(i64::from(v)),
+            Inner::U64(v) => i64::try_from(v).ok(),
+            Inner::U128(v) => i64::try_from(v).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `u64`, if it is an integer in the range of the type.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self.0 {
+            Inner::I8(v) => u64::try_from(v).ok(),
+            Inner::I16(v) => u64::try_from(v).ok(),
+            Inner::I32(v) => u64::try_from(v).ok(),
+            Inner::I64(v) => u64::try_from(v).ok(),
+            Inner::I128(v) => u64::try_from(v).ok(),
+            Inner::U8(v) => Some(u64::from(v)),
+            Inner::U16(v) => Some(u64::from(v)),
+            Inner::U32(v) => Some(u64::from(v)),
+            Inner::U64(v) => Some(v),
+            Inner::U128(v) => u64::try_from(v).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an `f64`, if it is a number.
+    ///
+    /// Like the value's `Deserializer` implementation, this also accepts the strings `"NaN"`, `"Infinity"`, and
+    /// `"-Infinity"` used to encode non-finite floats in Conjure JSON. Integers are converted with `as`, and may lose
+    /// precision.
+    pub fn as_f64(&self) -> Option<f64> {
+        match &self.0 {
+            Inner::I8(v) => Some(f64::from(*v)),
+            Inner::I16(v) => Some(f64::from(*v)),
+            Inner::I32(v) => Some(f64::from(*v)),
+            Inner::I64(v) => Some(*v as f64),
+            Inner::I128(v) => Some(*v as f64),
+            Inner::U8(v) => Some(f64::from(*v)),
+            Inner::U16(v) => Some(f64::from(*v)),
+            Inner::U32(v) => Some(f64::from(*v)),
+            Inner::U64(v) => Some(*v as f64),
+            Inner::U128(v) => Some(*v as f64),
+            Inner::F32(v) => Some(f64::from(v.0)),
+            Inner::F64(v) => Some(v.0),
+            Inner::String(v) if v == "NaN" => Some(f64::NAN),
+            Inner::String(v) if v == "Infinity" => Some(f64::INFINITY),
+            Inner::String(v) if v == "-Infinity" => Some(f64::NEG_INFINITY),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a string, if it is one.
+    pub fn as_str(&self) -> Option<&str> {
+        match &self.0 {
+            Inner::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a byte slice, if it is one.
+    ///
+    /// Binary values decoded from JSON are represented as base64 strings rather than bytes. Use
+    /// `Any::deserialize_into` to decode binary values regardless of their representation.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match &self.0 {
+            Inner::Bytes(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a slice of elements, if it is a sequence.
+    pub fn as_seq(&self) -> Option<&[Any]> {
+        match &self.0 {
+            Inner::Seq(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the elements of the value, if it is a sequence.
+    pub fn as_seq_mut(&mut self) -> Option<&mut Vec<Any>> {
+        match &mut self.0 {
+            Inner::Seq(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns the entries of the value, if it is a map.
+    pub fn as_map(&self) -> Option<&BTreeMap<Any, Any>> {
+        match &self.0 {
+            Inner::Map(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the entries of the value, if it is a map.
+    pub fn as_map_mut(&mut self) -> Option<&mut BTreeMap<Any, Any>> {
+        match &mut self.0 {
+            Inner::Map(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Looks up the value of a string key, if the value is a map.
+    pub fn get(&self, key: &str) -> Option<&Any> {
+        self.as_map()?.get(&Any::from(key))
+    }
+
+    /// Looks up the value of a string key mutably, if the value is a map.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Any> {
+        self.as_map_mut()?.get_mut(&Any::from(key))
+    }
+
+    /// Looks up an element by index, if the value is a sequence.
+    pub fn get_index(&self, index: usize) -> Option<&Any> {
+        self.as_seq()?.get(index)
+    }
+
+    /// Looks up an element by index mutably, if the value is a sequence.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<&mut Any> {
+        self.as_seq_mut()?.get_mut(index)
+    }
+
+    /// Looks up a nested value by a JSON pointer as defined in [RFC 6901].
+    ///
+    /// Each reference token is used as a string key for maps and as an index for sequences. The empty pointer refers
+    /// to the value itself.
+    ///
+    /// [RFC 6901]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub fn pointer(&self, pointer: &str) -> Option<&Any> {
+        pointer_tokens(pointer)?.try_fold(self, |value, token| match &value.0 {
+            Inner::Seq(v) => v.get(parse_index(&token)?),
+            Inner::Map(v) => v.get(&Any::from(token)),
+            _ => None,
+        })
+    }
+
+    /// Looks up a nested value mutably by a JSON pointer as defined in [RFC 6901].
+    ///
+    /// [RFC 6901]: https://datatracker.ietf.org/doc/html/rfc6901
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Any> {
+        pointer_tokens(pointer)?.try_fold(self, |value, token| match &mut value.0 {
+            Inner::Seq(v) => v.get_mut(parse_index(&token)?),
+            Inner::Map(v) => v.get_mut(&Any::from(token)),
+            _ => None,
+        })
+    }
+
+    /// Takes the value, leaving null in its place.
+    #[inline]
+    pub fn take(&mut self) -> Any {
+        mem::replace(self, Any::NULL)
+    }
+
+    /// Converts the value into a map key for formats which only support string keys.
+    ///
+    /// Strings are used as-is, and booleans and numbers are converted to their JSON representations, with non-finite
+    /// floats encoded as `"NaN"`, `"Infinity"`, and `"-Infinity"`. Binary values are base64 encoded.
+    #[cfg(any(feature = "serde_json", feature = "serde-smile"))]
+    fn into_string_key(self) -> Result<String, Error> {
+        use base64::Engine;
+
+        match self.0 {
+            Inner::Bool(v) => Ok(v.to_string()),
+            Inner::I8(v) => Ok(v.to_string()),
+            Inner::I16(v) => Ok(v.to_string()),
+            Inner::I32(v) => Ok(v.to_string()),
+            Inner::I64(v) => Ok(v.to_string()),
+            Inner::I128(v) => Ok(v.to_string()),
+            Inner::U8(v) => Ok(v.to_string()),
+            Inner::U16(v) => Ok(v.to_string()),
+            Inner::U32(v) => Ok(v.to_string()),
+            Inner::U64(v) => Ok(v.to_string()),
+            Inner::U128(v) => Ok(v.to_string()),
+            Inner::F32(v) => {
+                Ok(non_finite_str(f64::from(v.0))
+                    .map_or_else(|| format!("{:?}", v.0), str::to_string))
+            }
+            Inner::F64(v) => {
+                Ok(non_finite_str(v.0).map_or_else(|| format!("{:?}", v.0), str::to_string))
+            }
+            Inner::String(v) => Ok(v),
+            Inner::Bytes(v) => Ok(base64::engine::general_purpose::STANDARD.encode(v)),
+            Inner::Null | Inner::Seq(_) | Inner::Map(_) => Err(Error(format!(
+                "{:?} values cannot be used as map keys",
+                self.kind()
+            ))),
+        }
+    }
+
     fn unexpected(&self) -> Unexpected<'_> {
         match &self.0 {
             Inner::Null => Unexpected::Unit,
@@ -131,7 +399,6 @@ impl Any {
             Inner::U128(v) => Unexpected::Unsigned(*v as u64),
             Inner::F32(v) => Unexpected::Float(v.0 as f64),
             Inner::F64(v) => Unexpected::Float(v.0),
-            Inner::Char(v) => Unexpected::Char(*v),
             Inner::String(v) => Unexpected::Str(v),
             Inner::Bytes(v) => Unexpected::Bytes(v),
             Inner::Seq(_) => Unexpected::Seq,
@@ -139,3 +406,273 @@ impl Any {
         }
     }
 }
+
+/// The kind of an [`Any`] value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Kind {
+    /// A null value.
+    Null,
+    /// A boolean.
+    Bool,
+    /// An integer.
+    Integer,
+    /// A floating point number.
+    Float,
+    /// A string.
+    String,
+    /// A binary value.
+    Bytes,
+    /// A sequence of values.
+    Seq,
+    /// A map of values.
+    Map,
+}
+
+/// Returns the Conjure JSON encoding of a non-finite float.
+#[cfg(any(feature = "serde_json", feature = "serde-smile"))]
+fn non_finite_str(v: f64) -> Option<&'static str> {
+    if v.is_nan() {
+        Some("NaN")
+    } else if v == f64::INFINITY {
+        Some("Infinity")
+    } else if v == f64::NEG_INFINITY {
+        Some("-Infinity")
+    } else {
+        None
+    }
+}
+
+fn pointer_tokens(pointer: &str) -> Option<impl Iterator<Item = String> + '_> {
+    let tokens = if pointer.is_empty() {
+        None
+    } else {
+        Some(pointer.strip_prefix('/')?.split('/'))
+    };
+
+    Some(
+        tokens
+            .into_iter()
+            .flatten()
+            .map(|t| t.replace("~1", "/").replace("~0", "~")),
+    )
+}
+
+fn parse_index(token: &str) -> Option<usize> {
+    if token.starts_with('+') || (token.starts_with('0') && token.len() > 1) {
+        return None;
+    }
+
+    token.parse().ok()
+}
+
+macro_rules! impl_from {
+    ($($t:ty => $variant:ident,)*) => {
+        $(
+            impl From<$t> for Any {
+                #[inline]
+                fn from(v: $t) -> Any {
+                    Any(Inner::$variant(v))
+                }
+            }
+        )*
+    };
+}
+
+impl_from! {
+    bool => Bool,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    String => String,
+    Vec<Any> => Seq,
+    BTreeMap<Any, Any> => Map,
+}
+
+impl From<f32> for Any {
+    #[inline]
+    fn from(v: f32) -> Any {
+        Any(Inner::F32(OrderedFloat(v)))
+    }
+}
+
+impl From<f64> for Any {
+    #[inline]
+    fn from(v: f64) -> Any {
+        Any(Inner::F64(OrderedFloat(v)))
+    }
+}
+
+impl From<&str> for Any {
+    #[inline]
+    fn from(v: &str) -> Any {
+        Any(Inner::String(v.to_string()))
+    }
+}
+
+impl From<char> for Any {
+    #[inline]
+    fn from(v: char) -> Any {
+        Any(Inner::String(v.to_string()))
+    }
+}
+
+/// `None` is converted to null.
+impl<T> From<Option<T>> for Any
+where
+    T: Into<Any>,
+{
+    #[inline]
+    fn from(v: Option<T>) -> Any {
+        v.map_or(Any::NULL, Into::into)
+    }
+}
+
+/// Collects values into a sequence.
+impl FromIterator<Any> for Any {
+    fn from_iter<I>(iter: I) -> Any
+    where
+        I: IntoIterator<Item = Any>,
+    {
+        Any(Inner::Seq(iter.into_iter().collect()))
+    }
+}
+
+/// Collects entries into a map.
+impl<K, V> FromIterator<(K, V)> for Any
+where
+    K: Into<Any>,
+    V: Into<Any>,
+{
+    fn from_iter<I>(iter: I) -> Any
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        Any(Inner::Map(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        ))
+    }
+}
+
+impl Diff for Any {
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        match (&self.0, &other.0) {
+            (Inner::Seq(a), Inner::Seq(b)) => a.diff(b, differ),
+            (Inner::Map(a), Inner::Map(b)) => a.diff(b, differ),
+            (a, b) => {
+                if a != b {
+                    differ.changed(self, other);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn json(s: &str) -> Any {
+        serde_json::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!(json("null").kind(), Kind::Null);
+        assert_eq!(json("true").kind(), Kind::Bool);
+        assert_eq!(json("-1").kind(), Kind::Integer);
+        assert_eq!(json("1.5").kind(), Kind::Float);
+        assert_eq!(json(r#""foo""#).kind(), Kind::String);
+        assert_eq!(json("[]").kind(), Kind::Seq);
+        assert_eq!(json("{}").kind(), Kind::Map);
+        assert_eq!(Any::new(crate::Bytes::new()).unwrap().kind(), Kind::Bytes);
+    }
+
+    #[test]
+    fn chars() {
+        let value = Any::new('a').unwrap();
+        assert_eq!(value.kind(), Kind::String);
+        assert_eq!(value.as_str(), Some("a"));
+
+        let value = <Any as serde::Deserialize>::deserialize(serde::de::value::CharDeserializer::<
+            serde::de::value::Error,
+        >::new('a'))
+        .unwrap();
+        assert_eq!(value.kind(), Kind::String);
+        assert_eq!(value.as_str(), Some("a"));
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(json("1").as_i64(), Some(1));
+        assert_eq!(json("-1").as_i64(), Some(-1));
+        assert_eq!(json("-1").as_u64(), None);
+        assert_eq!(json("18446744073709551615").as_i64(), None);
+        assert_eq!(json("18446744073709551615").as_u64(), Some(u64::MAX));
+        assert_eq!(json("1").as_f64(), Some(1.));
+        assert_eq!(json("1.5").as_f64(), Some(1.5));
+        assert_eq!(json("1.5").as_i64(), None);
+        assert!(json(r#""NaN""#).as_f64().unwrap().is_nan());
+        assert_eq!(json(r#""foo""#).as_f64(), None);
+    }
+
+    #[test]
+    fn pointers() {
+        let value = json(r#"{"a": [{"b/c": 1, "d~e": 2}], "": 3, "10": 4}"#);
+
+        assert_eq!(value.pointer(""), Some(&value));
+        assert_eq!(value.pointer("/a/0/b~1c"), Some(&Any::from(1u64)));
+        assert_eq!(value.pointer("/a/0/d~0e"), Some(&Any::from(2u64)));
+        assert_eq!(value.pointer("/"), Some(&Any::from(3u64)));
+        assert_eq!(value.pointer("/10"), Some(&Any::from(4u64)));
+        assert_eq!(value.pointer("/a/00"), None);
+        assert_eq!(value.pointer("/a/1"), None);
+        assert_eq!(value.pointer("a"), None);
+        assert_eq!(value.pointer("/a/0/b~1c/d"), None);
+    }
+
+    #[test]
+    fn mutation() {
+        let mut value = json(r#"{"a": [1]}"#);
+
+        *value.pointer_mut("/a/0").unwrap() = Any::from("x");
+        value
+            .get_mut("a")
+            .unwrap()
+            .as_seq_mut()
+            .unwrap()
+            .push(Any::NULL);
+        value
+            .as_map_mut()
+            .unwrap()
+            .insert(Any::from("b"), Any::from(true));
+
+        assert_eq!(value, json(r#"{"a": ["x", null], "b": true}"#));
+        assert_eq!(value.get_mut("a").unwrap().take(), json(r#"["x", null]"#));
+    }
+
+    #[test]
+    fn conversions_match_serialization() {
+        assert_eq!(Any::from(1i32), Any::new(1i32).unwrap());
+        assert_eq!(Any::from(1.5f64), Any::new(1.5f64).unwrap());
+        assert_eq!(Any::from('a'), Any::new('a').unwrap());
+        assert_eq!(Any::from(None::<i32>), Any::new(None::<i32>).unwrap());
+        assert_eq!(
+            vec![("a", 1)].into_iter().collect::<Any>(),
+            Any::new(vec![("a", 1)].into_iter().collect::<BTreeMap<_, _>>()).unwrap(),
+        );
+        assert_eq!(
+            vec![Any::from(true)].into_iter().collect::<Any>(),
+            Any::new(vec![true]).unwrap(),
+        );
+    }
+}
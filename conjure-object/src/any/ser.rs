@@ -42,7 +42,6 @@ impl Serialize for Any {
             Inner::U128(v) => serializer.serialize_u128(*v),
             Inner::F32(v) => serializer.serialize_f32(v.0),
             Inner::F64(v) => serializer.serialize_f64(v.0),
-            Inner::Char(v) => serializer.serialize_char(*v),
             Inner::String(v) => serializer.serialize_str(v),
             Inner::Bytes(v) => serializer.serialize_bytes(v),
             Inner::Seq(v) => v.serialize(serializer),
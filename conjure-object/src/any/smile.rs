@@ -0,0 +1,133 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Conversions between `Any` and `serde_smile::value::Value`.
+use crate::any::{Any, Error, Inner};
+use ordered_float::OrderedFloat;
+use serde_smile::value::{BigInteger, Value};
+use std::convert::TryFrom;
+
+/// Converts a Smile value into an `Any`.
+///
+/// Smile's 32 and 64 bit integers are stored as `i32`s and `i64`s, and big integers as `i128`s. Non-finite floats are
+/// preserved as floats.
+///
+/// # Errors
+///
+/// Returns an error if the value contains a big integer outside the range of `i128`, or a big decimal.
+impl TryFrom<Value> for Any {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Any, Error> {
+        match value {
+            Value::Null => Ok(Any(Inner::Null)),
+            Value::Boolean(v) => Ok(Any(Inner::Bool(v))),
+            Value::Integer(v) => Ok(Any(Inner::I32(v))),
+            Value::Long(v) => Ok(Any(Inner::I64(v))),
+            Value::BigInteger(v) => big_integer_to_i128(&v).map(|v| Any(Inner::I128(v))),
+            Value::Float(v) => Ok(Any(Inner::F32(OrderedFloat(v)))),
+            Value::Double(v) => Ok(Any(Inner::F64(OrderedFloat(v)))),
+            Value::BigDecimal(_) => Err(Error(
+                "Smile big decimals cannot be represented by Any".to_string(),
+            )),
+            Value::String(v) => Ok(Any(Inner::String(v))),
+            Value::Binary(v) => Ok(Any(Inner::Bytes(v))),
+            Value::Array(v) => v
+                .into_iter()
+                .map(Any::try_from)
+                .collect::<Result<_, _>>()
+                .map(|v| Any(Inner::Seq(v))),
+            Value::Object(v) => v
+                .into_iter()
+                .map(|(k, v)| Ok((Any(Inner::String(k)), Any::try_from(v)?)))
+                .collect::<Result<_, _>>()
+                .map(|v| Any(Inner::Map(v))),
+        }
+    }
+}
+
+/// Converts an `Any` into a Smile value.
+///
+/// Integers which don't fit in Smile's 32 or 64 bit integers are stored as big integers, and non-finite floats remain
+/// floats. Map keys are converted to strings in the same way as for JSON.
+///
+/// # Errors
+///
+/// Returns an error if the value contains a map key which is null, a sequence, or a map.
+impl TryFrom<Any> for Value {
+    type Error = Error;
+
+    fn try_from(value: Any) -> Result<Value, Error> {
+        match value.0 {
+            Inner::Null => Ok(Value::Null),
+            Inner::Bool(v) => Ok(Value::Boolean(v)),
+            Inner::I8(v) => Ok(Value::Integer(i32::from(v))),
+            Inner::I16(v) => Ok(Value::Integer(i32::from(v))),
+            Inner::I32(v) => Ok(Value::Integer(v)),
+            Inner::I64(v) => Ok(Value::Long(v)),
+            Inner::I128(v) => Ok(integer(v)),
+            Inner::U8(v) => Ok(Value::Integer(i32::from(v))),
+            Inner::U16(v) => Ok(Value::Integer(i32::from(v))),
+            Inner::U32(v) => Ok(Value::Long(i64::from(v))),
+            Inner::U64(v) => Ok(integer(i128::from(v))),
+            Inner::U128(v) => Ok(match i128::try_from(v) {
+                Ok(v) => integer(v),
+                // Prefix a zero byte to keep the two's complement representation positive.
+                Err(_) => {
+                    let mut buf = vec![0];
+                    buf.extend_from_slice(&v.to_be_bytes());
+                    Value::BigInteger(BigInteger::from_be_bytes(buf))
+                }
+            }),
+            Inner::F32(v) => Ok(Value::Float(v.0)),
+            Inner::F64(v) => Ok(Value::Double(v.0)),
+            Inner::String(v) => Ok(Value::String(v)),
+            Inner::Bytes(v) => Ok(Value::Binary(v)),
+            Inner::Seq(v) => v
+                .into_iter()
+                .map(Value::try_from)
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            Inner::Map(v) => v
+                .into_iter()
+                .map(|(k, v)| Ok((k.into_string_key()?, Value::try_from(v)?)))
+                .collect::<Result<_, _>>()
+                .map(Value::Object),
+        }
+    }
+}
+
+fn integer(v: i128) -> Value {
+    if let Ok(v) = i32::try_from(v) {
+        Value::Integer(v)
+    } else if let Ok(v) = i64::try_from(v) {
+        Value::Long(v)
+    } else {
+        Value::BigInteger(BigInteger::from_be_bytes(v.to_be_bytes().to_vec()))
+    }
+}
+
+fn big_integer_to_i128(v: &BigInteger) -> Result<i128, Error> {
+    let bytes = v.as_be_bytes();
+    if bytes.is_empty() || bytes.len() > 16 {
+        return Err(Error(
+            "Smile big integer is out of range for Any".to_string(),
+        ));
+    }
+
+    // Sign extend the two's complement representation.
+    let fill = if bytes[0] & 0x80 == 0 { 0 } else { 0xff };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buf))
+}
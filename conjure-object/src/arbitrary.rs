@@ -0,0 +1,127 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! `proptest` strategies for Conjure types.
+//!
+//! Enable the `proptest` cargo feature to opt in. Code generated with `conjure_codegen::Config::arbitrary` uses the
+//! strategies in this module for builtin types which don't implement `Arbitrary` themselves.
+use crate::{
+    Any, BearerToken, Bytes, DateTime, DoubleKey, ResourceIdentifier, SafeLong, Utc, Uuid,
+};
+use chrono::TimeZone;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection;
+use proptest::strategy::{BoxedStrategy, Strategy};
+use std::collections::BTreeMap;
+
+// 0001-01-01T00:00:00Z through 9999-12-31T23:59:59.999Z, the range representable by RFC 3339 timestamps.
+const MIN_TIMESTAMP_MILLIS: i64 = -62_135_596_800_000;
+const MAX_TIMESTAMP_MILLIS: i64 = 253_402_300_799_999;
+
+/// Returns a strategy generating `SafeLong`s across their entire valid range.
+pub fn safe_long() -> impl Strategy<Value = SafeLong> {
+    (*SafeLong::min_value()..=*SafeLong::max_value()).prop_map(|v| SafeLong::new(v).unwrap())
+}
+
+/// Returns a strategy generating valid `ResourceIdentifier`s.
+pub fn resource_identifier() -> impl Strategy<Value = ResourceIdentifier> {
+    r"ri\.[a-z][a-z0-9\-]{0,8}\.([a-z0-9][a-z0-9\-]{0,8})?\.[a-z][a-z0-9\-]{0,8}\.[a-zA-Z0-9_\-\.]{1,16}"
+        .prop_map(|s| s.parse().unwrap())
+}
+
+/// Returns a strategy generating valid `BearerToken`s.
+pub fn bearer_token() -> impl Strategy<Value = BearerToken> {
+    r"[A-Za-z0-9\-\._~\+/]{1,32}={0,2}".prop_map(|s| s.parse().unwrap())
+}
+
+/// Returns a strategy generating `DoubleKey`s, including `NaN` and infinite values.
+pub fn double_key() -> impl Strategy<Value = DoubleKey> {
+    any::<f64>().prop_map(DoubleKey)
+}
+
+/// Returns a strategy generating `Any` values.
+///
+/// Generated values are restricted to strings, booleans, lists, and string-keyed maps. Those have a single in-memory
+/// representation regardless of the wire format they were decoded from, so the values compare equal after a round
+/// trip through JSON, Smile, or CBOR.
+pub fn any_value() -> impl Strategy<Value = Any> {
+    let leaf = proptest::prop_oneof![
+        any::<bool>().prop_map(|v| Any::new(v).unwrap()),
+        any::<String>().prop_map(|v| Any::new(v).unwrap()),
+    ];
+
+    leaf.prop_recursive(3, 16, 4, |inner| {
+        proptest::prop_oneof![
+            collection::vec(inner.clone(), 0..4).prop_map(|v| Any::new(v).unwrap()),
+            collection::btree_map(any::<String>(), inner, 0..4)
+                .prop_map(|v: BTreeMap<String, Any>| Any::new(v).unwrap()),
+        ]
+    })
+}
+
+/// Returns a strategy generating `DateTime<Utc>`s with millisecond precision.
+pub fn date_time() -> impl Strategy<Value = DateTime<Utc>> {
+    (MIN_TIMESTAMP_MILLIS..=MAX_TIMESTAMP_MILLIS).prop_map(|v| Utc.timestamp_millis_opt(v).unwrap())
+}
+
+/// Returns a strategy generating `Uuid`s.
+pub fn uuid() -> impl Strategy<Value = Uuid> {
+    any::<u128>().prop_map(Uuid::from_u128)
+}
+
+/// Returns a strategy generating `Bytes`.
+pub fn bytes() -> impl Strategy<Value = Bytes> {
+    collection::vec(any::<u8>(), 0..32).prop_map(Bytes::from)
+}
+
+macro_rules! impl_arbitrary {
+    ($($t:ty => $strategy:ident,)*) => {
+        $(
+            impl Arbitrary for $t {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<$t>;
+
+                fn arbitrary_with(_: ()) -> Self::Strategy {
+                    $strategy().boxed()
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary! {
+    SafeLong => safe_long,
+    ResourceIdentifier => resource_identifier,
+    BearerToken => bearer_token,
+    DoubleKey => double_key,
+    Any => any_value,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn resource_identifiers_round_trip(rid in resource_identifier()) {
+            assert_eq!(rid.as_str().parse::<ResourceIdentifier>().unwrap(), rid);
+        }
+
+        #[test]
+        fn any_round_trips(value in any_value()) {
+            let copy = Any::new(&value).unwrap();
+            assert_eq!(copy, value);
+        }
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::{alphabet, DecodeError, Engine};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::{Any, BearerToken};
+
+// JWTs are supposed to use unpadded base64url, but be lenient in what we accept.
+const URL_SAFE_INDIFFERENT: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+impl BearerToken {
+    /// Decodes the claims of a bearer token in the JWT format.
+    ///
+    /// # Warning
+    ///
+    /// The token's signature is *not* verified! The claims must not be trusted for authentication or authorization
+    /// unless the token has been validated separately.
+    ///
+    /// Requires the `jwt` Cargo feature.
+    pub fn unverified_claims(&self) -> Result<Claims, ClaimsError> {
+        let mut parts = self.expose().split('.');
+        let payload = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(_), Some(payload), Some(_), None) => payload,
+            _ => return Err(ClaimsError(ClaimsErrorInner::Format)),
+        };
+
+        let payload = URL_SAFE_INDIFFERENT
+            .decode(payload)
+            .map_err(|e| ClaimsError(ClaimsErrorInner::Base64(e)))?;
+
+        serde_json::from_slice(&payload).map_err(|e| ClaimsError(ClaimsErrorInner::Json(e)))
+    }
+}
+
+/// The claims of a JWT.
+///
+/// The registered claims defined in [RFC 7519] are exposed as typed fields, and all other claims are available as
+/// [`Any`] values.
+///
+/// [RFC 7519]: https://www.rfc-editor.org/rfc/rfc7519#section-4.1
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Claims {
+    #[serde(rename = "iss", default)]
+    issuer: Option<String>,
+    #[serde(rename = "sub", default)]
+    subject: Option<String>,
+    #[serde(rename = "aud", default, deserialize_with = "audience")]
+    audience: Vec<String>,
+    #[serde(rename = "exp", default, deserialize_with = "numeric_date")]
+    expiration: Option<DateTime<Utc>>,
+    #[serde(rename = "nbf", default, deserialize_with = "numeric_date")]
+    not_before: Option<DateTime<Utc>>,
+    #[serde(rename = "iat", default, deserialize_with = "numeric_date")]
+    issued_at: Option<DateTime<Utc>>,
+    #[serde(rename = "jti", default)]
+    jwt_id: Option<String>,
+    #[serde(flatten)]
+    extra: BTreeMap<String, Any>,
+}
+
+impl Claims {
+    /// Returns the `iss` (issuer) claim.
+    #[inline]
+    pub fn issuer(&self) -> Option<&str> {
+        self.issuer.as_deref()
+    }
+
+    /// Returns the `sub` (subject) claim.
+    #[inline]
+    pub fn subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    /// Returns the `aud` (audience) claim.
+    ///
+    /// A single string audience is returned as a one-element slice.
+    #[inline]
+    pub fn audience(&self) -> &[String] {
+        &self.audience
+    }
+
+    /// Returns the `exp` (expiration time) claim.
+    #[inline]
+    pub fn expiration(&self) -> Option<DateTime<Utc>> {
+        self.expiration
+    }
+
+    /// Returns the `nbf` (not before) claim.
+    #[inline]
+    pub fn not_before(&self) -> Option<DateTime<Utc>> {
+        self.not_before
+    }
+
+    /// Returns the `iat` (issued at) claim.
+    #[inline]
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.issued_at
+    }
+
+    /// Returns the `jti` (JWT ID) claim.
+    #[inline]
+    pub fn jwt_id(&self) -> Option<&str> {
+        self.jwt_id.as_deref()
+    }
+
+    /// Returns the value of a claim not covered by the other accessors.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&Any> {
+        self.extra.get(name)
+    }
+
+    /// Returns all claims not covered by the other accessors.
+    #[inline]
+    pub fn extra(&self) -> &BTreeMap<String, Any> {
+        &self.extra
+    }
+}
+
+fn audience<'de, D>(d: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Audience {
+        Single(String),
+        Multiple(Vec<String>),
+    }
+
+    // A null audience is treated the same as a missing one.
+    match Option::<Audience>::deserialize(d)? {
+        Some(Audience::Single(s)) => Ok(vec![s]),
+        Some(Audience::Multiple(v)) => Ok(v),
+        None => Ok(vec![]),
+    }
+}
+
+fn numeric_date<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // NumericDates are seconds since the epoch, and may be fractional.
+    let Some(seconds) = Option::<f64>::deserialize(d)? else {
+        return Ok(None);
+    };
+    Utc.timestamp_millis_opt((seconds * 1000.).round() as i64)
+        .single()
+        .map(Some)
+        .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Float(seconds), &"a NumericDate"))
+}
+
+#[derive(Debug)]
+enum ClaimsErrorInner {
+    Format,
+    Base64(DecodeError),
+    Json(serde_json::Error),
+}
+
+/// An error decoding the claims of a `BearerToken`.
+#[derive(Debug)]
+pub struct ClaimsError(ClaimsErrorInner);
+
+impl fmt::Display for ClaimsError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            ClaimsErrorInner::Format => fmt.write_str("bearer token is not a JWT"),
+            ClaimsErrorInner::Base64(e) => write!(fmt, "invalid JWT payload encoding: {}", e),
+            ClaimsErrorInner::Json(e) => write!(fmt, "invalid JWT claims: {}", e),
+        }
+    }
+}
+
+impl Error for ClaimsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.0 {
+            ClaimsErrorInner::Format => None,
+            ClaimsErrorInner::Base64(e) => Some(e),
+            ClaimsErrorInner::Json(e) => Some(e),
+        }
+    }
+}
@@ -18,8 +18,17 @@ use serde::ser::{Serialize, Serializer};
 use std::borrow::Borrow;
 use std::error::Error;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::str::FromStr;
+use std::{hint, mem};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
 
+#[cfg(feature = "jwt")]
+pub use jwt::{Claims, ClaimsError};
+
+#[cfg(feature = "jwt")]
+mod jwt;
 #[cfg(test)]
 mod test;
 
@@ -60,7 +69,10 @@ static VALID_CHARS: [u8; 256] = [
 /// An authentication bearer token.
 ///
 /// Bearer tokens are strings which match the regular expression `^[A-Za-z0-9\-\._~\+/]+=*$`.
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// Equality comparisons between tokens run in constant time with respect to their contents, though not their lengths.
+/// If the `zeroize` Cargo feature is enabled, the token's buffer is wiped when it is dropped.
+#[derive(Clone, PartialOrd, Ord)]
 pub struct BearerToken(String);
 
 impl BearerToken {
@@ -78,13 +90,61 @@ impl BearerToken {
         &self.0
     }
 
+    /// Returns the secret string representation of the bearer token.
+    ///
+    /// This is equivalent to `as_str`, but makes reads of the token's contents explicit and easy to audit.
+    #[inline]
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
     /// Consumes the bearer token, returning its owned string representation.
+    ///
+    /// The buffer is moved rather than copied, so no copy of the token is left behind. It is the caller's
+    /// responsibility to wipe the returned string if needed.
     #[inline]
-    pub fn into_string(self) -> String {
-        self.0
+    pub fn into_string(mut self) -> String {
+        mem::take(&mut self.0)
+    }
+}
+
+impl PartialEq for BearerToken {
+    fn eq(&self, other: &BearerToken) -> bool {
+        let a = self.0.as_bytes();
+        let b = other.0.as_bytes();
+
+        if a.len() != b.len() {
+            return false;
+        }
+
+        // Accumulate differences over the entire token rather than returning at the first mismatch to avoid leaking
+        // the length of the common prefix through timing.
+        let diff = a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b));
+        hint::black_box(diff) == 0
+    }
+}
+
+impl Eq for BearerToken {}
+
+impl Hash for BearerToken {
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.hash(state)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BearerToken {
+    fn drop(&mut self) {
+        self.0.zeroize();
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for BearerToken {}
+
 impl AsRef<str> for BearerToken {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -131,13 +191,14 @@ impl<'de> Deserialize<'de> for BearerToken {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(d)?;
+        // wrap the string immediately so it's wiped even if validation fails
+        let token = BearerToken(String::deserialize(d)?);
 
-        if is_valid(&s) {
-            Ok(BearerToken(s))
+        if is_valid(&token.0) {
+            Ok(token)
         } else {
             Err(de::Error::invalid_value(
-                Unexpected::Str(&s),
+                Unexpected::Other("an invalid bearer token"),
                 &"a bearer token",
             ))
         }
@@ -40,3 +40,91 @@ fn valid_chars_format() {
         assert!(b == 0 || b == i as u8);
     }
 }
+
+#[test]
+fn equality() {
+    let a = "foobar".parse::<BearerToken>().unwrap();
+    assert_eq!(a, "foobar".parse::<BearerToken>().unwrap());
+    assert_ne!(a, "foobaz".parse::<BearerToken>().unwrap());
+    assert_ne!(a, "fooba".parse::<BearerToken>().unwrap());
+    assert_ne!(a, "foobarr".parse::<BearerToken>().unwrap());
+}
+
+#[test]
+fn expose() {
+    let token = "foobar".parse::<BearerToken>().unwrap();
+    assert_eq!(token.expose(), "foobar");
+    assert_eq!(token.into_string(), "foobar");
+}
+
+#[test]
+fn deserialize_invalid_does_not_leak() {
+    let err = serde_json::from_str::<BearerToken>(r#""hunter 2""#).unwrap_err();
+    assert!(!err.to_string().contains("hunter"));
+}
+
+#[test]
+#[cfg(feature = "jwt")]
+fn unverified_claims() {
+    use chrono::TimeZone;
+
+    let token = BearerToken::new(
+        "eyJhbGciOiJub25lIn0.\
+         eyJpc3MiOiJpc3N1ZXIiLCJzdWIiOiJ1c2VyIiwiYXVkIjoic3ZjIiwiZXhwIjoxNzAwMDAwMDAwLCJpYXQiOjE2OTk5OTk5OTkuNSwib3JnIjoiYWNtZSJ9.\
+         c2ln",
+    )
+    .unwrap();
+
+    let claims = token.unverified_claims().unwrap();
+    assert_eq!(claims.issuer(), Some("issuer"));
+    assert_eq!(claims.subject(), Some("user"));
+    assert_eq!(claims.audience(), &["svc".to_string()]);
+    assert_eq!(
+        claims.expiration(),
+        Some(chrono::Utc.timestamp_opt(1_700_000_000, 0).unwrap())
+    );
+    assert_eq!(
+        claims.issued_at(),
+        Some(
+            chrono::Utc
+                .timestamp_opt(1_699_999_999, 500_000_000)
+                .unwrap()
+        )
+    );
+    assert_eq!(claims.not_before(), None);
+    assert_eq!(claims.jwt_id(), None);
+    assert_eq!(claims.get("org").and_then(|v| v.as_str()), Some("acme"));
+    assert_eq!(claims.extra().len(), 1);
+
+    let token = BearerToken::new("eyJhbGciOiJub25lIn0.eyJhdWQiOlsiYSIsImIiXX0.").unwrap();
+    let claims = token.unverified_claims().unwrap();
+    assert_eq!(claims.audience(), &["a".to_string(), "b".to_string()]);
+
+    // {"aud":null}
+    let token = BearerToken::new("eyJhbGciOiJub25lIn0.eyJhdWQiOm51bGx9.").unwrap();
+    let claims = token.unverified_claims().unwrap();
+    assert!(claims.audience().is_empty());
+
+    // {"exp":null}
+    let token = BearerToken::new("eyJhbGciOiJub25lIn0.eyJleHAiOm51bGx9.").unwrap();
+    let claims = token.unverified_claims().unwrap();
+    assert_eq!(claims.expiration(), None);
+}
+
+#[test]
+#[cfg(feature = "jwt")]
+fn invalid_claims() {
+    BearerToken::new("foobar")
+        .unwrap()
+        .unverified_claims()
+        .unwrap_err();
+    BearerToken::new("a.b.c.d")
+        .unwrap()
+        .unverified_claims()
+        .unwrap_err();
+    BearerToken::new("a.!!!.c").unwrap_err();
+    BearerToken::new("eyJhbGciOiJub25lIn0.Zm9v.c2ln")
+        .unwrap()
+        .unverified_claims()
+        .unwrap_err();
+}
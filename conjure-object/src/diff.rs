@@ -0,0 +1,616 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Structural diffing of Conjure values.
+//!
+//! The [`diff`] function compares two values and reports each difference along with the path to it. Code generated
+//! with `conjure_codegen::Config::diff` implements [`Diff`] for Conjure objects, unions, enums, and aliases. Values of
+//! fields with a log safety of `UNSAFE` or `DO_NOT_LOG` are redacted in the reported differences.
+//!
+//! ```
+//! use conjure_object::diff;
+//! use std::collections::BTreeMap;
+//!
+//! let old = BTreeMap::from([("a", vec![1, 2]), ("b", vec![3])]);
+//! let new = BTreeMap::from([("a", vec![1, 5]), ("c", vec![4])]);
+//!
+//! let differences = diff::diff(&old, &new);
+//! assert_eq!(
+//!     differences.to_string(),
+//!     "$[\"a\"][1]: 2 -> 5\n$[\"b\"]: removed [3]\n$[\"c\"]: added [4]\n",
+//! );
+//! ```
+use crate::{BearerToken, Bytes, DateTime, DoubleKey, ResourceIdentifier, SafeLong, Utc, Uuid};
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::sync::Arc;
+
+/// Returns the differences between two values.
+pub fn diff<T>(old: &T, new: &T) -> Differences
+where
+    T: ?Sized + Diff,
+{
+    let mut differ = Differ {
+        path: vec![],
+        redact: false,
+        differences: vec![],
+    };
+    old.diff(new, &mut differ);
+    Differences(differ.differences)
+}
+
+/// A trait implemented by types which can be structurally compared.
+pub trait Diff {
+    /// Records the differences between `self` and `other` in the `Differ`.
+    fn diff(&self, other: &Self, differ: &mut Differ);
+}
+
+/// A collector of differences between two values.
+///
+/// It tracks the path to the values currently being compared, and whether those values should be redacted.
+pub struct Differ {
+    path: Vec<PathSegment>,
+    redact: bool,
+    differences: Vec<Difference>,
+}
+
+impl Differ {
+    /// Compares the values of a field.
+    ///
+    /// If `redact` is `true`, the values of the field and everything nested within them will be redacted.
+    pub fn field<T>(&mut self, name: &str, redact: bool, old: &T, new: &T)
+    where
+        T: ?Sized + Diff,
+    {
+        self.path.push(PathSegment::Field(name.to_string()));
+        self.with_redaction(redact, |differ| old.diff(new, differ));
+        self.path.pop();
+    }
+
+    /// Runs a closure, redacting any differences it records if `redact` is `true`.
+    pub fn with_redaction<F>(&mut self, redact: bool, f: F)
+    where
+        F: FnOnce(&mut Differ),
+    {
+        let old = self.redact;
+        self.redact |= redact;
+        f(self);
+        self.redact = old;
+    }
+
+    /// Records that the value at the current path has changed.
+    pub fn changed<T>(&mut self, old: &T, new: &T)
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        let change = Change::Changed {
+            old: self.render(old),
+            new: self.render(new),
+        };
+        self.record(change);
+    }
+
+    /// Records that a value has been added at the current path.
+    pub fn added<T>(&mut self, value: &T)
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        let change = Change::Added(self.render(value));
+        self.record(change);
+    }
+
+    /// Records that a value has been removed from the current path.
+    pub fn removed<T>(&mut self, value: &T)
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        let change = Change::Removed(self.render(value));
+        self.record(change);
+    }
+
+    fn index<F>(&mut self, index: usize, f: F)
+    where
+        F: FnOnce(&mut Differ),
+    {
+        self.path.push(PathSegment::Index(index));
+        f(self);
+        self.path.pop();
+    }
+
+    fn key<K, F>(&mut self, key: &K, f: F)
+    where
+        K: ?Sized + fmt::Debug,
+        F: FnOnce(&mut Differ),
+    {
+        self.path.push(PathSegment::Key(self.render(key)));
+        f(self);
+        self.path.pop();
+    }
+
+    fn render<T>(&self, value: &T) -> Value
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        if self.redact {
+            Value::Redacted
+        } else {
+            Value::Rendered(format!("{value:?}"))
+        }
+    }
+
+    fn record(&mut self, change: Change) {
+        self.differences.push(Difference {
+            path: Path(self.path.clone()),
+            change,
+        });
+    }
+}
+
+/// The differences between two values.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Differences(Vec<Difference>);
+
+impl Differences {
+    /// Returns `true` if the values were equal.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of differences.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the differences.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Difference> {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Differences {
+    type Item = &'a Difference;
+    type IntoIter = std::slice::Iter<'a, Difference>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl IntoIterator for Differences {
+    type Item = Difference;
+    type IntoIter = std::vec::IntoIter<Difference>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Renders one difference per line.
+impl fmt::Display for Differences {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for difference in &self.0 {
+            writeln!(fmt, "{difference}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A single difference between two values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    path: Path,
+    change: Change,
+}
+
+impl Difference {
+    /// Returns the path to the value which differs.
+    #[inline]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the change made to the value.
+    #[inline]
+    pub fn change(&self) -> &Change {
+        &self.change
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}: ", self.path)?;
+        match &self.change {
+            Change::Changed { old, new } => write!(fmt, "{old} -> {new}"),
+            Change::Added(value) => write!(fmt, "added {value}"),
+            Change::Removed(value) => write!(fmt, "removed {value}"),
+        }
+    }
+}
+
+/// The path to a value, starting from the root of the compared values.
+///
+/// It is displayed in a JSONPath-like syntax, for example `$.foo[0]["bar"]`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Path(Vec<PathSegment>);
+
+impl Path {
+    /// Returns the segments of the path.
+    #[inline]
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("$")?;
+        for segment in &self.0 {
+            match segment {
+                PathSegment::Field(name) => write!(fmt, ".{name}")?,
+                PathSegment::Index(index) => write!(fmt, "[{index}]")?,
+                PathSegment::Key(key) => write!(fmt, "[{key}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A segment of a [`Path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// An object field or union variant.
+    Field(String),
+    /// A list index.
+    Index(usize),
+    /// A map key.
+    Key(Value),
+}
+
+/// A change made to a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Change {
+    /// The value was modified.
+    Changed {
+        /// The old value.
+        old: Value,
+        /// The new value.
+        new: Value,
+    },
+    /// The value was added.
+    Added(Value),
+    /// The value was removed.
+    Removed(Value),
+}
+
+/// A rendered value in a [`Difference`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Value {
+    /// The `Debug` representation of the value.
+    Rendered(String),
+    /// The value was redacted because it is not safe to log.
+    Redacted,
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Rendered(value) => fmt.write_str(value),
+            Value::Redacted => fmt.write_str("<redacted>"),
+        }
+    }
+}
+
+macro_rules! impl_leaf {
+    ($($t:ty,)*) => {
+        $(
+            impl Diff for $t {
+                #[inline]
+                fn diff(&self, other: &Self, differ: &mut Differ) {
+                    if self != other {
+                        differ.changed(self, other);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_leaf! {
+    bool,
+    i32,
+    str,
+    String,
+    Arc<str>,
+    SafeLong,
+    ResourceIdentifier,
+    BearerToken,
+    DateTime<Utc>,
+    Uuid,
+    Bytes,
+    DoubleKey,
+}
+
+impl Diff for f64 {
+    #[inline]
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        if self != other && !(self.is_nan() && other.is_nan()) {
+            differ.changed(self, other);
+        }
+    }
+}
+
+impl<T> Diff for Box<T>
+where
+    T: ?Sized + Diff,
+{
+    #[inline]
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        (**self).diff(other, differ)
+    }
+}
+
+impl<T> Diff for Option<T>
+where
+    T: Diff + fmt::Debug,
+{
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        match (self, other) {
+            (Some(a), Some(b)) => a.diff(b, differ),
+            (Some(a), None) => differ.removed(a),
+            (None, Some(b)) => differ.added(b),
+            (None, None) => {}
+        }
+    }
+}
+
+impl<T> Diff for [T]
+where
+    T: Diff + fmt::Debug,
+{
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        for (i, (a, b)) in self.iter().zip(other).enumerate() {
+            differ.index(i, |differ| a.diff(b, differ));
+        }
+        for (i, a) in self.iter().enumerate().skip(other.len()) {
+            differ.index(i, |differ| differ.removed(a));
+        }
+        for (i, b) in other.iter().enumerate().skip(self.len()) {
+            differ.index(i, |differ| differ.added(b));
+        }
+    }
+}
+
+impl<T> Diff for Vec<T>
+where
+    T: Diff + fmt::Debug,
+{
+    #[inline]
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        (**self).diff(other, differ)
+    }
+}
+
+/// Set elements have no path of their own, so additions and removals are reported at the path of the set.
+impl<T> Diff for BTreeSet<T>
+where
+    T: Ord + fmt::Debug,
+{
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        for a in self.difference(other) {
+            differ.removed(a);
+        }
+        for b in other.difference(self) {
+            differ.added(b);
+        }
+    }
+}
+
+impl<K, V> Diff for BTreeMap<K, V>
+where
+    K: Ord + fmt::Debug,
+    V: Diff + fmt::Debug,
+{
+    fn diff(&self, other: &Self, differ: &mut Differ) {
+        diff_sorted_entries(self.iter(), other.iter(), differ)
+    }
+}
+
+fn diff_sorted_entries<'a, K, V, I>(a: I, b: I, differ: &mut Differ)
+where
+    K: 'a + Ord + fmt::Debug,
+    V: 'a + Diff + fmt::Debug,
+    I: Iterator<Item = (&'a K, &'a V)>,
+{
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+
+    loop {
+        match (a.peek(), b.peek()) {
+            (Some((ka, va)), Some((kb, vb))) => match ka.cmp(kb) {
+                std::cmp::Ordering::Less => {
+                    differ.key(*ka, |differ| differ.removed(*va));
+                    a.next();
+                }
+                std::cmp::Ordering::Greater => {
+                    differ.key(*kb, |differ| differ.added(*vb));
+                    b.next();
+                }
+                std::cmp::Ordering::Equal => {
+                    differ.key(*ka, |differ| va.diff(vb, differ));
+                    a.next();
+                    b.next();
+                }
+            },
+            (Some((ka, va)), None) => {
+                differ.key(*ka, |differ| differ.removed(*va));
+                a.next();
+            }
+            (None, Some((kb, vb))) => {
+                differ.key(*kb, |differ| differ.added(*vb));
+                b.next();
+            }
+            (None, None) => break,
+        }
+    }
+}
+
+fn sorted_entries<'a, K, V>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+) -> std::vec::IntoIter<(&'a K, &'a V)>
+where
+    K: 'a + Ord,
+{
+    let mut entries = entries.collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries.into_iter()
+}
+
+// Unordered collections report their differences in key order so the output is deterministic.
+macro_rules! impl_unordered {
+    ($map:ident, $set:ident) => {
+        impl<K, V, S> Diff for $map<K, V, S>
+        where
+            K: Ord + Hash + fmt::Debug,
+            V: Diff + fmt::Debug,
+            S: BuildHasher,
+        {
+            fn diff(&self, other: &Self, differ: &mut Differ) {
+                diff_sorted_entries(
+                    sorted_entries(self.iter()),
+                    sorted_entries(other.iter()),
+                    differ,
+                )
+            }
+        }
+
+        impl<T, S> Diff for $set<T, S>
+        where
+            T: Ord + Hash + fmt::Debug,
+            S: BuildHasher,
+        {
+            fn diff(&self, other: &Self, differ: &mut Differ) {
+                let mut removed = self.difference(other).collect::<Vec<_>>();
+                removed.sort();
+                for a in removed {
+                    differ.removed(a);
+                }
+
+                let mut added = other.difference(self).collect::<Vec<_>>();
+                added.sort();
+                for b in added {
+                    differ.added(b);
+                }
+            }
+        }
+    };
+}
+
+impl_unordered!(HashMap, HashSet);
+#[cfg(feature = "indexmap")]
+impl_unordered!(IndexMap, IndexSet);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn equal() {
+        assert!(diff(&vec![1, 2], &vec![1, 2]).is_empty());
+        assert!(diff(&f64::NAN, &f64::NAN).is_empty());
+    }
+
+    #[test]
+    fn lists() {
+        let differences = diff(&vec![1, 2, 3], &vec![1, 4]);
+        assert_eq!(differences.to_string(), "$[1]: 2 -> 4\n$[2]: removed 3\n");
+
+        let differences = diff(&vec![1], &vec![1, 2]);
+        assert_eq!(differences.to_string(), "$[1]: added 2\n");
+    }
+
+    #[test]
+    fn sets() {
+        let old = BTreeSet::from(["a", "b"]);
+        let new = BTreeSet::from(["b", "c"]);
+        assert_eq!(
+            diff(&old, &new).to_string(),
+            "$: removed \"a\"\n$: added \"c\"\n"
+        );
+    }
+
+    #[test]
+    fn unordered_maps() {
+        let old = HashMap::from([("c", 1), ("a", 1), ("b", 2)]);
+        let new = HashMap::from([("d", 4), ("b", 3), ("a", 1)]);
+        assert_eq!(
+            diff(&old, &new).to_string(),
+            "$[\"b\"]: 2 -> 3\n$[\"c\"]: removed 1\n$[\"d\"]: added 4\n",
+        );
+    }
+
+    #[test]
+    fn optionals() {
+        assert_eq!(diff(&Some(1), &None).to_string(), "$: removed 1\n");
+        assert_eq!(diff(&None, &Some(1)).to_string(), "$: added 1\n");
+    }
+
+    struct Object {
+        safe: String,
+        secret: BTreeMap<String, String>,
+    }
+
+    impl Diff for Object {
+        fn diff(&self, other: &Self, differ: &mut Differ) {
+            differ.field("safe", false, &self.safe, &other.safe);
+            differ.field("secret", true, &self.secret, &other.secret);
+        }
+    }
+
+    #[test]
+    fn redaction() {
+        let old = Object {
+            safe: "a".to_string(),
+            secret: BTreeMap::from([("k".to_string(), "v1".to_string())]),
+        };
+        let new = Object {
+            safe: "b".to_string(),
+            secret: BTreeMap::from([("k".to_string(), "v2".to_string())]),
+        };
+
+        let differences = diff(&old, &new);
+        assert_eq!(
+            differences.to_string(),
+            "$.safe: \"a\" -> \"b\"\n$.secret[<redacted>]: <redacted> -> <redacted>\n",
+        );
+
+        let difference = differences.iter().nth(1).unwrap();
+        assert_eq!(
+            difference.path().segments(),
+            &[
+                PathSegment::Field("secret".to_string()),
+                PathSegment::Key(Value::Redacted),
+            ]
+        );
+    }
+}
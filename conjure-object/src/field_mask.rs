@@ -0,0 +1,546 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Field masks and partial serialization of Conjure values.
+//!
+//! A [`FieldMask`] selects a subset of the fields of an object, including fields of nested objects. Code generated
+//! with `conjure_codegen::Config::field_masks` implements [`Project`] for Conjure objects, unions, enums, and aliases,
+//! which serializes only the selected fields of objects.
+//!
+//! Projection always produces a valid serialized form of the value: only optional and collection fields are omitted,
+//! while unselected required fields are serialized with an empty mask. Masks apply through optionals, lists, sets, and
+//! map values to the values within them, and through unions to the value of their variant.
+//!
+//! ```
+//! use conjure_object::field_mask::FieldMask;
+//!
+//! let mask = "name,owner.email".parse::<FieldMask>().unwrap();
+//! assert!(mask.get("name").unwrap().is_all());
+//! assert!(mask.get("owner").unwrap().get("email").is_some());
+//! assert!(mask.get("owner").unwrap().get("phone").is_none());
+//! assert!(mask.get("tags").is_none());
+//! assert_eq!(mask.to_string(), "name,owner.email");
+//! ```
+use crate::{
+    Any, BearerToken, Bytes, DateTime, DoubleKey, ResourceIdentifier, SafeLong, Utc, Uuid,
+};
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+static ALL: FieldMask = FieldMask { fields: None };
+
+static EMPTY: FieldMask = FieldMask {
+    fields: Some(BTreeMap::new()),
+};
+
+/// A selection of the fields of a value.
+///
+/// Its string representation is a comma-separated list of dot-separated field paths, for example
+/// `name,owner.email`. The mask selecting all fields is represented as `*`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldMask {
+    fields: Option<BTreeMap<String, FieldMask>>,
+}
+
+impl FieldMask {
+    /// Returns a mask selecting all fields.
+    #[inline]
+    pub fn all() -> FieldMask {
+        ALL.clone()
+    }
+
+    /// Returns a mask selecting no fields.
+    #[inline]
+    pub fn empty() -> FieldMask {
+        EMPTY.clone()
+    }
+
+    /// Selects the entirety of a field.
+    pub fn field<F>(mut self, name: F) -> FieldMask
+    where
+        F: AsRef<str>,
+    {
+        if let Some(fields) = &mut self.fields {
+            fields.insert(name.as_ref().to_string(), FieldMask::all());
+        }
+        self
+    }
+
+    /// Selects the fields of a field matching a nested mask.
+    ///
+    /// The nested mask is merged with any existing selection of the field. An empty nested mask selects the entirety
+    /// of the field, like [`FieldMask::field`].
+    pub fn nested<F>(mut self, name: F, mask: FieldMask) -> FieldMask
+    where
+        F: AsRef<str>,
+    {
+        if let Some(fields) = &mut self.fields {
+            insert(fields, name.as_ref().to_string(), mask);
+        }
+        self
+    }
+
+    /// Returns the mask to apply to a field, or `None` if the field is not selected.
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&FieldMask> {
+        match &self.fields {
+            Some(fields) => fields.get(name),
+            None => Some(&ALL),
+        }
+    }
+
+    /// Returns `true` if the mask selects all fields.
+    #[inline]
+    pub fn is_all(&self) -> bool {
+        self.fields.is_none()
+    }
+
+    /// Returns `true` if the mask selects no fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.fields.as_ref().is_some_and(|f| f.is_empty())
+    }
+
+    /// Returns the mask to apply to a required field, which is serialized even if it is not selected.
+    #[doc(hidden)]
+    #[inline]
+    pub fn get_required(&self, name: &str) -> &FieldMask {
+        self.get(name).unwrap_or(&EMPTY)
+    }
+
+    fn merge(&mut self, other: FieldMask) {
+        let Some(fields) = &mut self.fields else {
+            return;
+        };
+
+        match other.fields {
+            Some(other) => {
+                for (name, mask) in other {
+                    insert(fields, name, mask);
+                }
+            }
+            None => self.fields = None,
+        }
+    }
+
+    fn fmt_paths(
+        &self,
+        prefix: &mut String,
+        first: &mut bool,
+        fmt: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let Some(fields) = &self.fields else {
+            return Ok(());
+        };
+
+        for (name, mask) in fields {
+            let len = prefix.len();
+            if !prefix.is_empty() {
+                prefix.push('.');
+            }
+            prefix.push_str(name);
+
+            if mask.is_all() {
+                if !*first {
+                    fmt.write_str(",")?;
+                }
+                *first = false;
+                fmt.write_str(prefix)?;
+            } else {
+                mask.fmt_paths(prefix, first, fmt)?;
+            }
+
+            prefix.truncate(len);
+        }
+
+        Ok(())
+    }
+}
+
+fn insert(fields: &mut BTreeMap<String, FieldMask>, name: String, mask: FieldMask) {
+    if mask.is_empty() {
+        fields.insert(name, FieldMask::all());
+    } else {
+        fields
+            .entry(name)
+            .or_insert_with(FieldMask::empty)
+            .merge(mask);
+    }
+}
+
+impl fmt::Display for FieldMask {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_all() {
+            return fmt.write_str("*");
+        }
+
+        self.fmt_paths(&mut String::new(), &mut true, fmt)
+    }
+}
+
+impl FromStr for FieldMask {
+    type Err = ParseFieldMaskError;
+
+    fn from_str(s: &str) -> Result<FieldMask, ParseFieldMaskError> {
+        let s = s.trim();
+        if s == "*" {
+            return Ok(FieldMask::all());
+        }
+
+        let mut mask = FieldMask::empty();
+        if s.is_empty() {
+            return Ok(mask);
+        }
+
+        for path in s.split(',') {
+            let mut segments = path.trim().split('.').rev();
+            let mut nested = FieldMask::all();
+            let mut name = segments.next().unwrap();
+            for parent in segments {
+                if name.is_empty() {
+                    return Err(ParseFieldMaskError(()));
+                }
+                nested = FieldMask::empty().nested(name, nested);
+                name = parent;
+            }
+            if name.is_empty() {
+                return Err(ParseFieldMaskError(()));
+            }
+            mask = mask.nested(name, nested);
+        }
+
+        Ok(mask)
+    }
+}
+
+/// An error parsing a [`FieldMask`] from its string representation.
+#[derive(Debug)]
+pub struct ParseFieldMaskError(());
+
+impl fmt::Display for ParseFieldMaskError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("invalid field mask")
+    }
+}
+
+impl Error for ParseFieldMaskError {}
+
+/// A trait implemented by types which can be serialized with a [`FieldMask`] applied.
+pub trait Project: Serialize {
+    /// Serializes the value, including only the fields selected by the mask.
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    /// Returns a serializable view of the value with a field mask applied.
+    #[inline]
+    fn project<'a>(&'a self, mask: &'a FieldMask) -> Projected<'a, Self> {
+        Projected::new(self, mask)
+    }
+}
+
+/// A serializable view of a value with a [`FieldMask`] applied.
+pub struct Projected<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    mask: &'a FieldMask,
+}
+
+impl<'a, T> Projected<'a, T>
+where
+    T: ?Sized,
+{
+    /// Creates a new view of a value with a field mask applied.
+    #[inline]
+    pub fn new(value: &'a T, mask: &'a FieldMask) -> Self {
+        Projected { value, mask }
+    }
+}
+
+impl<T> Serialize for Projected<'_, T>
+where
+    T: ?Sized + Project,
+{
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize_projected(self.mask, serializer)
+    }
+}
+
+macro_rules! impl_leaf {
+    ($($t:ty,)*) => {
+        $(
+            impl Project for $t {
+                #[inline]
+                fn serialize_projected<S>(&self, _: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    self.serialize(serializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_leaf! {
+    bool,
+    i32,
+    f64,
+    str,
+    String,
+    Arc<str>,
+    SafeLong,
+    ResourceIdentifier,
+    BearerToken,
+    DateTime<Utc>,
+    Uuid,
+    Bytes,
+    DoubleKey,
+    Any,
+}
+
+impl<T> Project for Box<T>
+where
+    T: ?Sized + Project,
+{
+    #[inline]
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize_projected(mask, serializer)
+    }
+}
+
+impl<T> Project for Option<T>
+where
+    T: Project,
+{
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Some(value) => serializer.serialize_some(&Projected::new(value, mask)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+fn serialize_seq<'a, I, T, S>(values: I, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+where
+    I: ExactSizeIterator<Item = &'a T>,
+    T: 'a + Project,
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(values.len()))?;
+    for value in values {
+        seq.serialize_element(&Projected::new(value, mask))?;
+    }
+    seq.end()
+}
+
+fn serialize_map<'a, I, K, V, S>(
+    entries: I,
+    mask: &FieldMask,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    I: ExactSizeIterator<Item = (&'a K, &'a V)>,
+    K: 'a + Serialize,
+    V: 'a + Project,
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(entries.len()))?;
+    for (key, value) in entries {
+        map.serialize_entry(key, &Projected::new(value, mask))?;
+    }
+    map.end()
+}
+
+impl<T> Project for [T]
+where
+    T: Project,
+{
+    #[inline]
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_seq(self.iter(), mask, serializer)
+    }
+}
+
+impl<T> Project for Vec<T>
+where
+    T: Project,
+{
+    #[inline]
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_seq(self.iter(), mask, serializer)
+    }
+}
+
+impl<T> Project for BTreeSet<T>
+where
+    T: Project,
+{
+    #[inline]
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_seq(self.iter(), mask, serializer)
+    }
+}
+
+impl<K, V> Project for BTreeMap<K, V>
+where
+    K: Serialize,
+    V: Project,
+{
+    #[inline]
+    fn serialize_projected<S>(&self, mask: &FieldMask, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_map(self.iter(), mask, serializer)
+    }
+}
+
+macro_rules! impl_unordered {
+    ($map:ident, $set:ident) => {
+        impl<T, H> Project for $set<T, H>
+        where
+            T: Project,
+        {
+            #[inline]
+            fn serialize_projected<S>(
+                &self,
+                mask: &FieldMask,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_seq(self.iter(), mask, serializer)
+            }
+        }
+
+        impl<K, V, H> Project for $map<K, V, H>
+        where
+            K: Serialize,
+            V: Project,
+        {
+            #[inline]
+            fn serialize_projected<S>(
+                &self,
+                mask: &FieldMask,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_map(self.iter(), mask, serializer)
+            }
+        }
+    };
+}
+
+impl_unordered!(HashMap, HashSet);
+#[cfg(feature = "indexmap")]
+impl_unordered!(IndexMap, IndexSet);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let mask = "a, b.c,b.d.e ,f.g,f".parse::<FieldMask>().unwrap();
+        assert_eq!(
+            mask,
+            FieldMask::empty()
+                .field("a")
+                .nested(
+                    "b",
+                    FieldMask::empty()
+                        .field("c")
+                        .nested("d", FieldMask::empty().field("e")),
+                )
+                .field("f"),
+        );
+        assert_eq!(mask.to_string(), "a,b.c,b.d.e,f");
+
+        assert!("*".parse::<FieldMask>().unwrap().is_all());
+        assert!("".parse::<FieldMask>().unwrap().is_empty());
+        assert!("a,".parse::<FieldMask>().is_err());
+        assert!("a..b".parse::<FieldMask>().is_err());
+        assert!(".a".parse::<FieldMask>().is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        let masks = [
+            FieldMask::all(),
+            FieldMask::empty(),
+            FieldMask::empty().nested("a", FieldMask::empty()),
+            FieldMask::empty()
+                .nested("a", FieldMask::empty().nested("b", FieldMask::empty()))
+                .field("c"),
+            FieldMask::empty().nested("a", FieldMask::empty().field("b").field("c")),
+        ];
+        for mask in masks {
+            assert_eq!(mask.to_string().parse::<FieldMask>().unwrap(), mask);
+        }
+
+        assert_eq!(
+            FieldMask::empty().nested("a", FieldMask::empty()),
+            FieldMask::empty().field("a"),
+        );
+    }
+
+    #[test]
+    fn get() {
+        let mask = FieldMask::empty().nested("a", FieldMask::empty().field("b"));
+        assert!(mask.get("a").unwrap().get("b").unwrap().is_all());
+        assert_eq!(mask.get("c"), None);
+        assert!(mask.get_required("c").is_empty());
+        assert!(FieldMask::all().get("c").unwrap().is_all());
+    }
+
+    #[test]
+    fn collections() {
+        let value = BTreeMap::from([("a", vec![Some(1)]), ("b", vec![None])]);
+        assert_eq!(
+            serde_json::to_string(&value.project(&FieldMask::empty())).unwrap(),
+            serde_json::to_string(&value).unwrap(),
+        );
+    }
+}
@@ -20,6 +20,8 @@
 
 pub use bytes::{self, Bytes};
 pub use chrono::{self, DateTime, Utc};
+#[cfg(feature = "indexmap")]
+pub use indexmap;
 pub use serde;
 pub use uuid::{self, Uuid};
 
@@ -37,8 +39,12 @@ pub use crate::resource_identifier::ResourceIdentifier;
 pub use crate::safe_long::SafeLong;
 
 pub mod any;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 pub mod bearer_token;
+pub mod diff;
 pub mod double_key;
+pub mod field_mask;
 pub mod log_safety;
 pub mod plain;
 pub mod resource_identifier;
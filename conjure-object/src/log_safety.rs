@@ -63,6 +63,10 @@ impl<K: LogSafe, V: LogSafe, S> LogSafe for std::collections::HashMap<K, V, S> {
 impl<T: LogSafe> LogSafe for std::collections::BTreeSet<T> {}
 impl<T: LogSafe, S> LogSafe for std::collections::HashSet<T, S> {}
 impl<T: LogSafe> LogSafe for std::collections::BinaryHeap<T> {}
+#[cfg(feature = "indexmap")]
+impl<K: LogSafe, V: LogSafe, S> LogSafe for indexmap::IndexMap<K, V, S> {}
+#[cfg(feature = "indexmap")]
+impl<T: LogSafe, S> LogSafe for indexmap::IndexSet<T, S> {}
 
 /// Re-exports LogSafe derive macro
 pub mod derive {
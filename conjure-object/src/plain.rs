@@ -20,12 +20,14 @@ use base64::{DecodeError, Engine};
 use bytes::Bytes;
 use chrono::format::{Fixed, Item, ParseError};
 use chrono::{DateTime, Utc};
+use std::convert::Infallible;
 use std::error::Error;
 use std::f64;
 use std::fmt;
 use std::iter;
 use std::num::ParseFloatError;
 use std::str::{FromStr, ParseBoolError};
+use std::sync::Arc;
 use uuid::Uuid;
 
 use crate::{BearerToken, ResourceIdentifier, SafeLong};
@@ -61,6 +63,7 @@ as_display!(ResourceIdentifier);
 as_display!(SafeLong);
 as_display!(str);
 as_display!(String);
+as_display!(Arc<str>);
 as_display!(Uuid);
 
 impl Plain for BearerToken {
@@ -160,6 +163,15 @@ as_from_str!(SafeLong);
 as_from_str!(String);
 as_from_str!(Uuid);
 
+impl FromPlain for Arc<str> {
+    type Err = Infallible;
+
+    #[inline]
+    fn from_plain(s: &str) -> Result<Self, Infallible> {
+        Ok(Arc::from(s))
+    }
+}
+
 impl FromPlain for bool {
     type Err = ParseBoolError;
 
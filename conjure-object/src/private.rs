@@ -14,19 +14,28 @@
 
 pub use conjure_macros::DeriveWith;
 pub use educe::Educe;
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
 use ordered_float::OrderedFloat;
+#[cfg(feature = "proptest")]
+pub use proptest;
 use serde::de::{self, IntoDeserializer};
 use serde::{Deserialize, Serialize};
 pub use staged_builder;
 use std::cmp::Ordering;
-use std::collections::BTreeMap;
-use std::hash::{Hash, Hasher};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::Deref;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::{fmt, mem};
 
+use crate::field_mask::{FieldMask, Project, Projected};
 use crate::plain::ParseEnumError;
+use crate::{
+    Any, BearerToken, Bytes, DateTime, DoubleKey, ResourceIdentifier, SafeLong, Utc, Uuid,
+};
 
 pub struct DoubleWrapper<T>(pub T);
 
@@ -217,6 +226,152 @@ where
     }
 }
 
+// Types which are Ord and Hash can be used with DoubleWrapper directly. Generated types implement this when they can
+// be stored in unordered collections, which are only Ord and Hash through DoubleWrapper.
+pub trait DoubleOpsLeaf: Ord + Hash {}
+
+macro_rules! double_ops_leaf {
+    ($($t:ty),*) => {
+        $(
+            impl DoubleOpsLeaf for $t {}
+        )*
+    };
+}
+
+double_ops_leaf!(
+    bool,
+    i32,
+    String,
+    Arc<str>,
+    SafeLong,
+    ResourceIdentifier,
+    BearerToken,
+    DateTime<Utc>,
+    Uuid,
+    Bytes,
+    Any,
+    DoubleKey
+);
+
+impl<T> DoubleOpsLeaf for Box<T> where T: ?Sized + DoubleOpsLeaf {}
+
+impl<T> DoubleOpsLeaf for BTreeSet<T> where T: Ord + Hash {}
+
+impl<T> DoubleOps for T
+where
+    T: DoubleOpsLeaf,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(self, other)
+    }
+
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        PartialEq::eq(self, other)
+    }
+
+    #[inline]
+    fn hash<H>(&self, hasher: &mut H)
+    where
+        H: Hasher,
+    {
+        Hash::hash(self, hasher)
+    }
+}
+
+fn sorted_entries<'a, K, V>(
+    entries: impl IntoIterator<Item = (&'a K, &'a V)>,
+) -> Vec<(&'a K, DoubleWrapper<&'a V>)>
+where
+    K: 'a + Ord,
+    V: 'a + DoubleOps,
+{
+    let mut entries = entries
+        .into_iter()
+        .map(|(k, v)| (k, DoubleWrapper(v)))
+        .collect::<Vec<_>>();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+    entries
+}
+
+fn sorted_values<'a, T>(values: impl IntoIterator<Item = &'a T>) -> Vec<&'a T>
+where
+    T: 'a + Ord,
+{
+    let mut values = values.into_iter().collect::<Vec<_>>();
+    values.sort();
+    values
+}
+
+// Unordered collections compare and hash as if they were sorted, matching their BTree equivalents.
+macro_rules! unordered_map_double_ops {
+    ($t:ident) => {
+        impl<K, V, S> DoubleOps for $t<K, V, S>
+        where
+            K: Ord + Hash,
+            V: DoubleOps,
+            S: BuildHasher,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                sorted_entries(self).cmp(&sorted_entries(other))
+            }
+
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.len() == other.len()
+                    && self
+                        .iter()
+                        .all(|(k, v)| other.get(k).map_or(false, |o| v.eq(o)))
+            }
+
+            #[inline]
+            fn hash<H>(&self, hasher: &mut H)
+            where
+                H: Hasher,
+            {
+                sorted_entries(self).hash(hasher)
+            }
+        }
+    };
+}
+
+macro_rules! unordered_set_double_ops {
+    ($t:ident) => {
+        impl<T, S> DoubleOps for $t<T, S>
+        where
+            T: Ord + Hash,
+            S: BuildHasher,
+        {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                sorted_values(self).cmp(&sorted_values(other))
+            }
+
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self == other
+            }
+
+            #[inline]
+            fn hash<H>(&self, hasher: &mut H)
+            where
+                H: Hasher,
+            {
+                sorted_values(self).hash(hasher)
+            }
+        }
+    };
+}
+
+unordered_map_double_ops!(HashMap);
+unordered_set_double_ops!(HashSet);
+#[cfg(feature = "indexmap")]
+unordered_map_double_ops!(IndexMap);
+#[cfg(feature = "indexmap")]
+unordered_set_double_ops!(IndexSet);
+
 fn valid_enum_variant(s: &str) -> bool {
     if s.is_empty() {
         return false;
@@ -227,6 +382,15 @@ fn valid_enum_variant(s: &str) -> bool {
         .all(|b| matches!(b, b'A'..=b'Z' | b'0'..=b'9' | b'_'))
 }
 
+/// A placeholder for values which are not safe to log in `Debug` output.
+pub struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("<redacted>")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 #[serde(transparent)]
 pub struct Variant(Box<str>);
@@ -371,19 +535,46 @@ impl de::Visitor<'_> for UnionTypeFieldVisitor {
 ///     uuid_alias_map: BTreeMap<UuidAliasExample, String>,
 /// }
 /// ```
-pub fn serialize_map_keys_as_strings<K, V, S>(
-    map: &BTreeMap<K, V>,
+pub fn serialize_map_keys_as_strings<'a, M, K, V, S>(
+    map: &'a M,
     serializer: S,
 ) -> Result<S::Ok, S::Error>
 where
-    K: std::fmt::Display,
-    V: Serialize,
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    <&'a M as IntoIterator>::IntoIter: ExactSizeIterator,
+    K: 'a + std::fmt::Display,
+    V: 'a + Serialize,
     S: serde::Serializer,
 {
     use serde::ser::SerializeMap;
+    let map = map.into_iter();
     let mut ser_map = serializer.serialize_map(Some(map.len()))?;
     for (key, value) in map {
         ser_map.serialize_entry(&key.to_string(), value)?;
     }
     ser_map.end()
 }
+
+/// A projected map which serializes its keys as strings, like [`serialize_map_keys_as_strings`].
+pub struct ProjectedMapKeysAsStrings<'a, M>(pub &'a M, pub &'a FieldMask);
+
+impl<'a, M, K, V> Serialize for ProjectedMapKeysAsStrings<'a, M>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    <&'a M as IntoIterator>::IntoIter: ExactSizeIterator,
+    K: 'a + std::fmt::Display,
+    V: 'a + Project,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+        let map = self.0.into_iter();
+        let mut ser_map = serializer.serialize_map(Some(map.len()))?;
+        for (key, value) in map {
+            ser_map.serialize_entry(&key.to_string(), &Projected::new(value, self.1))?;
+        }
+        ser_map.end()
+    }
+}
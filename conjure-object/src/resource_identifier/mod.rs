@@ -15,8 +15,6 @@
 //! The Conjure `rid` type.
 #![warn(missing_docs, clippy::all)]
 
-use lazy_static::lazy_static;
-use regex::Regex;
 use serde::de::{self, Deserialize, Deserializer, Unexpected};
 use serde::ser::{Serialize, Serializer};
 use std::borrow::Borrow;
@@ -32,25 +30,6 @@ mod test;
 const RID_CLASS: &str = "ri";
 const SEPARATOR: &str = ".";
 
-lazy_static! {
-    static ref PARSE_REGEX: Regex = Regex::new(
-        r"(?x)
-            ^
-            ri
-            \.
-            ([a-z][a-z0-9\-]*) #service
-            \.
-            ((?:[a-z0-9][a-z0-9\-]*)?) #instance
-            \.
-            ([a-z][a-z0-9\-]*) #type
-            \.
-            ([a-zA-Z0-9_\-\.]+) #locator
-            $
-        ",
-    )
-    .unwrap();
-}
-
 /// A common format for wrapping existing unique identifiers to provide additional context.
 ///
 /// Resource identifiers contain 4 components, prefixed by a format identifier `ri`, and separated with periods:
@@ -61,7 +40,7 @@ lazy_static! {
 /// * Instance: An optionally empty string that represents the specific service cluster, to allow for disambiduation of
 ///   artifacts from different service clusters. Must conform to the regex pattern `([a-z0-9][a-z0-9\-]*)?`.
 /// * Type: A service-specific resource type to namespace a group of locators. Must conform to the regex pattern
-///   `[a-z][a-z0-9\-\._]+`.
+///   `[a-z][a-z0-9\-]*`.
 /// * Locator: A string used to uniquely locate the specific resource. Must conform to the regex pattern
 ///   `[a-zA-Z0-9\-\._]+`.
 #[derive(Clone)]
@@ -82,19 +61,32 @@ impl ResourceIdentifier {
     }
 
     /// Creates a resource identifier from its individual components.
+    ///
+    /// This is a shorthand for setting each component with a [`Builder`].
     pub fn from_components(
         service: &str,
         instance: &str,
         type_: &str,
         locator: &str,
     ) -> Result<ResourceIdentifier, ParseError> {
-        // Make sure there aren't `.`s in componenents other than the locator up front, since that could cause an
-        // invalid input to parse correctly.
-        if service.contains('.') || instance.contains('.') || type_.contains('.') {
-            return Err(ParseError(()));
-        }
+        ResourceIdentifier::builder()
+            .service(service)
+            .instance(instance)
+            .type_(type_)
+            .locator(locator)
+            .build()
+    }
 
-        format!("ri.{service}.{instance}.{type_}.{locator}").parse()
+    /// Creates a new builder which validates each component of a resource identifier separately.
+    ///
+    /// The instance component defaults to empty, and all other components must be set.
+    pub fn builder() -> Builder {
+        Builder {
+            service: None,
+            instance: String::new(),
+            type_: None,
+            locator: None,
+        }
     }
 
     /// Returns the service component of the resource identifier.
@@ -136,22 +128,54 @@ impl ResourceIdentifier {
     pub fn into_string(self) -> String {
         self.rid
     }
+
+    /// Returns a child resource identifier, identical to this one but with `.<segment>` appended to the locator.
+    pub fn child(&self, segment: &str) -> Result<ResourceIdentifier, ParseError> {
+        Component::Locator.validate(segment)?;
+
+        Ok(ResourceIdentifier {
+            rid: format!("{}{SEPARATOR}{segment}", self.rid),
+            service_end: self.service_end,
+            instance_end: self.instance_end,
+            type_end: self.type_end,
+        })
+    }
+
+    /// Returns `true` if both resource identifiers have the same service and type components.
+    ///
+    /// Types are namespaced by service, so this determines if the identifiers refer to the same kind of resource.
+    #[inline]
+    pub fn is_same_type(&self, other: &ResourceIdentifier) -> bool {
+        self.service() == other.service() && self.type_() == other.type_()
+    }
+
+    /// Returns `true` if the resource identifier matches the pattern.
+    #[inline]
+    pub fn matches(&self, pattern: &Pattern) -> bool {
+        pattern.matches(self)
+    }
 }
 
 impl FromStr for ResourceIdentifier {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<ResourceIdentifier, ParseError> {
-        let captures = match PARSE_REGEX.captures(s) {
-            Some(captures) => captures,
-            None => return Err(ParseError(())),
-        };
+        let [service, instance, type_, locator] = split(s)?;
+
+        Component::Service.validate(service)?;
+        Component::Instance.validate(instance)?;
+        Component::Type.validate(type_)?;
+        Component::Locator.validate(locator)?;
+
+        let service_end = RID_CLASS.len() + SEPARATOR.len() + service.len();
+        let instance_end = service_end + SEPARATOR.len() + instance.len();
+        let type_end = instance_end + SEPARATOR.len() + type_.len();
 
         Ok(ResourceIdentifier {
             rid: s.to_string(),
-            service_end: captures.get(1).unwrap().end(),
-            instance_end: captures.get(2).unwrap().end(),
-            type_end: captures.get(3).unwrap().end(),
+            service_end,
+            instance_end,
+            type_end,
         })
     }
 }
@@ -261,13 +285,266 @@ impl Hash for ResourceIdentifier {
     }
 }
 
+/// Splits a resource identifier or pattern into its components, without validating them.
+fn split(s: &str) -> Result<[&str; 4], ParseError> {
+    let s = s
+        .strip_prefix(RID_CLASS)
+        .and_then(|s| s.strip_prefix(SEPARATOR))
+        .ok_or(ParseError {
+            component: None,
+            reason: Reason::Prefix,
+        })?;
+
+    let mut parts = s.splitn(4, SEPARATOR);
+    let mut components = [""; 4];
+    for (component, out) in Component::ALL.iter().zip(&mut components) {
+        *out = parts.next().ok_or(ParseError {
+            component: Some(*component),
+            reason: Reason::Missing,
+        })?;
+    }
+
+    Ok(components)
+}
+
+/// A builder for [`ResourceIdentifier`]s.
+pub struct Builder {
+    service: Option<String>,
+    instance: String,
+    type_: Option<String>,
+    locator: Option<String>,
+}
+
+impl Builder {
+    /// Sets the service component.
+    pub fn service(mut self, service: impl Into<String>) -> Self {
+        self.service = Some(service.into());
+        self
+    }
+
+    /// Sets the instance component.
+    pub fn instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = instance.into();
+        self
+    }
+
+    /// Sets the type component.
+    pub fn type_(mut self, type_: impl Into<String>) -> Self {
+        self.type_ = Some(type_.into());
+        self
+    }
+
+    /// Sets the locator component.
+    pub fn locator(mut self, locator: impl Into<String>) -> Self {
+        self.locator = Some(locator.into());
+        self
+    }
+
+    /// Builds the [`ResourceIdentifier`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error identifying the first component which is missing or invalid.
+    pub fn build(self) -> Result<ResourceIdentifier, ParseError> {
+        let missing = |component| ParseError {
+            component: Some(component),
+            reason: Reason::Missing,
+        };
+        let service = self.service.ok_or_else(|| missing(Component::Service))?;
+        let type_ = self.type_.ok_or_else(|| missing(Component::Type))?;
+        let locator = self.locator.ok_or_else(|| missing(Component::Locator))?;
+
+        Component::Service.validate(&service)?;
+        Component::Instance.validate(&self.instance)?;
+        Component::Type.validate(&type_)?;
+        Component::Locator.validate(&locator)?;
+
+        let service_end = RID_CLASS.len() + SEPARATOR.len() + service.len();
+        let instance_end = service_end + SEPARATOR.len() + self.instance.len();
+        let type_end = instance_end + SEPARATOR.len() + type_.len();
+
+        Ok(ResourceIdentifier {
+            rid: format!(
+                "{RID_CLASS}{SEPARATOR}{service}{SEPARATOR}{}{SEPARATOR}{type_}{SEPARATOR}{locator}",
+                self.instance
+            ),
+            service_end,
+            instance_end,
+            type_end,
+        })
+    }
+}
+
+/// A pattern matching resource identifiers, for use in authorization checks.
+///
+/// Patterns have the same structure as resource identifiers, but any component may be replaced with a `*` wildcard
+/// which matches any value of that component. For example, `ri.foo.*.dataset.*` matches every `dataset` resource of
+/// the `foo` service across all instances.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    pattern: String,
+    components: [Option<String>; 4],
+}
+
+impl Pattern {
+    /// Creates a pattern from a string.
+    ///
+    /// This function behaves identically to `Pattern`'s `FromStr` implementation.
+    #[inline]
+    pub fn new(s: &str) -> Result<Pattern, ParseError> {
+        s.parse()
+    }
+
+    /// Returns `true` if the resource identifier matches the pattern.
+    pub fn matches(&self, rid: &ResourceIdentifier) -> bool {
+        let values = [rid.service(), rid.instance(), rid.type_(), rid.locator()];
+
+        self.components
+            .iter()
+            .zip(values)
+            .all(|(pattern, value)| pattern.as_ref().is_none_or(|p| p == value))
+    }
+
+    /// Returns the string representation of the pattern.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Pattern, ParseError> {
+        let mut components = [None, None, None, None];
+        for ((component, value), out) in Component::ALL.iter().zip(split(s)?).zip(&mut components) {
+            if value != "*" {
+                component.validate(value)?;
+                *out = Some(value.to_string());
+            }
+        }
+
+        Ok(Pattern {
+            pattern: s.to_string(),
+            components,
+        })
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.pattern, fmt)
+    }
+}
+
+/// A component of a resource identifier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Component {
+    /// The service component.
+    Service,
+    /// The instance component.
+    Instance,
+    /// The type component.
+    Type,
+    /// The locator component.
+    Locator,
+}
+
+impl Component {
+    const ALL: [Component; 4] = [
+        Component::Service,
+        Component::Instance,
+        Component::Type,
+        Component::Locator,
+    ];
+
+    fn pattern(self) -> &'static str {
+        match self {
+            Component::Service | Component::Type => r"[a-z][a-z0-9\-]*",
+            Component::Instance => r"([a-z0-9][a-z0-9\-]*)?",
+            Component::Locator => r"[a-zA-Z0-9_\-\.]+",
+        }
+    }
+
+    fn validate(self, s: &str) -> Result<(), ParseError> {
+        let is_tail = |b: &u8| matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'-');
+
+        let valid = match self {
+            Component::Service | Component::Type => match s.as_bytes() {
+                [b'a'..=b'z', tail @ ..] => tail.iter().all(is_tail),
+                _ => false,
+            },
+            Component::Instance => match s.as_bytes() {
+                [] => true,
+                [b'a'..=b'z' | b'0'..=b'9', tail @ ..] => tail.iter().all(is_tail),
+                _ => false,
+            },
+            Component::Locator => !s.is_empty()
+                && s.bytes().all(
+                    |b| matches!(b, b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'-' | b'.'),
+                ),
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(ParseError {
+                component: Some(self),
+                reason: Reason::Invalid,
+            })
+        }
+    }
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Component::Service => "service",
+            Component::Instance => "instance",
+            Component::Type => "type",
+            Component::Locator => "locator",
+        };
+        fmt.write_str(s)
+    }
+}
+
+#[derive(Debug)]
+enum Reason {
+    Prefix,
+    Missing,
+    Invalid,
+}
+
 /// An error returned from parsing an invalid resource identifier.
 #[derive(Debug)]
-pub struct ParseError(());
+pub struct ParseError {
+    component: Option<Component>,
+    reason: Reason,
+}
+
+impl ParseError {
+    /// Returns the component which was missing or invalid, if the error was caused by a single component.
+    #[inline]
+    pub fn component(&self) -> Option<Component> {
+        self.component
+    }
+}
 
 impl fmt::Display for ParseError {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        fmt.write_str("invalid resource identifier")
+        fmt.write_str("invalid resource identifier")?;
+        match (&self.reason, self.component) {
+            (Reason::Prefix, _) | (_, None) => {
+                write!(fmt, ": must start with `{RID_CLASS}{SEPARATOR}`")
+            }
+            (Reason::Missing, Some(component)) => write!(fmt, ": missing {component} component"),
+            (Reason::Invalid, Some(component)) => write!(
+                fmt,
+                ": {component} component must match `{}`",
+                component.pattern()
+            ),
+        }
     }
 }
 
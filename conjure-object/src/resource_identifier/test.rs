@@ -11,7 +11,10 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use crate::resource_identifier::{Component, Pattern};
 use crate::ResourceIdentifier;
+use proptest::prelude::*;
+use regex::Regex;
 
 #[test]
 fn basic() {
@@ -37,3 +40,159 @@ fn no_multi_components() {
     assert!(ResourceIdentifier::from_components("a", "b.c", "d", "e").is_err());
     assert!(ResourceIdentifier::from_components("a", "b", "c.d", "e").is_err());
 }
+
+#[test]
+fn builder() {
+    let rid = ResourceIdentifier::builder()
+        .service("service")
+        .type_("type")
+        .locator("locator.locator")
+        .build()
+        .unwrap();
+    assert_eq!(rid.as_str(), "ri.service..type.locator.locator");
+    assert_eq!(rid.service(), "service");
+    assert_eq!(rid.instance(), "");
+    assert_eq!(rid.type_(), "type");
+    assert_eq!(rid.locator(), "locator.locator");
+}
+
+#[test]
+fn builder_errors() {
+    let err = ResourceIdentifier::builder()
+        .service("service")
+        .locator("locator")
+        .build()
+        .unwrap_err();
+    assert_eq!(err.component(), Some(Component::Type));
+    assert_eq!(
+        err.to_string(),
+        "invalid resource identifier: missing type component"
+    );
+
+    let err = ResourceIdentifier::builder()
+        .service("Service")
+        .type_("type")
+        .locator("locator")
+        .build()
+        .unwrap_err();
+    assert_eq!(err.component(), Some(Component::Service));
+    assert_eq!(
+        err.to_string(),
+        r"invalid resource identifier: service component must match `[a-z][a-z0-9\-]*`"
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let err = "rid.service..type.locator"
+        .parse::<ResourceIdentifier>()
+        .unwrap_err();
+    assert_eq!(err.component(), None);
+
+    let err = "ri.service..type"
+        .parse::<ResourceIdentifier>()
+        .unwrap_err();
+    assert_eq!(err.component(), Some(Component::Locator));
+
+    let err = "ri.service.-instance.type.locator"
+        .parse::<ResourceIdentifier>()
+        .unwrap_err();
+    assert_eq!(err.component(), Some(Component::Instance));
+
+    let err = "ri.service..type.loc/ator"
+        .parse::<ResourceIdentifier>()
+        .unwrap_err();
+    assert_eq!(err.component(), Some(Component::Locator));
+}
+
+#[test]
+fn child() {
+    let rid = ResourceIdentifier::new("ri.service.instance.type.locator").unwrap();
+    let child = rid.child("child").unwrap();
+    assert_eq!(child.as_str(), "ri.service.instance.type.locator.child");
+    assert_eq!(child.locator(), "locator.child");
+    assert!(child.is_same_type(&rid));
+    assert!(rid.child("").is_err());
+    assert!(rid.child("a/b").is_err());
+}
+
+#[test]
+fn same_type() {
+    let a = ResourceIdentifier::new("ri.service.a.type.locator").unwrap();
+    let b = ResourceIdentifier::new("ri.service.b.type.other").unwrap();
+    let c = ResourceIdentifier::new("ri.service.a.other.locator").unwrap();
+    assert!(a.is_same_type(&b));
+    assert!(!a.is_same_type(&c));
+}
+
+#[test]
+fn patterns() {
+    let pattern = Pattern::new("ri.foo.*.dataset.*").unwrap();
+    assert_eq!(pattern.to_string(), "ri.foo.*.dataset.*");
+
+    assert!(ResourceIdentifier::new("ri.foo..dataset.a")
+        .unwrap()
+        .matches(&pattern));
+    assert!(ResourceIdentifier::new("ri.foo.prod.dataset.a.b")
+        .unwrap()
+        .matches(&pattern));
+    assert!(!ResourceIdentifier::new("ri.bar..dataset.a")
+        .unwrap()
+        .matches(&pattern));
+    assert!(!ResourceIdentifier::new("ri.foo..folder.a")
+        .unwrap()
+        .matches(&pattern));
+
+    let pattern = Pattern::new("ri.foo..dataset.a").unwrap();
+    assert!(ResourceIdentifier::new("ri.foo..dataset.a")
+        .unwrap()
+        .matches(&pattern));
+    assert!(!ResourceIdentifier::new("ri.foo..dataset.ab")
+        .unwrap()
+        .matches(&pattern));
+
+    let err = Pattern::new("ri.foo.*.Dataset.*").unwrap_err();
+    assert_eq!(err.component(), Some(Component::Type));
+}
+
+// The regex used by the original parser implementation.
+const PARSE_REGEX: &str = r"(?x)
+    ^
+    ri
+    \.
+    ([a-z][a-z0-9\-]*) #service
+    \.
+    ((?:[a-z0-9][a-z0-9\-]*)?) #instance
+    \.
+    ([a-z][a-z0-9\-]*) #type
+    \.
+    ([a-zA-Z0-9_\-\.]+) #locator
+    $
+";
+
+fn near_rid() -> impl Strategy<Value = String> {
+    prop_oneof![
+        r"ri\.[a-z0-9\-A-Z_]{0,4}\.[a-z0-9\-A-Z_]{0,4}\.[a-z0-9\-A-Z_]{0,4}\.[a-zA-Z0-9_\-\./ ]{0,8}",
+        r"(ri)?[a-zA-Z0-9_\-\./]{0,20}\n?",
+        ".*",
+    ]
+}
+
+proptest! {
+    #[test]
+    fn matches_regex(s in near_rid()) {
+        let captures = Regex::new(PARSE_REGEX).unwrap().captures(&s);
+
+        match (s.parse::<ResourceIdentifier>(), captures) {
+            (Ok(rid), Some(captures)) => {
+                prop_assert_eq!(rid.as_str(), &s);
+                prop_assert_eq!(rid.service(), &captures[1]);
+                prop_assert_eq!(rid.instance(), &captures[2]);
+                prop_assert_eq!(rid.type_(), &captures[3]);
+                prop_assert_eq!(rid.locator(), &captures[4]);
+            }
+            (Err(_), None) => {}
+            (rid, captures) => prop_assert!(false, "mismatch: {:?} vs {:?}", rid, captures),
+        }
+    }
+}
@@ -57,6 +57,66 @@ impl SafeLong {
             Err(BoundsError(()))
         }
     }
+
+    /// Checked addition. Returns `None` if the result is out of range.
+    #[inline]
+    pub fn checked_add(self, rhs: SafeLong) -> Option<SafeLong> {
+        self.0
+            .checked_add(rhs.0)
+            .and_then(|n| SafeLong::new(n).ok())
+    }
+
+    /// Checked subtraction. Returns `None` if the result is out of range.
+    #[inline]
+    pub fn checked_sub(self, rhs: SafeLong) -> Option<SafeLong> {
+        self.0
+            .checked_sub(rhs.0)
+            .and_then(|n| SafeLong::new(n).ok())
+    }
+
+    /// Checked multiplication. Returns `None` if the result is out of range.
+    #[inline]
+    pub fn checked_mul(self, rhs: SafeLong) -> Option<SafeLong> {
+        self.0
+            .checked_mul(rhs.0)
+            .and_then(|n| SafeLong::new(n).ok())
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    #[inline]
+    pub fn checked_div(self, rhs: SafeLong) -> Option<SafeLong> {
+        // the quotient of two safe longs is always in range
+        self.0.checked_div(rhs.0).map(SafeLong)
+    }
+
+    /// Checked negation. This never fails since the valid range is symmetric, but is provided for consistency.
+    #[inline]
+    pub fn checked_neg(self) -> Option<SafeLong> {
+        Some(SafeLong(-self.0))
+    }
+
+    /// Saturating addition. Clamps the result to the valid range.
+    #[inline]
+    pub fn saturating_add(self, rhs: SafeLong) -> SafeLong {
+        // the sum of two safe longs can't overflow an i64
+        SafeLong::clamp(self.0 + rhs.0)
+    }
+
+    /// Saturating subtraction. Clamps the result to the valid range.
+    #[inline]
+    pub fn saturating_sub(self, rhs: SafeLong) -> SafeLong {
+        SafeLong::clamp(self.0 - rhs.0)
+    }
+
+    /// Saturating multiplication. Clamps the result to the valid range.
+    #[inline]
+    pub fn saturating_mul(self, rhs: SafeLong) -> SafeLong {
+        SafeLong::clamp(self.0.saturating_mul(rhs.0))
+    }
+
+    fn clamp(value: i64) -> SafeLong {
+        SafeLong(value.clamp(*SafeLong::min_value(), *SafeLong::max_value()))
+    }
 }
 
 impl Deref for SafeLong {
@@ -107,6 +167,69 @@ impl<'de> de::Deserialize<'de> for SafeLong {
     }
 }
 
+/// Serde support for `SafeLong` values which may be encoded as strings.
+///
+/// The Conjure specification requires safe longs to be encoded as JSON numbers, but some legacy clients send them as
+/// strings instead. This module can be used with `#[serde(with = "conjure_object::safe_long::lenient")]` to accept
+/// both forms. Values are always serialized as numbers.
+///
+/// Code generated by `conjure-codegen` never uses this module, so it only applies to hand-written types. It also only
+/// supports fields of type `SafeLong` itself - optional and collection fields are not supported.
+pub mod lenient {
+    use super::SafeLongVisitor;
+    use crate::SafeLong;
+    use serde::{Deserializer, Serialize, Serializer};
+
+    /// Serializes a `SafeLong` as a number.
+    pub fn serialize<S>(value: &SafeLong, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(s)
+    }
+
+    /// Deserializes a `SafeLong` from either a number or a string.
+    pub fn deserialize<'de, D>(d: D) -> Result<SafeLong, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(SafeLongVisitor)
+    }
+}
+
+struct SafeLongVisitor;
+
+impl de::Visitor<'_> for SafeLongVisitor {
+    type Value = SafeLong;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("a safe long")
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<SafeLong, E>
+    where
+        E: de::Error,
+    {
+        SafeLong::new(v).map_err(|_| de::Error::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<SafeLong, E>
+    where
+        E: de::Error,
+    {
+        SafeLong::try_from(v)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<SafeLong, E>
+    where
+        E: de::Error,
+    {
+        v.parse()
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
 macro_rules! impl_from {
     ($($t:ty),*) => {
         $(
@@ -173,6 +296,96 @@ macro_rules! impl_try_into {
 
 impl_try_into!(u8, i8, u16, i16, u32, i32, u64, u128, usize, isize);
 
+impl TryFrom<f64> for SafeLong {
+    type Error = TryFromFloatError;
+
+    /// Converts an integral `f64` into a `SafeLong`.
+    ///
+    /// Returns an error if the value is not finite, has a fractional component, or is out of range.
+    #[inline]
+    fn try_from(n: f64) -> Result<SafeLong, TryFromFloatError> {
+        if n.fract() == 0.
+            && n >= *SafeLong::min_value() as f64
+            && n <= *SafeLong::max_value() as f64
+        {
+            Ok(SafeLong(n as i64))
+        } else {
+            Err(TryFromFloatError(()))
+        }
+    }
+}
+
+impl From<SafeLong> for f64 {
+    /// Every `SafeLong` is exactly representable as an `f64`.
+    #[inline]
+    fn from(n: SafeLong) -> f64 {
+        n.0 as f64
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Bounded for SafeLong {
+    #[inline]
+    fn min_value() -> SafeLong {
+        SafeLong::min_value()
+    }
+
+    #[inline]
+    fn max_value() -> SafeLong {
+        SafeLong::max_value()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::ToPrimitive for SafeLong {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        Some(self.0)
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        num_traits::ToPrimitive::to_u64(&self.0)
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::FromPrimitive for SafeLong {
+    #[inline]
+    fn from_i64(n: i64) -> Option<SafeLong> {
+        SafeLong::new(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<SafeLong> {
+        SafeLong::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<SafeLong> {
+        SafeLong::try_from(n).ok()
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::NumCast for SafeLong {
+    #[inline]
+    fn from<T>(n: T) -> Option<SafeLong>
+    where
+        T: num_traits::ToPrimitive,
+    {
+        match n.to_i64() {
+            Some(n) => SafeLong::new(n).ok(),
+            None => n.to_f64().and_then(|n| SafeLong::try_from(n).ok()),
+        }
+    }
+}
+
 /// The error returned from constructing an out-of bounds `SafeLong`.
 #[derive(Debug, Clone)]
 pub struct BoundsError(());
@@ -185,6 +398,18 @@ impl fmt::Display for BoundsError {
 
 impl Error for BoundsError {}
 
+/// The error returned from converting a non-integral or out-of-bounds `f64` into a `SafeLong`.
+#[derive(Debug, Clone)]
+pub struct TryFromFloatError(());
+
+impl fmt::Display for TryFromFloatError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("value was not an integer within the bounds of a safe long")
+    }
+}
+
+impl Error for TryFromFloatError {}
+
 #[derive(Debug, Clone)]
 enum ParseErrorInner {
     Parse(ParseIntError),
@@ -205,3 +430,90 @@ impl fmt::Display for ParseError {
 }
 
 impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn safe_long(n: i64) -> SafeLong {
+        SafeLong::new(n).unwrap()
+    }
+
+    #[test]
+    fn checked_ops() {
+        let max = SafeLong::max_value();
+        let min = SafeLong::min_value();
+
+        assert_eq!(safe_long(1).checked_add(safe_long(2)), Some(safe_long(3)));
+        assert_eq!(max.checked_add(safe_long(1)), None);
+        assert_eq!(min.checked_sub(safe_long(1)), None);
+        assert_eq!(max.checked_mul(safe_long(2)), None);
+        assert_eq!(max.checked_mul(max), None);
+        assert_eq!(
+            safe_long(-3).checked_mul(safe_long(4)),
+            Some(safe_long(-12))
+        );
+        assert_eq!(safe_long(7).checked_div(safe_long(2)), Some(safe_long(3)));
+        assert_eq!(safe_long(7).checked_div(safe_long(0)), None);
+        assert_eq!(min.checked_neg(), Some(max));
+    }
+
+    #[test]
+    fn saturating_ops() {
+        let max = SafeLong::max_value();
+        let min = SafeLong::min_value();
+
+        assert_eq!(safe_long(1).saturating_add(safe_long(2)), safe_long(3));
+        assert_eq!(max.saturating_add(max), max);
+        assert_eq!(min.saturating_sub(max), min);
+        assert_eq!(max.saturating_mul(min), min);
+        assert_eq!(min.saturating_mul(min), max);
+    }
+
+    #[test]
+    fn try_from_f64() {
+        assert_eq!(SafeLong::try_from(3.).unwrap(), safe_long(3));
+        assert_eq!(
+            SafeLong::try_from(-9007199254740991.).unwrap(),
+            SafeLong::min_value()
+        );
+        assert!(SafeLong::try_from(9007199254740992.).is_err());
+        assert!(SafeLong::try_from(1.5).is_err());
+        assert!(SafeLong::try_from(f64::NAN).is_err());
+        assert!(SafeLong::try_from(f64::INFINITY).is_err());
+        assert_eq!(f64::from(SafeLong::max_value()), 9007199254740991.);
+    }
+
+    #[test]
+    fn lenient() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Lenient(#[serde(with = "lenient")] SafeLong);
+
+        let de = |s| serde_json::from_str::<Lenient>(s).map(|v| v.0);
+
+        assert_eq!(de("-12").unwrap(), safe_long(-12));
+        assert_eq!(de("\"-12\"").unwrap(), safe_long(-12));
+        de("9007199254740992").unwrap_err();
+        de("\"9007199254740992\"").unwrap_err();
+        de("\"1.0\"").unwrap_err();
+        de("1.0").unwrap_err();
+        serde_json::from_str::<SafeLong>("\"1\"").unwrap_err();
+
+        let json = serde_json::to_string(&Lenient(safe_long(5))).unwrap();
+        assert_eq!(json, "5");
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn num_traits() {
+        use num_traits::{Bounded, FromPrimitive, NumCast, ToPrimitive};
+
+        assert_eq!(<SafeLong as Bounded>::max_value(), SafeLong::max_value());
+        assert_eq!(SafeLong::from_u64(5), Some(safe_long(5)));
+        assert_eq!(SafeLong::from_u64(u64::MAX), None);
+        assert_eq!(SafeLong::from_f64(2.5), None);
+        assert_eq!(safe_long(-1).to_u64(), None);
+        assert_eq!(<SafeLong as NumCast>::from(5u8), Some(safe_long(5)));
+        assert_eq!(<SafeLong as NumCast>::from(i64::MAX), None);
+    }
+}
@@ -0,0 +1,502 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Support for `conjure-rust.toml` configuration files.
+use anyhow::{bail, Context, Error};
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The contents of a configuration file.
+///
+/// Settings at the top level apply to every input, and relative paths are resolved against the directory containing
+/// the file.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    #[serde(default)]
+    exhaustive: bool,
+    #[serde(default)]
+    serialize_empty_collections: bool,
+    #[serde(default = "default_true")]
+    use_legacy_error_serialization: bool,
+    #[serde(default)]
+    public_fields: bool,
+    #[serde(default)]
+    arbitrary: bool,
+    #[serde(default)]
+    patches: bool,
+    #[serde(default)]
+    diff: bool,
+    #[serde(default)]
+    redacted_debug: bool,
+    #[serde(default)]
+    field_masks: bool,
+    #[serde(default)]
+    map_type: MapType,
+    #[serde(default)]
+    set_type: SetType,
+    #[serde(default)]
+    string_type: StringType,
+    extra_manifest: Option<toml::Table>,
+    #[serde(default)]
+    packages: BTreeMap<String, Overrides>,
+    #[serde(default)]
+    types: BTreeMap<String, Overrides>,
+    #[serde(default)]
+    external_types: BTreeMap<String, String>,
+    #[serde(default)]
+    external_crates: BTreeMap<String, toml::Value>,
+    inputs: Vec<Input>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Overrides {
+    exhaustive: Option<bool>,
+    serialize_empty_collections: Option<bool>,
+    public_fields: Option<bool>,
+}
+
+impl Overrides {
+    fn to_codegen(&self) -> conjure_codegen::Overrides {
+        let mut overrides = conjure_codegen::Overrides::new();
+        if let Some(exhaustive) = self.exhaustive {
+            overrides.exhaustive(exhaustive);
+        }
+        if let Some(serialize_empty_collections) = self.serialize_empty_collections {
+            overrides.serialize_empty_collections(serialize_empty_collections);
+        }
+        if let Some(public_fields) = self.public_fields {
+            overrides.public_fields(public_fields);
+        }
+        overrides
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Input {
+    ir: Ir,
+    output: PathBuf,
+    strip_prefix: Option<String>,
+    product_name: Option<String>,
+    product_version: Option<String>,
+    crate_version: Option<String>,
+    #[serde(default)]
+    crate_per_package: bool,
+    extra_manifest: Option<toml::Table>,
+}
+
+/// One or more IR files, which are merged when there are several.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Ir {
+    Single(PathBuf),
+    Merged(Vec<PathBuf>),
+}
+
+impl Ir {
+    fn paths_mut(&mut self) -> &mut [PathBuf] {
+        match self {
+            Ir::Single(path) => std::slice::from_mut(path),
+            Ir::Merged(paths) => paths,
+        }
+    }
+
+    fn paths(&self) -> &[PathBuf] {
+        match self {
+            Ir::Single(path) => std::slice::from_ref(path),
+            Ir::Merged(paths) => paths,
+        }
+    }
+}
+
+impl fmt::Display for Ir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, path) in self.paths().iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            fmt::Display::fmt(&path.display(), f)?;
+        }
+        Ok(())
+    }
+}
+
+/// The Rust representation of Conjure `map`s.
+#[derive(Deserialize, ValueEnum, Copy, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+#[allow(clippy::enum_variant_names)]
+pub enum MapType {
+    #[default]
+    BTreeMap,
+    HashMap,
+    IndexMap,
+}
+
+impl From<MapType> for conjure_codegen::MapType {
+    fn from(map_type: MapType) -> Self {
+        match map_type {
+            MapType::BTreeMap => conjure_codegen::MapType::BTreeMap,
+            MapType::HashMap => conjure_codegen::MapType::HashMap,
+            MapType::IndexMap => conjure_codegen::MapType::IndexMap,
+        }
+    }
+}
+
+/// The Rust representation of Conjure `set`s.
+#[derive(Deserialize, ValueEnum, Copy, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+#[allow(clippy::enum_variant_names)]
+pub enum SetType {
+    #[default]
+    BTreeSet,
+    HashSet,
+    IndexSet,
+}
+
+impl From<SetType> for conjure_codegen::SetType {
+    fn from(set_type: SetType) -> Self {
+        match set_type {
+            SetType::BTreeSet => conjure_codegen::SetType::BTreeSet,
+            SetType::HashSet => conjure_codegen::SetType::HashSet,
+            SetType::IndexSet => conjure_codegen::SetType::IndexSet,
+        }
+    }
+}
+
+/// The Rust representation of Conjure `string`s.
+#[derive(Deserialize, ValueEnum, Copy, Clone, Default)]
+#[serde(rename_all = "lowercase")]
+#[value(rename_all = "lower")]
+pub enum StringType {
+    #[default]
+    String,
+    ArcStr,
+}
+
+impl From<StringType> for conjure_codegen::StringType {
+    fn from(string_type: StringType) -> Self {
+        match string_type {
+            StringType::String => conjure_codegen::StringType::String,
+            StringType::ArcStr => conjure_codegen::StringType::ArcStr,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl ConfigFile {
+    /// Reads a configuration file.
+    pub fn read(path: &Path) -> Result<ConfigFile, Error> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("error reading config file {}", path.display()))?;
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        ConfigFile::parse(&contents, base)
+            .with_context(|| format!("error parsing config file {}", path.display()))
+    }
+
+    fn parse(contents: &str, base: &Path) -> Result<ConfigFile, Error> {
+        let mut config = toml::from_str::<ConfigFile>(contents)?;
+
+        for input in &mut config.inputs {
+            if input.ir.paths().is_empty() {
+                bail!("no IR files listed for input {}", input.output.display());
+            }
+            if input.product_name.is_some() != input.product_version.is_some() {
+                bail!(
+                    "product_name and product_version must be set together for input {}",
+                    input.ir,
+                );
+            }
+            if input.product_name.is_none() {
+                if input.crate_version.is_some() {
+                    bail!("crate_version requires product_name for input {}", input.ir);
+                }
+                if input.crate_per_package {
+                    bail!(
+                        "crate_per_package requires product_name for input {}",
+                        input.ir,
+                    );
+                }
+            }
+            for ir in input.ir.paths_mut() {
+                *ir = base.join(&*ir);
+            }
+            input.output = base.join(&input.output);
+        }
+
+        Ok(config)
+    }
+
+    /// Generates code for each input in the configuration file.
+    pub fn generate(&self) -> Result<(), Error> {
+        for input in &self.inputs {
+            self.config(input)
+                .generate_merged_files(input.ir.paths(), &input.output)
+                .with_context(|| format!("error generating code for {}", input.ir))?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the generated code for each input in the configuration file is up to date.
+    pub fn verify(&self) -> Result<(), Error> {
+        for input in &self.inputs {
+            self.config(input)
+                .verify_merged_files(input.ir.paths(), &input.output)
+                .with_context(|| format!("error verifying code for {}", input.ir))?;
+        }
+
+        Ok(())
+    }
+
+    fn config(&self, input: &Input) -> conjure_codegen::Config {
+        let mut config = conjure_codegen::Config::new();
+        config
+            .exhaustive(self.exhaustive)
+            .serialize_empty_collections(self.serialize_empty_collections)
+            .use_legacy_error_serialization(self.use_legacy_error_serialization)
+            .public_fields(self.public_fields)
+            .arbitrary(self.arbitrary)
+            .patches(self.patches)
+            .diff(self.diff)
+            .redacted_debug(self.redacted_debug)
+            .field_masks(self.field_masks)
+            .map_type(self.map_type.into())
+            .set_type(self.set_type.into())
+            .string_type(self.string_type.into())
+            .strip_prefix(input.strip_prefix.clone());
+
+        for (package, overrides) in &self.packages {
+            config.package_overrides(package, overrides.to_codegen());
+        }
+        for (type_name, overrides) in &self.types {
+            config.type_overrides(type_name, overrides.to_codegen());
+        }
+        for (conjure_type, rust_type) in &self.external_types {
+            config.external_type(conjure_type, rust_type);
+        }
+        for (name, dependency) in &self.external_crates {
+            config.external_crate(name, dependency.clone());
+        }
+
+        let crate_version = input
+            .crate_version
+            .as_deref()
+            .or(input.product_version.as_deref());
+        if let (Some(product_name), Some(crate_version)) = (&input.product_name, crate_version) {
+            config.build_crate(product_name, crate_version);
+        }
+        config.crate_per_package(input.crate_per_package);
+        if let Some(extra_manifest) = input
+            .extra_manifest
+            .as_ref()
+            .or(self.extra_manifest.as_ref())
+        {
+            config.extra_manifest_config(extra_manifest.clone());
+        }
+        config.version(input.product_version.clone());
+
+        config
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(contents: &str) -> Result<ConfigFile, Error> {
+        ConfigFile::parse(contents, Path::new("base"))
+    }
+
+    #[test]
+    fn defaults() {
+        let config = parse(
+            r#"
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            "#,
+        )
+        .unwrap();
+
+        assert!(!config.exhaustive);
+        assert!(config.use_legacy_error_serialization);
+        assert!(config.packages.is_empty());
+        assert!(config.types.is_empty());
+        assert_eq!(config.inputs.len(), 1);
+        assert!(!config.inputs[0].crate_per_package);
+    }
+
+    #[test]
+    fn relative_paths() {
+        let config = parse(
+            r#"
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+
+            [[inputs]]
+            ir = ["a.conjure.json", "/abs/b.conjure.json"]
+            output = "/abs/out"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.inputs[0].ir.paths(),
+            [Path::new("base/api.conjure.json")],
+        );
+        assert_eq!(config.inputs[0].output, Path::new("base/src/api"));
+        assert_eq!(
+            config.inputs[1].ir.paths(),
+            [
+                Path::new("base/a.conjure.json"),
+                Path::new("/abs/b.conjure.json"),
+            ],
+        );
+        assert_eq!(config.inputs[1].output, Path::new("/abs/out"));
+    }
+
+    #[test]
+    fn overrides() {
+        let config = parse(
+            r#"
+            exhaustive = true
+
+            [packages."com.palantir.foo"]
+            exhaustive = false
+
+            [types."com.palantir.foo.Bar"]
+            public_fields = true
+
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.exhaustive);
+        assert_eq!(config.packages["com.palantir.foo"].exhaustive, Some(false));
+        assert_eq!(
+            config.types["com.palantir.foo.Bar"].public_fields,
+            Some(true)
+        );
+        assert_eq!(config.types["com.palantir.foo.Bar"].exhaustive, None);
+    }
+
+    #[test]
+    fn unknown_fields() {
+        parse(
+            r#"
+            exhaustve = true
+
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            "#,
+        )
+        .err()
+        .unwrap();
+    }
+
+    #[test]
+    fn empty_ir() {
+        let err = parse(
+            r#"
+            [[inputs]]
+            ir = []
+            output = "src/api"
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(err.to_string(), "no IR files listed for input src/api");
+    }
+
+    #[test]
+    fn unpaired_product() {
+        let err = parse(
+            r#"
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            product_name = "api"
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "product_name and product_version must be set together for input api.conjure.json",
+        );
+    }
+
+    #[test]
+    fn crate_settings_require_product_name() {
+        let err = parse(
+            r#"
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            crate_version = "1.0.0"
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "crate_version requires product_name for input api.conjure.json",
+        );
+
+        let err = parse(
+            r#"
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            crate_per_package = true
+            "#,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "crate_per_package requires product_name for input api.conjure.json",
+        );
+
+        let config = parse(
+            r#"
+            [[inputs]]
+            ir = "api.conjure.json"
+            output = "src/api"
+            product_name = "api"
+            product_version = "1.0.0"
+            crate_version = "2.0.0"
+            crate_per_package = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.inputs[0].crate_version.as_deref(), Some("2.0.0"));
+        assert!(config.inputs[0].crate_per_package);
+    }
+}
@@ -13,10 +13,13 @@
 // limitations under the License.
 #![warn(clippy::all)]
 
+use crate::config::{ConfigFile, MapType, SetType, StringType};
 use clap::{ArgAction, Parser};
 use std::path::PathBuf;
 use std::process;
 
+mod config;
+
 #[derive(Parser)]
 #[clap(rename_all = "camelCase")]
 enum Opts {
@@ -28,6 +31,43 @@ enum Opts {
 #[derive(Parser)]
 #[clap(rename_all = "camelCase")]
 struct Args {
+    /// Path to a TOML configuration file. Replaces all other arguments except `--verify`
+    #[clap(
+        long,
+        value_name = "path",
+        conflicts_with_all = [
+            "exhaustive",
+            "serialize_empty_collections",
+            "use_legacy_error_serialization",
+            "public_fields",
+            "arbitrary",
+            "patches",
+            "diff",
+            "redacted_debug",
+            "field_masks",
+            "map_type",
+            "set_type",
+            "string_type",
+            "strip_prefix",
+            "product_name",
+            "product_version",
+            "crate_version",
+            "crate_per_package",
+            "extra_manifest",
+            "merge",
+            "yaml",
+        ],
+    )]
+    config: Option<PathBuf>,
+    /// Check that the generated code is up to date instead of writing it
+    #[clap(long)]
+    verify: bool,
+    /// An additional input to merge with the first into a single module tree. May be repeated
+    #[clap(long, value_name = "path")]
+    merge: Vec<PathBuf>,
+    /// Read Conjure YAML definitions from a file or directory instead of a JSON IR file
+    #[clap(long)]
+    yaml: bool,
     /// Generate exhaustively matchable enums and unions
     #[clap(
         long,
@@ -68,6 +108,65 @@ struct Args {
         action = ArgAction::Set,
     )]
     public_fields: bool,
+    /// Generate proptest `Arbitrary` implementations for types
+    #[clap(
+        long,
+        default_missing_value = "true",
+        default_value = "false",
+        num_args = 0..=1,
+        require_equals = true,
+        action = ArgAction::Set,
+    )]
+    arbitrary: bool,
+    /// Generate partial-update `Patch` types for objects
+    #[clap(
+        long,
+        default_missing_value = "true",
+        default_value = "false",
+        num_args = 0..=1,
+        require_equals = true,
+        action = ArgAction::Set,
+    )]
+    patches: bool,
+    /// Generate structural `Diff` implementations for types
+    #[clap(
+        long,
+        default_missing_value = "true",
+        default_value = "false",
+        num_args = 0..=1,
+        require_equals = true,
+        action = ArgAction::Set,
+    )]
+    diff: bool,
+    /// Redact unsafe values in the `Debug` output of types
+    #[clap(
+        long,
+        default_missing_value = "true",
+        default_value = "false",
+        num_args = 0..=1,
+        require_equals = true,
+        action = ArgAction::Set,
+    )]
+    redacted_debug: bool,
+    /// Generate field masks for types and honor the `fields` query parameter in servers
+    #[clap(
+        long,
+        default_missing_value = "true",
+        default_value = "false",
+        num_args = 0..=1,
+        require_equals = true,
+        action = ArgAction::Set,
+    )]
+    field_masks: bool,
+    /// The Rust type used for maps
+    #[clap(long, value_name = "type", value_enum, default_value_t = MapType::BTreeMap)]
+    map_type: MapType,
+    /// The Rust type used for sets
+    #[clap(long, value_name = "type", value_enum, default_value_t = SetType::BTreeSet)]
+    set_type: SetType,
+    /// The Rust type used for strings
+    #[clap(long, value_name = "type", value_enum, default_value_t = StringType::String)]
+    string_type: StringType,
     /// Strip a prefix from types's package paths
     #[clap(long, value_name = "prefix")]
     strip_prefix: Option<String>,
@@ -80,10 +179,23 @@ struct Args {
     /// The version of the generated crate. Defaults to `--productVersion`
     #[clap(long, value_name = "version", requires = "product_version")]
     crate_version: Option<String>,
-    /// Path to a JSON-formatted Conjure IR file
-    input_json: PathBuf,
+    /// Generate a Cargo workspace with one crate per top-level package
+    #[clap(
+        long,
+        default_missing_value = "true",
+        default_value = "false",
+        num_args = 0..=1,
+        require_equals = true,
+        action = ArgAction::Set,
+        requires = "product_name",
+    )]
+    crate_per_package: bool,
+    /// Path to a JSON-formatted Conjure IR file, or Conjure YAML definitions with `--yaml`
+    #[clap(required_unless_present = "config", conflicts_with = "config")]
+    input_json: Option<PathBuf>,
     /// Directory to place generated code
-    output_directory: PathBuf,
+    #[clap(required_unless_present = "config", conflicts_with = "config")]
+    output_directory: Option<PathBuf>,
     #[clap(
         long = "extraManifestJson",
         value_name = "json",
@@ -113,12 +225,38 @@ fn parse_extra_manifest_json(s: &str) -> Result<toml::Table, String> {
 fn main() {
     let Opts::Generate(args) = Opts::parse();
 
+    let r = match &args.config {
+        Some(path) => ConfigFile::read(path).and_then(|config| {
+            if args.verify {
+                config.verify()
+            } else {
+                config.generate()
+            }
+        }),
+        None => generate(args),
+    };
+
+    if let Err(e) = r {
+        eprintln!("{e:?}");
+        process::exit(1);
+    }
+}
+
+fn generate(args: Args) -> Result<(), anyhow::Error> {
     let mut config = conjure_codegen::Config::new();
     config
         .exhaustive(args.exhaustive)
         .serialize_empty_collections(args.serialize_empty_collections)
         .use_legacy_error_serialization(args.use_legacy_error_serialization)
-        .public_fields(args.public_fields);
+        .public_fields(args.public_fields)
+        .arbitrary(args.arbitrary)
+        .patches(args.patches)
+        .diff(args.diff)
+        .redacted_debug(args.redacted_debug)
+        .field_masks(args.field_masks)
+        .map_type(args.map_type.into())
+        .set_type(args.set_type.into())
+        .string_type(args.string_type.into());
     if let Some(prefix) = args.strip_prefix {
         config.strip_prefix(prefix);
     }
@@ -129,16 +267,34 @@ fn main() {
     if let (Some(product_name), Some(crate_version)) = (args.product_name, crate_version) {
         config.build_crate(&product_name, crate_version);
     }
+    config.crate_per_package(args.crate_per_package);
     if let Some(extra_manifest_config) = args.extra_manifest {
         config.extra_manifest_config(extra_manifest_config);
     }
     if let Some(product_version) = args.product_version {
         config.version(product_version);
     }
-    let r = config.generate_files(&args.input_json, &args.output_directory);
-
-    if let Err(e) = r {
-        eprintln!("{e:?}");
-        process::exit(1);
+    // clap ensures these are present when no config file is provided
+    let output_directory = args.output_directory.unwrap();
+    let inputs = [args.input_json.unwrap()]
+        .into_iter()
+        .chain(args.merge)
+        .collect::<Vec<_>>();
+    if args.yaml {
+        let inputs = inputs
+            .iter()
+            .map(|path| Ok((path.display(), conjure_codegen::yaml::compile(path)?)))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        let defs = conjure_codegen::merge::merge(inputs)?;
+        let generated = config.generate(&defs)?;
+        if args.verify {
+            generated.verify(output_directory)
+        } else {
+            generated.write_to(output_directory)
+        }
+    } else if args.verify {
+        config.verify_merged_files(&inputs, output_directory)
+    } else {
+        config.generate_merged_files(&inputs, output_directory)
     }
 }
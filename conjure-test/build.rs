@@ -8,6 +8,11 @@ fn main() {
     let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure");
     conjure_codegen::Config::new()
         .strip_prefix("com.palantir.conjure".to_string())
+        .arbitrary(true)
+        .patches(true)
+        .diff(true)
+        .redacted_debug(true)
+        .field_masks(true)
         .generate_files(input, output)
         .unwrap();
 
@@ -16,6 +21,68 @@ fn main() {
         .strip_prefix("com.palantir.conjure".to_string())
         .exhaustive(true)
         .public_fields(true)
+        .diff(true)
+        .generate_files(input, output)
+        .unwrap();
+
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure-overrides");
+    let mut overrides = conjure_codegen::Overrides::new();
+    overrides.exhaustive(true).public_fields(true);
+    let mut type_overrides = conjure_codegen::Overrides::new();
+    type_overrides
+        .exhaustive(false)
+        .serialize_empty_collections(true);
+    conjure_codegen::Config::new()
+        .strip_prefix("com.palantir.conjure".to_string())
+        .field_masks(true)
+        .package_overrides("com.palantir.conjure", overrides)
+        .type_overrides("com.palantir.conjure.TestEnum", type_overrides.clone())
+        .type_overrides("com.palantir.conjure.MixedFields", type_overrides)
+        .generate_files(input, output)
+        .unwrap();
+
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure-external");
+    conjure_codegen::Config::new()
+        .strip_prefix("com.palantir.conjure".to_string())
+        .external_type(
+            "com.palantir.conjure.TestObject",
+            "crate::types::objects::TestObject",
+        )
+        .external_type(
+            "com.palantir.test.ExternalSecret",
+            "crate::types::objects::UnsafeStringAlias",
+        )
+        .generate_files(input, output)
+        .unwrap();
+
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure-hash");
+    conjure_codegen::Config::new()
+        .strip_prefix("com.palantir.conjure".to_string())
+        .map_type(conjure_codegen::MapType::HashMap)
+        .set_type(conjure_codegen::SetType::HashSet)
+        .string_type(conjure_codegen::StringType::ArcStr)
+        .arbitrary(true)
+        .patches(true)
+        .diff(true)
+        .field_masks(true)
+        .generate_files(input, output)
+        .unwrap();
+
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("conjure-index");
+    conjure_codegen::Config::new()
+        .strip_prefix("com.palantir.conjure".to_string())
+        .map_type(conjure_codegen::MapType::IndexMap)
+        .set_type(conjure_codegen::SetType::IndexSet)
+        .public_fields(true)
+        .generate_files(input, output)
+        .unwrap();
+
+    let input = "verification/verification-api.json";
+    println!("cargo:rerun-if-changed={input}");
+
+    let output = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("verification");
+    conjure_codegen::Config::new()
+        .strip_prefix("com.palantir.conjure.verification".to_string())
         .generate_files(input, output)
         .unwrap();
 }
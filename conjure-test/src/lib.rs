@@ -25,3 +25,28 @@ pub mod types {
 pub mod exhaustive_types {
     include!(concat!(env!("OUT_DIR"), "/conjure-exhaustive/mod.rs"));
 }
+
+#[allow(dead_code, unused_imports, clippy::all)]
+pub mod overridden_types {
+    include!(concat!(env!("OUT_DIR"), "/conjure-overrides/mod.rs"));
+}
+
+#[allow(dead_code, unused_imports, clippy::all)]
+pub mod external_types {
+    include!(concat!(env!("OUT_DIR"), "/conjure-external/mod.rs"));
+}
+
+#[allow(dead_code, unused_imports, clippy::all)]
+pub mod hash_types {
+    include!(concat!(env!("OUT_DIR"), "/conjure-hash/mod.rs"));
+}
+
+#[allow(dead_code, unused_imports, clippy::all)]
+pub mod index_types {
+    include!(concat!(env!("OUT_DIR"), "/conjure-index/mod.rs"));
+}
+
+#[allow(dead_code, unused_imports, clippy::all)]
+pub mod verification_types {
+    include!(concat!(env!("OUT_DIR"), "/verification/mod.rs"));
+}
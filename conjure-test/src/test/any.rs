@@ -0,0 +1,120 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use conjure_object::{Any, Bytes};
+use serde_json::json;
+use std::collections::BTreeMap;
+
+#[test]
+fn json_value_matches_deserialization() {
+    let value = json!({
+        "null": null,
+        "bool": true,
+        "positive": 1,
+        "negative": -1,
+        "float": 1.5,
+        "nan": "NaN",
+        "list": [1, "a", {}],
+    });
+
+    let expected = conjure_serde::json::client_from_str::<Any>(&value.to_string()).unwrap();
+    assert_eq!(Any::from(value), expected);
+}
+
+#[test]
+fn any_to_json_value() {
+    let any = Any::new(BTreeMap::from([
+        ("nan", Any::new(f64::NAN).unwrap()),
+        ("infinity", Any::new(f64::INFINITY).unwrap()),
+        ("binary", Any::new(Bytes::from_static(b"foo")).unwrap()),
+        ("big", Any::new(1i128).unwrap()),
+        ("keys", Any::new(BTreeMap::from([(1, true)])).unwrap()),
+    ]))
+    .unwrap();
+
+    let value = serde_json::Value::try_from(any.clone()).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "nan": "NaN",
+            "infinity": "Infinity",
+            "binary": "Zm9v",
+            "big": 1,
+            "keys": {"1": true},
+        }),
+    );
+    assert_eq!(
+        value.to_string(),
+        conjure_serde::json::to_string(&any).unwrap()
+    );
+}
+
+#[test]
+fn any_to_json_value_errors() {
+    assert!(serde_json::Value::try_from(Any::new(u128::MAX).unwrap()).is_err());
+
+    let any = Any::new(BTreeMap::from([(vec![1], 1)])).unwrap();
+    assert!(serde_json::Value::try_from(any).is_err());
+}
+
+#[test]
+fn cbor_round_trip() {
+    let any = Any::new(BTreeMap::from([
+        ("nan", Any::new(f64::NAN).unwrap()),
+        ("binary", Any::new(Bytes::from_static(b"foo")).unwrap()),
+        ("negative", Any::new(-1i64).unwrap()),
+        ("keys", Any::new(BTreeMap::from([(1u64, true)])).unwrap()),
+    ]))
+    .unwrap();
+
+    let value = serde_cbor_2::Value::try_from(any.clone()).unwrap();
+    assert_eq!(Any::try_from(value.clone()).unwrap(), any);
+
+    let encoded = conjure_serde::cbor::to_vec(&any).unwrap();
+    assert_eq!(
+        serde_cbor_2::from_slice::<serde_cbor_2::Value>(&encoded).unwrap(),
+        value
+    );
+    assert_eq!(
+        Any::try_from(serde_cbor_2::Value::Tag(0, Box::new(value))).unwrap(),
+        any
+    );
+}
+
+#[test]
+fn smile_round_trip() {
+    use serde_smile::value::{BigInteger, Value};
+
+    let any = Any::new(BTreeMap::from([
+        ("int", Any::new(1i32).unwrap()),
+        ("long", Any::new(i64::MAX).unwrap()),
+        ("big", Any::new(i128::MIN).unwrap()),
+        ("binary", Any::new(Bytes::from_static(b"foo")).unwrap()),
+    ]))
+    .unwrap();
+
+    let value = Value::try_from(any.clone()).unwrap();
+    assert_eq!(Any::try_from(value).unwrap(), any);
+
+    assert_eq!(
+        Any::try_from(Value::BigInteger(BigInteger::from_be_bytes(vec![0xff]))).unwrap(),
+        Any::new(-1i128).unwrap(),
+    );
+    assert!(Any::try_from(Value::BigInteger(BigInteger::from_be_bytes(vec![1; 17]))).is_err());
+    assert_eq!(
+        Value::try_from(Any::new(u128::MAX).unwrap()).unwrap(),
+        Value::BigInteger(BigInteger::from_be_bytes(
+            [0].iter().chain(&[0xff; 16]).copied().collect()
+        )),
+    );
+}
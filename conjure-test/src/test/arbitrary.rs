@@ -0,0 +1,96 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::types::objects::*;
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn round_trip<T>(value: &T)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = conjure_serde::json::to_vec(value).unwrap();
+    assert_eq!(
+        *value,
+        conjure_serde::json::client_from_slice::<T>(&json).unwrap()
+    );
+    assert_eq!(
+        *value,
+        conjure_serde::json::server_from_slice::<T>(&json).unwrap()
+    );
+
+    let smile = conjure_serde::smile::to_vec(value).unwrap();
+    assert_eq!(
+        *value,
+        conjure_serde::smile::client_from_slice::<T>(&smile).unwrap()
+    );
+
+    let cbor = conjure_serde::cbor::to_vec(value).unwrap();
+    assert_eq!(
+        *value,
+        conjure_serde::cbor::client_from_slice::<T>(&cbor).unwrap()
+    );
+}
+
+proptest! {
+    #[test]
+    fn objects(
+        a in any::<AllOptionalFields>(),
+        b in any::<AllRequiredFields>(),
+        c in any::<DoubleKeys>(),
+        d in any::<TransparentAliases>(),
+        e in any::<OtherSubpackageCollections>(),
+        f in any::<com::palantir::product::CborMapKeyTests>(),
+    ) {
+        round_trip(&a);
+        round_trip(&b);
+        round_trip(&c);
+        round_trip(&d);
+        round_trip(&e);
+        round_trip(&f);
+    }
+
+    #[test]
+    fn unions(a in any::<TestUnion>(), b in any::<RecursiveUnion>(), c in any::<EmptyUnion>()) {
+        round_trip(&a);
+        round_trip(&b);
+        round_trip(&c);
+    }
+
+    #[test]
+    fn enums(a in any::<TestEnum>()) {
+        round_trip(&a);
+    }
+
+    #[test]
+    fn aliases(a in any::<OptionalObjectAlias>(), b in any::<MapDoubleAlias>(), c in any::<BinaryAlias>()) {
+        round_trip(&a);
+        round_trip(&b);
+        round_trip(&c);
+    }
+
+    #[test]
+    fn builtins(
+        a in any::<conjure_object::SafeLong>(),
+        b in any::<conjure_object::ResourceIdentifier>(),
+        c in any::<conjure_object::BearerToken>(),
+        d in any::<conjure_object::Any>(),
+    ) {
+        round_trip(&a);
+        round_trip(&b);
+        round_trip(&c);
+        round_trip(&d);
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{hash_types, index_types};
+use conjure_object::diff;
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+fn hash<T>(value: &T) -> u64
+where
+    T: Hash,
+{
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn index_map_preserves_order() {
+    let json = r#"{"map":{"b":"1","a":"2"},"set":["y","x"]}"#;
+    let value =
+        conjure_serde::json::client_from_str::<index_types::objects::AllOptionalFields>(json)
+            .unwrap();
+
+    assert_eq!(value.map.keys().collect::<Vec<_>>(), ["b", "a"]);
+    assert_eq!(conjure_serde::json::to_string(&value).unwrap(), json);
+}
+
+#[test]
+fn unordered_comparison() {
+    let a = index_types::objects::MapDoubleValues::builder()
+        .insert_raw("a", 1.)
+        .insert_raw("b", f64::NAN)
+        .build();
+    let b = index_types::objects::MapDoubleValues::builder()
+        .insert_raw("b", f64::NAN)
+        .insert_raw("a", 1.)
+        .build();
+
+    assert_eq!(a, b);
+    assert_eq!(a.cmp(&b), Ordering::Equal);
+    assert_eq!(hash(&a), hash(&b));
+
+    let c = index_types::objects::MapDoubleValues::builder()
+        .insert_raw("a", 2.)
+        .build();
+    assert_ne!(a, c);
+    assert!(a < c);
+}
+
+#[test]
+fn arc_str() {
+    let value = hash_types::objects::AllRequiredFields::new(1, 2., "foo");
+    assert_eq!(value.string(), "foo");
+
+    let value = hash_types::objects::AllOptionalFields::builder()
+        .optional_string(Arc::from("bar"))
+        .insert_set("baz")
+        .build();
+    assert_eq!(
+        conjure_serde::json::to_string(&value).unwrap(),
+        r#"{"optionalString":"bar","set":["baz"]}"#,
+    );
+}
+
+#[test]
+fn unordered_diff() {
+    let old = hash_types::objects::MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("c", "d")
+        .insert_map("a", "b")
+        .build();
+    let new = hash_types::objects::MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("e", "f")
+        .insert_map("a", "x")
+        .build();
+
+    assert_eq!(
+        diff::diff(&old, &new).to_string(),
+        "$.map[\"a\"]: \"b\" -> \"x\"\n\
+         $.map[\"c\"]: removed \"d\"\n\
+         $.map[\"e\"]: added \"f\"\n",
+    );
+}
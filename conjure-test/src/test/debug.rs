@@ -0,0 +1,68 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::types::errors::*;
+use crate::types::objects::*;
+
+#[test]
+fn safe_fields_are_unchanged() {
+    let value = MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("a", "b")
+        .build();
+
+    assert_eq!(
+        format!("{value:?}"),
+        r#"MixedFields { integer: 1, map: {"a": "b"}, string: "foo" }"#,
+    );
+}
+
+#[test]
+fn unsafe_object_fields() {
+    let value = SensitiveFields::builder()
+        .id("a")
+        .secret("hunter2")
+        .token("foo".parse::<conjure_object::BearerToken>().unwrap())
+        .insert_notes("k", UnsafeStringAlias("hunter3".to_string()))
+        .build();
+
+    assert_eq!(
+        format!("{value:?}"),
+        r#"SensitiveFields { id: "a", secret: <redacted>, token: <redacted>, notes: {"k": UnsafeStringAlias(<redacted>)} }"#,
+    );
+}
+
+#[test]
+fn unsafe_union_variants() {
+    assert_eq!(format!("{:?}", SensitiveUnion::Safe(1)), "Safe(1)");
+    assert_eq!(
+        format!("{:?}", SensitiveUnion::Secret("hunter2".to_string())),
+        "Secret(<redacted>)",
+    );
+}
+
+#[test]
+fn unsafe_error_args() {
+    let error = SimpleError::builder()
+        .foo("hello")
+        .bar(15)
+        .baz(EmptyObject::new())
+        .unsafe_foo(true)
+        .build();
+
+    assert_eq!(
+        format!("{error:?}"),
+        r#"SimpleError { foo: "hello", bar: 15, baz: EmptyObject, unsafe_foo: <redacted> }"#,
+    );
+}
@@ -0,0 +1,200 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::types::objects::*;
+use conjure_object::diff::{self, Change, PathSegment, Value};
+use conjure_object::Any;
+
+#[test]
+fn identical_values() {
+    let value = MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("a", "b")
+        .build();
+
+    assert!(diff::diff(&value, &value.clone()).is_empty());
+    assert!(diff::diff(&EmptyObject::new(), &EmptyObject::new()).is_empty());
+}
+
+#[test]
+fn object_fields() {
+    let old = MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("a", "b")
+        .insert_map("c", "d")
+        .build();
+    let new = MixedFields::builder()
+        .integer(2)
+        .string("foo")
+        .insert_map("a", "x")
+        .insert_map("e", "f")
+        .build();
+
+    let differences = diff::diff(&old, &new);
+    assert_eq!(
+        differences.to_string(),
+        "$.integer: 1 -> 2\n\
+         $.map[\"a\"]: \"b\" -> \"x\"\n\
+         $.map[\"c\"]: removed \"d\"\n\
+         $.map[\"e\"]: added \"f\"\n",
+    );
+
+    let first = differences.iter().next().unwrap();
+    assert_eq!(
+        first.path().segments(),
+        &[PathSegment::Field("integer".to_string())]
+    );
+    assert_eq!(
+        first.change(),
+        &Change::Changed {
+            old: Value::Rendered("1".to_string()),
+            new: Value::Rendered("2".to_string()),
+        }
+    );
+}
+
+#[test]
+fn nested_collections() {
+    let old = OtherSubpackageCollections::builder()
+        .list(vec![bar::baz::OtherSubpackageObject::new(
+            foo::SubpackageObject::new(IntegerAlias(1)),
+        )])
+        .build();
+    let new = OtherSubpackageCollections::builder()
+        .list(vec![bar::baz::OtherSubpackageObject::new(
+            foo::SubpackageObject::new(IntegerAlias(2)),
+        )])
+        .build();
+
+    assert_eq!(
+        diff::diff(&old, &new).to_string(),
+        "$.list[0].foo.foo: 1 -> 2\n"
+    );
+}
+
+#[test]
+fn unions() {
+    let differences = diff::diff(&TestUnion::Integer(1), &TestUnion::Integer(2));
+    assert_eq!(differences.to_string(), "$.integer: 1 -> 2\n");
+
+    let differences = diff::diff(&TestUnion::Integer(1), &TestUnion::String("a".to_string()));
+    assert_eq!(differences.to_string(), "$: Integer(1) -> String(\"a\")\n");
+}
+
+#[test]
+fn unknown_union_variants() {
+    let old = serde_json::from_str::<TestUnion>(r#"{"type":"new","new":{"a":[1,2]}}"#).unwrap();
+    let new = serde_json::from_str::<TestUnion>(r#"{"type":"new","new":{"a":[1,3]}}"#).unwrap();
+
+    assert_eq!(
+        diff::diff(&old, &new).to_string(),
+        format!(
+            "$.new[{:?}][1]: {:?} -> {:?}\n",
+            Any::new("a").unwrap(),
+            Any::new(2u64).unwrap(),
+            Any::new(3u64).unwrap(),
+        ),
+    );
+}
+
+#[test]
+fn enums() {
+    assert!(diff::diff(&TestEnum::One, &TestEnum::One).is_empty());
+    assert_eq!(
+        diff::diff(&TestEnum::One, &"FOO".parse().unwrap()).to_string(),
+        "$: One -> Unknown(Unknown(Variant(\"FOO\")))\n",
+    );
+}
+
+#[test]
+fn redacted_fields() {
+    let old = SensitiveFields::builder()
+        .id("a")
+        .secret("hunter2")
+        .token("foo".parse::<conjure_object::BearerToken>().unwrap())
+        .insert_notes("k", UnsafeStringAlias("x".to_string()))
+        .build();
+    let new = SensitiveFields::builder()
+        .id("b")
+        .secret("hunter3")
+        .insert_notes("k", UnsafeStringAlias("y".to_string()))
+        .build();
+
+    let differences = diff::diff(&old, &new);
+    assert_eq!(
+        differences.to_string(),
+        "$.id: \"a\" -> \"b\"\n\
+         $.secret: <redacted> -> <redacted>\n\
+         $.token: removed <redacted>\n\
+         $.notes[<redacted>]: <redacted> -> <redacted>\n",
+    );
+    assert!(!differences.to_string().contains("hunter"));
+}
+
+#[test]
+fn redacted_union_variants() {
+    let differences = diff::diff(
+        &SensitiveUnion::Secret("a".to_string()),
+        &SensitiveUnion::Secret("b".to_string()),
+    );
+    assert_eq!(
+        differences.to_string(),
+        "$.secret: <redacted> -> <redacted>\n"
+    );
+
+    let differences = diff::diff(
+        &SensitiveUnion::Safe(1),
+        &SensitiveUnion::Secret("b".to_string()),
+    );
+    assert_eq!(differences.to_string(), "$: <redacted> -> <redacted>\n");
+}
+
+#[test]
+fn redacted_nested_values() {
+    use crate::exhaustive_types::objects::{SensitiveContainer, SensitiveFields};
+
+    let secret = SensitiveFields {
+        id: "a".to_string(),
+        secret: "hunter2".to_string(),
+        token: None,
+        notes: Default::default(),
+    };
+    let old = SensitiveContainer {
+        optional: None,
+        list: vec![],
+    };
+    let new = SensitiveContainer {
+        optional: Some(Box::new(secret.clone())),
+        list: vec![secret],
+    };
+
+    let differences = diff::diff(&old, &new);
+    assert_eq!(
+        differences.to_string(),
+        "$.optional: added <redacted>\n\
+         $.list[0]: added <redacted>\n",
+    );
+}
+
+#[test]
+fn redacted_unknown_union_variants() {
+    let old = serde_json::from_str::<SensitiveUnion>(r#"{"type":"new","new":"hunter2"}"#).unwrap();
+    let new = serde_json::from_str::<SensitiveUnion>(r#"{"type":"new","new":"hunter3"}"#).unwrap();
+
+    assert_eq!(
+        diff::diff(&old, &new).to_string(),
+        "$.new: <redacted> -> <redacted>\n"
+    );
+}
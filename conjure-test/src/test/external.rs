@@ -0,0 +1,46 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::external_types::clients::TestService;
+use crate::external_types::objects::{ObjectAlias, TestUnion};
+use crate::types::objects::{TestObject, UnsafeStringAlias};
+use bytes::Bytes;
+use conjure_error::Error;
+use conjure_object::{BearerToken, ResourceIdentifier};
+
+#[test]
+#[allow(deprecated)]
+fn mapped_types() {
+    let union = TestUnion::Object(TestObject::new(1));
+    let json = serde_json::to_string(&union).unwrap();
+    assert_eq!(json, r#"{"type":"object","object":{"foo":1}}"#);
+
+    let alias = ObjectAlias(TestObject::new(2));
+    assert_eq!(*alias, TestObject::new(2));
+}
+
+// Mapped external references use the mapped type, and unmapped ones still use their fallback.
+#[allow(dead_code)]
+fn mapped_external_references<T, O, I>(client: &T) -> Result<(), Error>
+where
+    T: TestService<O, I>,
+    I: Iterator<Item = Result<Bytes, Error>>,
+{
+    client.external_header_and_query(
+        &BearerToken::new("token").unwrap(),
+        &UnsafeStringAlias("hunter2".to_string()),
+        &"ri.service.instance.type.locator"
+            .parse::<ResourceIdentifier>()
+            .unwrap(),
+    )
+}
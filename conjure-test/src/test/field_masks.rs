@@ -0,0 +1,154 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::overridden_types;
+use crate::types::objects::com::palantir::product::CborMapKeyTests;
+use crate::types::objects::*;
+use conjure_object::field_mask::{FieldMask, Project};
+use conjure_object::Uuid;
+use conjure_serde::{cbor, json};
+use std::collections::BTreeMap;
+
+fn project<T>(value: &T, mask: &str) -> String
+where
+    T: Project,
+{
+    json::to_string(&value.project(&mask.parse().unwrap())).unwrap()
+}
+
+#[test]
+fn required_fields_are_kept() {
+    let value = MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("a", "b")
+        .build();
+
+    assert_eq!(project(&value, ""), r#"{"integer":1,"string":"foo"}"#);
+    assert_eq!(
+        project(&value, "map"),
+        r#"{"integer":1,"map":{"a":"b"},"string":"foo"}"#,
+    );
+    assert_eq!(project(&value, "*"), json::to_string(&value).unwrap());
+
+    let projected = json::client_from_str::<MixedFields>(&project(&value, "")).unwrap();
+    assert_eq!(
+        projected,
+        MixedFields::builder().integer(1).string("foo").build(),
+    );
+}
+
+#[test]
+fn nested_fields() {
+    let value = OptionalConstructorFields2::builder()
+        .object(TestObject::new(1))
+        .build();
+
+    assert_eq!(project(&value, ""), "{}");
+    assert_eq!(project(&value, "object"), json::to_string(&value).unwrap());
+
+    let value = BTreeMap::from([(
+        "a".to_string(),
+        AllOptionalFields::builder()
+            .optional_string("foo".to_string())
+            .push_list("bar")
+            .build(),
+    )]);
+    assert_eq!(
+        project(&value, "optionalString"),
+        r#"{"a":{"optionalString":"foo"}}"#,
+    );
+}
+
+#[test]
+fn aliases() {
+    let value = TransparentAliases::builder()
+        .object_alias(ObjectAlias(TestObject::new(1)))
+        .union_alias(UnionAlias(TestUnion::Integer(1)))
+        .optional_alias(OptionalAlias(Some(2)))
+        .list_alias(ListAlias(vec![1]))
+        .build();
+
+    assert_eq!(
+        project(&value, "optionalAlias"),
+        r#"{"optionalAlias":2,"objectAlias":{"foo":1},"unionAlias":{"type":"integer","integer":1}}"#,
+    );
+}
+
+#[test]
+fn unions() {
+    let value = vec![
+        OptionalFieldsUnion::Object(
+            AllOptionalFields::builder()
+                .optional_string("foo".to_string())
+                .push_list("bar")
+                .build(),
+        ),
+        OptionalFieldsUnion::Integer(1),
+    ];
+
+    assert_eq!(
+        project(&value, "list"),
+        r#"[{"type":"object","object":{"list":["bar"]}},{"type":"integer","integer":1}]"#,
+    );
+    assert_eq!(project(&value, "*"), json::to_string(&value).unwrap());
+
+    let unknown =
+        json::client_from_str::<OptionalFieldsUnion>(r#"{"type":"other","other":{"a":1}}"#)
+            .unwrap();
+    assert_eq!(project(&unknown, ""), r#"{"type":"other","other":{"a":1}}"#);
+}
+
+#[test]
+fn string_map_keys() {
+    let value = CborMapKeyTests::builder()
+        .string_key_map(BTreeMap::from([("a".to_string(), "b".to_string())]))
+        .uuid_key_map(BTreeMap::from([(Uuid::nil(), "c".to_string())]))
+        .build();
+
+    let mask = FieldMask::empty().nested("uuidKeyMap", FieldMask::empty());
+    assert_eq!(
+        cbor::to_vec(&value.project(&mask)).unwrap(),
+        cbor::to_vec(
+            &CborMapKeyTests::builder()
+                .uuid_key_map(value.uuid_key_map().clone())
+                .build()
+        )
+        .unwrap(),
+    );
+}
+
+#[test]
+fn serialize_empty_collections() {
+    let value = overridden_types::objects::MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .build();
+
+    assert_eq!(
+        project(&value, "map"),
+        r#"{"integer":1,"map":{},"string":"foo"}"#,
+    );
+    assert_eq!(project(&value, ""), r#"{"integer":1,"string":"foo"}"#);
+}
+
+#[test]
+fn field_enum() {
+    assert_eq!(MixedFieldsField::Map.as_str(), "map");
+    assert_eq!(MixedFieldsField::Integer.to_string(), "integer");
+
+    let mask = FieldMask::empty()
+        .field(MixedFieldsField::Map)
+        .nested(MixedFieldsField::String, FieldMask::empty());
+    assert_eq!(mask.to_string(), "map,string");
+}
@@ -0,0 +1,56 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod inline_types {
+    conjure_codegen_macros::include_conjure!(
+        "test-ir.json",
+        strip_prefix = "com.palantir.conjure",
+        exhaustive = true,
+        public_fields = true,
+    );
+}
+
+#[test]
+fn matches_build_script_output() {
+    let inline = inline_types::objects::foo::SubpackageObject {
+        foo: inline_types::objects::IntegerAlias(1),
+    };
+    let json = serde_json::to_string(&inline).unwrap();
+    assert_eq!(json, r#"{"foo":1}"#);
+
+    let generated =
+        conjure_serde::json::client_from_str::<crate::types::objects::foo::SubpackageObject>(&json)
+            .unwrap();
+    assert_eq!(
+        generated,
+        crate::types::objects::foo::SubpackageObject::new(crate::types::objects::IntegerAlias(1)),
+    );
+}
+
+#[test]
+fn nested_modules() {
+    let value = inline_types::objects::bar::baz::OtherSubpackageObject {
+        foo: Box::new(inline_types::objects::foo::SubpackageObject {
+            foo: inline_types::objects::IntegerAlias(1),
+        }),
+    };
+    assert_eq!(value.foo.foo.0, 1);
+
+    let error = inline_types::errors::SimpleError {
+        foo: "a".to_string(),
+        bar: 1,
+        baz: Box::new(inline_types::objects::EmptyObject {}),
+        unsafe_foo: false,
+    };
+    assert_eq!(error.bar, 1);
+}
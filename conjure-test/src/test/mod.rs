@@ -18,13 +18,25 @@ use std::mem;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+mod any;
+mod arbitrary;
 mod cbor_map_key_tests;
 mod clients;
+mod collections;
+mod debug;
+mod diff;
 mod errors;
+mod external;
+mod field_masks;
+mod include;
 mod log_safety;
 mod objects;
+mod overrides;
+mod patches;
 mod servers;
 mod smile_map_key_tests;
+mod unions;
+mod verification;
 
 #[derive(Debug, PartialEq)]
 struct RemoteBody(Vec<u8>);
@@ -132,6 +132,36 @@ fn enums() {
     assert!(conjure_serde::json::client_from_str::<TestEnum>(r#""lowercase""#).is_err());
 }
 
+#[test]
+#[allow(deprecated)]
+fn enum_values() {
+    assert_eq!(TestEnum::VALUES, [TestEnum::One, TestEnum::Two]);
+    assert_eq!(
+        TestEnum::iter().map(TestEnum::as_str).collect::<Vec<_>>(),
+        ["ONE", "TWO"],
+    );
+
+    assert!(TestEnum::One.is_known());
+    assert_eq!(TestEnum::One.docs(), Some("The first value."));
+    assert_eq!(TestEnum::One.deprecated(), None);
+    assert_eq!(TestEnum::Two.docs(), None);
+    assert_eq!(TestEnum::Two.deprecated(), Some("Don't use me!"));
+
+    let bogus = deserialize::<TestEnum>(r#""BOGUS""#);
+    assert!(!bogus.is_known());
+    assert_eq!(bogus.docs(), None);
+    assert_eq!(bogus.deprecated(), None);
+
+    assert_eq!(
+        exhaustive_types::objects::TestEnum::VALUES,
+        [
+            exhaustive_types::objects::TestEnum::One,
+            exhaustive_types::objects::TestEnum::Two,
+        ],
+    );
+    assert!(exhaustive_types::objects::TestEnum::Two.is_known());
+}
+
 #[test]
 fn unions() {
     test_serde(
@@ -0,0 +1,55 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::overridden_types::errors::SimpleError;
+use crate::overridden_types::objects::{
+    bar, foo, EmptyObject, IntegerAlias, MixedFields, TestEnum, TestUnion,
+};
+
+#[test]
+fn package_overrides() {
+    // package overrides also apply to subpackages and errors
+    let value = foo::SubpackageObject::new(IntegerAlias(1));
+    assert_eq!(value.foo, IntegerAlias(1));
+    let value = bar::baz::OtherSubpackageObject::new(value);
+    assert_eq!(value.foo.foo, IntegerAlias(1));
+    let error = SimpleError::builder()
+        .foo("a")
+        .bar(1)
+        .baz(EmptyObject::new())
+        .unsafe_foo(false)
+        .build();
+    assert_eq!(error.bar, 1);
+
+    // exhaustive unions don't have an unknown variant
+    #[allow(deprecated)]
+    match TestUnion::Integer(1) {
+        TestUnion::Integer(_)
+        | TestUnion::Double(_)
+        | TestUnion::String(_)
+        | TestUnion::Object(_) => {}
+    }
+}
+
+#[test]
+fn type_overrides() {
+    let value = "FOO".parse::<TestEnum>().unwrap();
+    assert_eq!(value.as_str(), "FOO");
+
+    let value = MixedFields::builder().integer(1).string("foo").build();
+    assert_eq!(value.integer, 1);
+    assert_eq!(
+        serde_json::to_value(&value).unwrap(),
+        serde_json::json!({"integer": 1, "map": {}, "string": "foo"}),
+    );
+}
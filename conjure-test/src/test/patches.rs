@@ -0,0 +1,86 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::types::objects::*;
+use std::collections::BTreeMap;
+
+#[test]
+fn default() {
+    assert_eq!(
+        AllOptionalFields::default(),
+        AllOptionalFields::builder().build()
+    );
+    assert_eq!(EmptyObject::default(), EmptyObject::new());
+}
+
+#[test]
+fn diff_and_apply() {
+    let old = MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("a", "b")
+        .build();
+    let new = MixedFields::builder()
+        .integer(2)
+        .string("foo")
+        .insert_map("a", "c")
+        .build();
+
+    let patch = MixedFieldsPatch::diff(&old, &new);
+    assert_eq!(patch.integer, Some(2));
+    assert_eq!(patch.string, None);
+    assert_eq!(
+        patch.map,
+        Some(BTreeMap::from([("a".to_string(), "c".to_string())]))
+    );
+    assert!(!patch.is_empty());
+
+    let mut value = old.clone();
+    patch.apply(&mut value);
+    assert_eq!(value, new);
+}
+
+#[test]
+fn manual_patch() {
+    let mut value = MixedFields::builder()
+        .integer(1)
+        .string("foo")
+        .insert_map("a", "b")
+        .build();
+
+    let mut patch = MixedFieldsPatch::default();
+    assert!(patch.is_empty());
+    patch.string = Some("bar".to_string());
+    patch.apply(&mut value);
+
+    assert_eq!(value.integer(), 1);
+    assert_eq!(value.string(), "bar");
+}
+
+#[test]
+fn identical_values() {
+    let value = AllRequiredFields::builder()
+        .integer(1)
+        .double(f64::NAN)
+        .string("foo")
+        .build();
+    assert!(AllRequiredFieldsPatch::diff(&value, &value).is_empty());
+
+    let value = WrappedDoubles::builder()
+        .optional(f64::NAN)
+        .push_list(f64::NAN)
+        .build();
+    assert!(WrappedDoublesPatch::diff(&value, &value).is_empty());
+
+    assert!(EmptyObjectPatch::diff(&EmptyObject::new(), &EmptyObject::new()).is_empty());
+}
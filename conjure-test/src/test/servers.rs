@@ -161,6 +161,10 @@ test_service_handler! {
 
     fn map_json_response(&self) -> Result<BTreeMap<String, String>, Error>;
 
+    fn object_response(&self) -> Result<MixedFields, Error>;
+
+    fn fields_query_param(&self, fields: String) -> Result<MixedFields, Error>;
+
     fn streaming_response(&self) -> Result<StreamingBody, Error>;
 
     fn optional_streaming_response(&self) -> Result<Option<StreamingBody>, Error>;
@@ -809,6 +813,69 @@ fn map_json_response() {
         .send("mapJsonResponse");
 }
 
+#[test]
+fn object_response_field_mask() {
+    let handler = TestServiceHandler::new().object_response(|| {
+        Ok(MixedFields::builder()
+            .integer(1)
+            .string("foo")
+            .insert_map("a", "b")
+            .build())
+    });
+
+    handler
+        .clone()
+        .call()
+        .uri("/test/objectResponse")
+        .response(TestBody::Json(
+            r#"{"integer":1,"map":{"a":"b"},"string":"foo"}"#.to_string(),
+        ))
+        .send("objectResponse");
+
+    handler
+        .clone()
+        .call()
+        .uri("/test/objectResponse?fields=integer")
+        .response(TestBody::Json(
+            r#"{"integer":1,"string":"foo"}"#.to_string(),
+        ))
+        .send("objectResponse");
+
+    handler
+        .clone()
+        .call()
+        .uri("/test/objectResponse?fields=map")
+        .response(TestBody::Json(
+            r#"{"integer":1,"map":{"a":"b"},"string":"foo"}"#.to_string(),
+        ))
+        .send("objectResponse");
+
+    handler
+        .call()
+        .uri("/test/objectResponse?fields=map..a")
+        .error(ErrorCode::InvalidArgument)
+        .send("objectResponse");
+}
+
+#[test]
+fn declared_fields_query_param() {
+    TestServiceHandler::new()
+        .fields_query_param(|fields| {
+            assert_eq!(fields, "map..a");
+            Ok(MixedFields::builder()
+                .integer(1)
+                .string("foo")
+                .insert_map("a", "b")
+                .build())
+        })
+        .call()
+        .uri("/test/fieldsQueryParam?fields=map..a")
+        .response(TestBody::Json(
+            r#"{"integer":1,"map":{"a":"b"},"string":"foo"}"#.to_string(),
+        ))
+        .send("fieldsQueryParam");
+}
+
 #[test]
 fn smile_response() {
     TestServiceHandler::new()
@@ -1184,6 +1251,28 @@ fn custom_context() {
         .send_sync("context");
 }
 
+#[test]
+fn custom_context_unverified_claims() {
+    let mut mock = MockCustomService::new();
+    mock.expect_context()
+        .withf(|ctx| ctx.unverified_claims().unwrap().subject() == Some("user"))
+        .returning(|_| Ok(()));
+
+    Call::new(CustomServiceEndpoints::new(mock))
+        .header(
+            "Authorization",
+            "Bearer eyJhbGciOiJub25lIn0.eyJzdWIiOiJ1c2VyIn0.c2ln",
+        )
+        .send_sync("context");
+
+    let mut mock = MockCustomService::new();
+    mock.expect_context()
+        .withf(|ctx| ctx.unverified_claims().is_none())
+        .returning(|_| Ok(()));
+
+    Call::new(CustomServiceEndpoints::new(mock)).send_sync("context");
+}
+
 #[conjure_endpoints]
 trait CustomStreamingService<#[request_body] I, #[response_writer] O>
 where
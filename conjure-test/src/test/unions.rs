@@ -0,0 +1,106 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(deprecated)]
+
+use crate::exhaustive_types;
+use crate::types::objects::*;
+
+struct Describe;
+
+impl TestUnionVisitor for Describe {
+    type Output = String;
+
+    fn visit_integer(self, value: &i32) -> String {
+        format!("integer {value}")
+    }
+
+    fn visit_double(self, value: &f64) -> String {
+        format!("double {value}")
+    }
+
+    fn visit_string(self, value: &str) -> String {
+        format!("string {value}")
+    }
+
+    fn visit_object(self, value: &TestObject) -> String {
+        format!("object {}", value.foo())
+    }
+
+    fn visit_unknown(self, type_: &str) -> String {
+        format!("unknown {type_}")
+    }
+}
+
+impl exhaustive_types::objects::TestUnionVisitor for Describe {
+    type Output = String;
+
+    fn visit_integer(self, value: &i32) -> String {
+        format!("integer {value}")
+    }
+
+    fn visit_double(self, value: &f64) -> String {
+        format!("double {value}")
+    }
+
+    fn visit_string(self, value: &str) -> String {
+        format!("string {value}")
+    }
+
+    fn visit_object(self, value: &exhaustive_types::objects::TestObject) -> String {
+        format!("object {}", value.foo)
+    }
+
+    fn visit_unknown(self, type_: &str) -> String {
+        format!("unknown {type_}")
+    }
+}
+
+#[test]
+fn visitor() {
+    assert_eq!(TestUnion::Integer(1).accept(Describe), "integer 1");
+    assert_eq!(
+        TestUnion::String("foo".to_string()).accept(Describe),
+        "string foo"
+    );
+    assert_eq!(
+        TestUnion::Object(TestObject::new(2)).accept(Describe),
+        "object 2"
+    );
+
+    let unknown = conjure_serde::json::client_from_str::<TestUnion>(
+        r#"{"type": "unknownVariant", "unknownVariant": 3}"#,
+    )
+    .unwrap();
+    assert_eq!(unknown.accept(Describe), "unknown unknownVariant");
+
+    assert_eq!(
+        exhaustive_types::objects::TestUnion::Double(1.5).accept(Describe),
+        "double 1.5"
+    );
+}
+
+#[test]
+fn accessors() {
+    let value = TestUnion::String("foo".to_string());
+    assert!(value.is_string());
+    assert!(!value.is_integer());
+    assert_eq!(value.as_string(), Some("foo"));
+    assert_eq!(value.as_integer(), None);
+    assert_eq!(value.clone().into_string(), Some("foo".to_string()));
+    assert_eq!(value.into_integer(), None);
+
+    let value = RecursiveUnion::B(Box::new(RecursiveUnion::A(1.)));
+    assert_eq!(value.as_b(), Some(&RecursiveUnion::A(1.)));
+    assert_eq!(value.into_b(), Some(RecursiveUnion::A(1.)));
+}
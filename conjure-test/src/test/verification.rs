@@ -0,0 +1,756 @@
+// Copyright 2026 Palantir Technologies, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Wire-format conformance tests driven by the Conjure verification suite.
+//!
+//! Test cases are read from `verification/test-cases.json`, which uses the same format as the `test-cases.json`
+//! published by conjure-verification. Set `CONJURE_VERIFICATION_TEST_CASES` to run a different file, such as the full
+//! upstream suite. Endpoints which aren't defined in `verification/verification-api.json` are reported as skipped.
+//!
+//! Every case is run twice: once against the generated client, talking to a stand-in server, and once against the
+//! generated server. Known failures are listed in `verification/ignored-test-cases.json` (overridden by
+//! `CONJURE_VERIFICATION_IGNORED_TEST_CASES`), and an ignored case which starts passing is itself a failure so the
+//! list stays accurate. The per-case report is printed to stdout and, if `CONJURE_VERIFICATION_REPORT` is set, written
+//! to that path.
+use crate::test::RemoteBody;
+use crate::verification_types::clients::client::{
+    AutoDeserializeService as _, AutoDeserializeServiceClient, SingleHeaderService as _,
+    SingleHeaderServiceClient, SinglePathParamService as _, SinglePathParamServiceClient,
+    SingleQueryParamService as _, SingleQueryParamServiceClient,
+};
+use crate::verification_types::endpoints::client::{
+    SingleHeaderService, SingleHeaderServiceEndpoints, SinglePathParamService,
+    SinglePathParamServiceEndpoints, SingleQueryParamService, SingleQueryParamServiceEndpoints,
+};
+use crate::verification_types::endpoints::server::{
+    ServerAutoDeserializeService, ServerAutoDeserializeServiceEndpoints,
+};
+use crate::verification_types::objects::types::*;
+use conjure_error::Error;
+use conjure_http::client::{Client, ConjureRuntime, RequestBody, Service as _};
+use conjure_http::server::{self, ResponseBody, Service};
+use conjure_http::PathParams;
+use conjure_object::{BearerToken, DateTime, ResourceIdentifier, SafeLong, Utc, Uuid};
+use http::header::{ACCEPT, CONTENT_TYPE};
+use http::{Extensions, Method, Request, Response, StatusCode, Uri};
+use percent_encoding::NON_ALPHANUMERIC;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Debug};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::{env, fs};
+
+const HEADER_NAME: &str = "Some-Header";
+const QUERY_NAME: &str = "foo";
+
+#[derive(Deserialize)]
+struct TestCases {
+    client: ClientTestCases,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct ClientTestCases {
+    auto_deserialize: BTreeMap<String, PositiveAndNegativeTestCases>,
+    single_header_service: BTreeMap<String, Vec<String>>,
+    single_path_param_service: BTreeMap<String, Vec<String>>,
+    single_query_param_service: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct PositiveAndNegativeTestCases {
+    positive: Vec<String>,
+    negative: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct IgnoredTestCases {
+    client: IgnoredCases,
+    server: IgnoredCases,
+}
+
+impl IgnoredTestCases {
+    fn is_ignored(&self, mode: Mode, category: Category, endpoint: &str, case: &str) -> bool {
+        let cases = match mode {
+            Mode::Client => &self.client,
+            Mode::Server => &self.server,
+        };
+        let cases = match category {
+            Category::AutoDeserialize => &cases.auto_deserialize,
+            Category::SingleHeaderService => &cases.single_header_service,
+            Category::SinglePathParamService => &cases.single_path_param_service,
+            Category::SingleQueryParamService => &cases.single_query_param_service,
+        };
+        cases.get(endpoint).is_some_and(|c| c.contains(case))
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase", default)]
+struct IgnoredCases {
+    auto_deserialize: BTreeMap<String, BTreeSet<String>>,
+    single_header_service: BTreeMap<String, BTreeSet<String>>,
+    single_path_param_service: BTreeMap<String, BTreeSet<String>>,
+    single_query_param_service: BTreeMap<String, BTreeSet<String>>,
+}
+
+#[derive(Copy, Clone)]
+enum Mode {
+    Client,
+    Server,
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Client => fmt.write_str("client"),
+            Mode::Server => fmt.write_str("server"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Category {
+    AutoDeserialize,
+    SingleHeaderService,
+    SinglePathParamService,
+    SingleQueryParamService,
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Category::AutoDeserialize => "autoDeserialize",
+            Category::SingleHeaderService => "singleHeaderService",
+            Category::SinglePathParamService => "singlePathParamService",
+            Category::SingleQueryParamService => "singleQueryParamService",
+        };
+        fmt.write_str(s)
+    }
+}
+
+enum Status {
+    Pass,
+    Fail(String),
+    Ignored(String),
+    Skipped,
+}
+
+struct CaseResult {
+    mode: Mode,
+    category: Category,
+    endpoint: String,
+    // e.g. `positive[0]`
+    label: String,
+    case: String,
+    status: Status,
+}
+
+#[derive(Default)]
+struct Report {
+    results: Vec<CaseResult>,
+}
+
+impl Report {
+    fn count(&self, f: impl Fn(&Status) -> bool) -> usize {
+        self.results.iter().filter(|r| f(&r.status)).count()
+    }
+
+    fn failures(&self) -> usize {
+        self.count(|s| matches!(s, Status::Fail(_)))
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            let (status, detail) = match &result.status {
+                Status::Pass => ("PASS", None),
+                Status::Fail(detail) => ("FAIL", Some(detail)),
+                Status::Ignored(detail) => ("IGNORED", Some(detail)),
+                Status::Skipped => ("SKIPPED", None),
+            };
+            write!(
+                fmt,
+                "{status:<7} {} {}/{} {} {}",
+                result.mode, result.category, result.endpoint, result.label, result.case,
+            )?;
+            if let Some(detail) = detail {
+                write!(fmt, ": {detail}")?;
+            }
+            writeln!(fmt)?;
+        }
+
+        writeln!(
+            fmt,
+            "{} passed, {} failed, {} ignored, {} skipped",
+            self.count(|s| matches!(s, Status::Pass)),
+            self.failures(),
+            self.count(|s| matches!(s, Status::Ignored(_))),
+            self.count(|s| matches!(s, Status::Skipped)),
+        )
+    }
+}
+
+fn runtime() -> Arc<ConjureRuntime> {
+    Arc::new(ConjureRuntime::new())
+}
+
+/// A stand-in for the verification server, used to exercise generated clients.
+///
+/// It responds to every request with a fixed body and records the request so the encoded parameters can be
+/// inspected.
+struct StandInServer {
+    response: Option<String>,
+    request: RefCell<Option<http::request::Parts>>,
+}
+
+impl StandInServer {
+    fn new(response: Option<&str>) -> StandInServer {
+        StandInServer {
+            response: response.map(str::to_string),
+            request: RefCell::new(None),
+        }
+    }
+
+    fn param(&self, category: Category, endpoint: &str) -> Option<String> {
+        let request = self.request.borrow();
+        let request = request.as_ref().expect("no request was sent");
+
+        match category {
+            Category::AutoDeserialize => unreachable!(),
+            Category::SingleHeaderService => request
+                .headers
+                .get(HEADER_NAME)
+                .map(|v| String::from_utf8_lossy(v.as_bytes()).into_owned()),
+            Category::SinglePathParamService => {
+                let prefix = format!("/single-path-param/{endpoint}/0/");
+                let segment = request.uri.path().strip_prefix(&prefix)?;
+                Some(
+                    percent_encoding::percent_decode_str(segment)
+                        .decode_utf8_lossy()
+                        .into_owned(),
+                )
+            }
+            Category::SingleQueryParamService => {
+                form_urlencoded::parse(request.uri.query()?.as_bytes())
+                    .find(|(k, _)| k == QUERY_NAME)
+                    .map(|(_, v)| v.into_owned())
+            }
+        }
+    }
+}
+
+impl Client for &StandInServer {
+    type BodyWriter = Vec<u8>;
+    type ResponseBody = RemoteBody;
+
+    fn send(
+        &self,
+        req: Request<RequestBody<'_, Self::BodyWriter>>,
+    ) -> Result<Response<Self::ResponseBody>, Error> {
+        let (parts, _) = req.into_parts();
+        *self.request.borrow_mut() = Some(parts);
+
+        match &self.response {
+            Some(body) => Ok(Response::builder()
+                .status(StatusCode::OK)
+                .header(CONTENT_TYPE, "application/json")
+                .body(RemoteBody(body.as_bytes().to_vec()))
+                .unwrap()),
+            None => Ok(Response::builder()
+                .status(StatusCode::NO_CONTENT)
+                .body(RemoteBody(vec![]))
+                .unwrap()),
+        }
+    }
+}
+
+/// A server-side handler which echoes request bodies back to the caller.
+struct Echo;
+
+/// A server-side handler which records the parameter it was called with.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Option<serde_json::Value>>>);
+
+impl Recorder {
+    fn record<T>(&self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize,
+    {
+        *self.0.lock().unwrap() = Some(to_json_value(value)?);
+        Ok(())
+    }
+
+    fn take(&self) -> Option<serde_json::Value> {
+        self.0.lock().unwrap().take()
+    }
+}
+
+fn to_json_value<T>(value: &T) -> Result<serde_json::Value, Error>
+where
+    T: Serialize,
+{
+    let json = conjure_serde::json::to_vec(value).map_err(Error::internal_safe)?;
+    serde_json::from_slice(&json).map_err(Error::internal_safe)
+}
+
+fn serve<S>(service: S, endpoint: &str, request: Request<RemoteBody>) -> Result<Vec<u8>, Error>
+where
+    S: Service<RemoteBody, Vec<u8>>,
+{
+    let endpoint = service
+        .endpoints(&Arc::new(server::ConjureRuntime::new()))
+        .into_iter()
+        .find(|e| e.name() == endpoint)
+        .unwrap();
+
+    let response = endpoint.handle(request, &mut Extensions::new())?;
+    match response.into_body() {
+        ResponseBody::Empty => Ok(vec![]),
+        ResponseBody::Fixed(bytes) => Ok(bytes.to_vec()),
+        ResponseBody::Streaming(_) => panic!("unexpected streaming response"),
+    }
+}
+
+fn check_body<T>(
+    mode: Mode,
+    endpoint: &str,
+    case: &str,
+    positive: bool,
+    call: impl FnOnce(&StandInServer) -> Result<T, Error>,
+) -> Result<(), String>
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let result = match mode {
+        Mode::Client => call(&StandInServer::new(Some(case))),
+        Mode::Server => {
+            let mut request = Request::new(RemoteBody(case.as_bytes().to_vec()));
+            *request.method_mut() = Method::POST;
+            *request.uri_mut() = format!("/body/{endpoint}").parse().unwrap();
+            request
+                .headers_mut()
+                .insert(CONTENT_TYPE, "application/json".parse().unwrap());
+            request
+                .headers_mut()
+                .insert(ACCEPT, "application/json".parse().unwrap());
+            serve(
+                ServerAutoDeserializeServiceEndpoints::new(Echo),
+                endpoint,
+                request,
+            )
+            .and_then(|body| {
+                conjure_serde::json::client_from_slice(&body).map_err(Error::internal_safe)
+            })
+        }
+    };
+
+    match (result, positive) {
+        (Ok(value), true) => {
+            let expected = conjure_serde::json::client_from_str::<T>(case)
+                .map_err(|e| format!("invalid test case: {e}"))?;
+            if value != expected {
+                return Err(format!("expected {expected:?}, got {value:?}"));
+            }
+
+            let json = conjure_serde::json::to_vec(&value)
+                .map_err(|e| format!("error serializing {value:?}: {e}"))?;
+            let round_tripped = conjure_serde::json::client_from_slice::<T>(&json)
+                .map_err(|e| format!("error deserializing re-serialized value: {e}"))?;
+            if round_tripped != expected {
+                return Err(format!(
+                    "expected {expected:?} after a round trip, got {round_tripped:?}"
+                ));
+            }
+
+            Ok(())
+        }
+        (Err(e), true) => Err(format!("expected success, got error: {}", e.cause())),
+        (Ok(value), false) => Err(format!("expected error, got {value:?}")),
+        (Err(_), false) => Ok(()),
+    }
+}
+
+fn check_param<T>(
+    mode: Mode,
+    category: Category,
+    endpoint: &str,
+    case: &str,
+    call: impl FnOnce(&StandInServer, &T) -> Result<(), Error>,
+) -> Result<(), String>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let value = conjure_serde::json::client_from_str::<T>(case)
+        .map_err(|e| format!("invalid test case: {e}"))?;
+    // The verification server expects strings to be sent unquoted and everything else in its JSON form.
+    let wire = match serde_json::from_str(case).map_err(|e| format!("invalid test case: {e}"))? {
+        serde_json::Value::Null => None,
+        serde_json::Value::String(s) => Some(s),
+        v => Some(v.to_string()),
+    };
+
+    match mode {
+        Mode::Client => {
+            let server = StandInServer::new(None);
+            call(&server, &value).map_err(|e| format!("request failed: {}", e.cause()))?;
+            let actual = server.param(category, endpoint);
+            // Plain encodings aren't canonical (`1` vs `1.0`), so fall back to comparing decoded values.
+            if actual != wire
+                && decode_param::<T>(case, actual.as_deref()) != to_json_value(&value).ok()
+            {
+                return Err(format!("expected {wire:?} on the wire, got {actual:?}"));
+            }
+        }
+        Mode::Server => {
+            let mut request = Request::new(RemoteBody(vec![]));
+            *request.method_mut() = Method::POST;
+            let mut path_params = PathParams::new();
+            path_params.insert("index", "0");
+
+            let recorder = Recorder::default();
+            let result = match category {
+                Category::AutoDeserialize => unreachable!(),
+                Category::SingleHeaderService => {
+                    *request.uri_mut() = format!("/single-header-param/{endpoint}/0")
+                        .parse()
+                        .unwrap();
+                    if let Some(wire) = &wire {
+                        let value = wire.parse().map_err(|e| format!("invalid header: {e}"))?;
+                        request.headers_mut().insert(HEADER_NAME, value);
+                    }
+                    request.extensions_mut().insert(path_params);
+                    serve(
+                        SingleHeaderServiceEndpoints::new(recorder.clone()),
+                        endpoint,
+                        request,
+                    )
+                }
+                Category::SinglePathParamService => {
+                    let wire = wire.as_deref().unwrap_or_default();
+                    let segment = percent_encoding::utf8_percent_encode(wire, NON_ALPHANUMERIC);
+                    *request.uri_mut() = format!("/single-path-param/{endpoint}/0/{segment}")
+                        .parse()
+                        .unwrap();
+                    path_params.insert("param", segment.to_string());
+                    request.extensions_mut().insert(path_params);
+                    serve(
+                        SinglePathParamServiceEndpoints::new(recorder.clone()),
+                        endpoint,
+                        request,
+                    )
+                }
+                Category::SingleQueryParamService => {
+                    let mut uri = format!("/single-query-param/{endpoint}/0");
+                    if let Some(wire) = &wire {
+                        let query = form_urlencoded::Serializer::new(String::new())
+                            .append_pair(QUERY_NAME, wire)
+                            .finish();
+                        uri = format!("{uri}?{query}");
+                    }
+                    *request.uri_mut() = uri.parse::<Uri>().unwrap();
+                    request.extensions_mut().insert(path_params);
+                    serve(
+                        SingleQueryParamServiceEndpoints::new(recorder.clone()),
+                        endpoint,
+                        request,
+                    )
+                }
+            };
+            result.map_err(|e| format!("request failed: {}", e.cause()))?;
+
+            let expected = to_json_value(&value).map_err(|e| format!("{}", e.cause()))?;
+            let actual = recorder.take();
+            if actual.as_ref() != Some(&expected) {
+                return Err(format!("expected {expected}, got {actual:?}"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_param<T>(case: &str, wire: Option<&str>) -> Option<serde_json::Value>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let json = match wire {
+        None => serde_json::Value::Null,
+        Some(wire) if case.starts_with('"') => serde_json::Value::String(wire.to_string()),
+        Some(wire) => serde_json::from_str(wire).ok()?,
+    };
+    let value = conjure_serde::json::client_from_str::<T>(&json.to_string()).ok()?;
+    to_json_value(&value).ok()
+}
+
+macro_rules! body_endpoints {
+    ($($endpoint:literal => $method:ident($type_:ty),)*) => {
+        impl ServerAutoDeserializeService for Echo {
+            $(
+                fn $method(&self, body: $type_) -> Result<$type_, Error> {
+                    Ok(body)
+                }
+            )*
+        }
+
+        fn run_body_case(
+            mode: Mode,
+            endpoint: &str,
+            case: &str,
+            positive: bool,
+        ) -> Option<Result<(), String>> {
+            let result = match endpoint {
+                $(
+                    $endpoint => check_body::<$type_>(mode, endpoint, case, positive, |server| {
+                        AutoDeserializeServiceClient::new(server, &runtime()).$method(0)
+                    }),
+                )*
+                _ => return None,
+            };
+            Some(result)
+        }
+    };
+}
+
+body_endpoints! {
+    "receiveBearerTokenExample" => receive_bearer_token_example(BearerTokenExample),
+    "receiveBinaryExample" => receive_binary_example(BinaryExample),
+    "receiveBooleanExample" => receive_boolean_example(BooleanExample),
+    "receiveDateTimeExample" => receive_date_time_example(DateTimeExample),
+    "receiveDoubleExample" => receive_double_example(DoubleExample),
+    "receiveIntegerExample" => receive_integer_example(IntegerExample),
+    "receiveRidExample" => receive_rid_example(RidExample),
+    "receiveSafeLongExample" => receive_safe_long_example(SafeLongExample),
+    "receiveStringExample" => receive_string_example(StringExample),
+    "receiveUuidExample" => receive_uuid_example(UuidExample),
+    "receiveAnyExample" => receive_any_example(AnyExample),
+    "receiveEnumFieldExample" => receive_enum_field_example(EnumFieldExample),
+    "receiveListExample" => receive_list_example(ListExample),
+    "receiveSetStringExample" => receive_set_string_example(SetStringExample),
+    "receiveSetDoubleExample" => receive_set_double_example(SetDoubleExample),
+    "receiveMapExample" => receive_map_example(MapExample),
+    "receiveOptionalExample" => receive_optional_example(OptionalExample),
+    "receiveOptionalBooleanExample" => receive_optional_boolean_example(OptionalBooleanExample),
+    "receiveOptionalIntegerExample" => receive_optional_integer_example(OptionalIntegerExample),
+    "receiveKebabCaseObjectExample" => receive_kebab_case_object_example(KebabCaseObjectExample),
+    "receiveSnakeCaseObjectExample" => receive_snake_case_object_example(SnakeCaseObjectExample),
+    "receiveEnumExample" => receive_enum_example(EnumExample),
+    "receiveUnionTypeExample" => receive_union_type_example(UnionTypeExample),
+    "receiveAliasString" => receive_alias_string(AliasString),
+}
+
+macro_rules! param_endpoints {
+    (
+        $category:ident, $trait_:ident, $client:ident, $run:ident {
+            $($endpoint:literal => $method:ident($type_:ty, |$v:ident| $arg:expr),)*
+        }
+    ) => {
+        impl $trait_ for Recorder {
+            $(
+                fn $method(&self, _: i32, value: $type_) -> Result<(), Error> {
+                    self.record(&value)
+                }
+            )*
+        }
+
+        fn $run(mode: Mode, endpoint: &str, case: &str) -> Option<Result<(), String>> {
+            let result = match endpoint {
+                $(
+                    $endpoint => check_param::<$type_>(
+                        mode,
+                        Category::$category,
+                        endpoint,
+                        case,
+                        |server, $v| $client::new(server, &runtime()).$method(0, $arg),
+                    ),
+                )*
+                _ => return None,
+            };
+            Some(result)
+        }
+    };
+}
+
+param_endpoints! {
+    SingleHeaderService, SingleHeaderService, SingleHeaderServiceClient, run_header_case {
+        "headerBearertoken" => header_bearertoken(BearerToken, |v| v),
+        "headerBoolean" => header_boolean(bool, |v| *v),
+        "headerDatetime" => header_datetime(DateTime<Utc>, |v| *v),
+        "headerDouble" => header_double(f64, |v| *v),
+        "headerInteger" => header_integer(i32, |v| *v),
+        "headerRid" => header_rid(ResourceIdentifier, |v| v),
+        "headerSafelong" => header_safelong(SafeLong, |v| *v),
+        "headerString" => header_string(String, |v| v),
+        "headerUuid" => header_uuid(Uuid, |v| *v),
+        "headerOptionalOfString" => header_optional_of_string(Option<String>, |v| v.as_deref()),
+        "headerAliasString" => header_alias_string(AliasString, |v| v),
+        "headerEnumExample" => header_enum_example(EnumExample, |v| v),
+    }
+}
+
+param_endpoints! {
+    SinglePathParamService, SinglePathParamService, SinglePathParamServiceClient, run_path_case {
+        "pathParamBearertoken" => path_param_bearertoken(BearerToken, |v| v),
+        "pathParamBoolean" => path_param_boolean(bool, |v| *v),
+        "pathParamDatetime" => path_param_datetime(DateTime<Utc>, |v| *v),
+        "pathParamDouble" => path_param_double(f64, |v| *v),
+        "pathParamInteger" => path_param_integer(i32, |v| *v),
+        "pathParamRid" => path_param_rid(ResourceIdentifier, |v| v),
+        "pathParamSafelong" => path_param_safelong(SafeLong, |v| *v),
+        "pathParamString" => path_param_string(String, |v| v),
+        "pathParamUuid" => path_param_uuid(Uuid, |v| *v),
+        "pathParamAliasString" => path_param_alias_string(AliasString, |v| v),
+        "pathParamEnumExample" => path_param_enum_example(EnumExample, |v| v),
+    }
+}
+
+param_endpoints! {
+    SingleQueryParamService, SingleQueryParamService, SingleQueryParamServiceClient, run_query_case {
+        "queryParamBearertoken" => query_param_bearertoken(BearerToken, |v| v),
+        "queryParamBoolean" => query_param_boolean(bool, |v| *v),
+        "queryParamDatetime" => query_param_datetime(DateTime<Utc>, |v| *v),
+        "queryParamDouble" => query_param_double(f64, |v| *v),
+        "queryParamInteger" => query_param_integer(i32, |v| *v),
+        "queryParamRid" => query_param_rid(ResourceIdentifier, |v| v),
+        "queryParamSafelong" => query_param_safelong(SafeLong, |v| *v),
+        "queryParamString" => query_param_string(String, |v| v),
+        "queryParamUuid" => query_param_uuid(Uuid, |v| *v),
+        "queryParamOptionalOfString" => query_param_optional_of_string(Option<String>, |v| v.as_deref()),
+        "queryParamAliasString" => query_param_alias_string(AliasString, |v| v),
+        "queryParamEnumExample" => query_param_enum_example(EnumExample, |v| v),
+    }
+}
+
+struct Runner {
+    ignored: IgnoredTestCases,
+    report: Report,
+}
+
+impl Runner {
+    fn run(
+        &mut self,
+        category: Category,
+        endpoint: &str,
+        label: String,
+        case: &str,
+        f: impl Fn(Mode) -> Option<Result<(), String>>,
+    ) {
+        for mode in [Mode::Client, Mode::Server] {
+            let ignored = self.ignored.is_ignored(mode, category, endpoint, case);
+            let status = match (f(mode), ignored) {
+                (None, _) => Status::Skipped,
+                (Some(Ok(())), false) => Status::Pass,
+                (Some(Ok(())), true) => {
+                    Status::Fail("passed, but is listed as an ignored test case".to_string())
+                }
+                (Some(Err(e)), false) => Status::Fail(e),
+                (Some(Err(e)), true) => Status::Ignored(e),
+            };
+
+            self.report.results.push(CaseResult {
+                mode,
+                category,
+                endpoint: endpoint.to_string(),
+                label: label.clone(),
+                case: case.to_string(),
+                status,
+            });
+        }
+    }
+
+    fn run_params(
+        &mut self,
+        category: Category,
+        cases: &BTreeMap<String, Vec<String>>,
+        f: fn(Mode, &str, &str) -> Option<Result<(), String>>,
+    ) {
+        for (endpoint, cases) in cases {
+            for (i, case) in cases.iter().enumerate() {
+                self.run(category, endpoint, format!("[{i}]"), case, |mode| {
+                    f(mode, endpoint, case)
+                });
+            }
+        }
+    }
+}
+
+fn file(var: &str, default: &str) -> PathBuf {
+    match env::var_os(var) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join(default),
+    }
+}
+
+#[test]
+fn verification() {
+    let path = file(
+        "CONJURE_VERIFICATION_TEST_CASES",
+        "verification/test-cases.json",
+    );
+    let test_cases = serde_json::from_slice::<TestCases>(&fs::read(&path).unwrap()).unwrap();
+    let path = file(
+        "CONJURE_VERIFICATION_IGNORED_TEST_CASES",
+        "verification/ignored-test-cases.json",
+    );
+    let ignored = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+
+    let mut runner = Runner {
+        ignored,
+        report: Report::default(),
+    };
+
+    let cases = &test_cases.client;
+    for (endpoint, cases) in &cases.auto_deserialize {
+        for (positive, cases) in [(true, &cases.positive), (false, &cases.negative)] {
+            for (i, case) in cases.iter().enumerate() {
+                let label = if positive { "positive" } else { "negative" };
+                runner.run(
+                    Category::AutoDeserialize,
+                    endpoint,
+                    format!("{label}[{i}]"),
+                    case,
+                    |mode| run_body_case(mode, endpoint, case, positive),
+                );
+            }
+        }
+    }
+    runner.run_params(
+        Category::SingleHeaderService,
+        &cases.single_header_service,
+        run_header_case,
+    );
+    runner.run_params(
+        Category::SinglePathParamService,
+        &cases.single_path_param_service,
+        run_path_case,
+    );
+    runner.run_params(
+        Category::SingleQueryParamService,
+        &cases.single_query_param_service,
+        run_query_case,
+    );
+
+    let report = runner.report.to_string();
+    println!("{report}");
+    if let Some(path) = env::var_os("CONJURE_VERIFICATION_REPORT") {
+        fs::write(path, &report).unwrap();
+    }
+
+    assert_eq!(runner.report.failures(), 0, "{report}");
+}